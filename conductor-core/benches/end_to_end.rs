@@ -31,12 +31,19 @@ fn create_test_config(num_mappings: usize) -> Config {
             trigger: Trigger::Note {
                 note: (36 + (i % 50)) as u8,
                 velocity_min: None,
+                channel: None,
             },
             action: ActionConfig::Keystroke {
                 keys: "a".to_string(),
                 modifiers: vec![],
             },
             description: None,
+            immediate: false,
+            on_release: None,
+            momentary: false,
+            continuous: None,
+            while_held: vec![],
+            concurrency: Default::default(),
         })
         .collect();
 
@@ -55,6 +62,7 @@ fn create_test_config(num_mappings: usize) -> Config {
         global_mappings: vec![],
         logging: None,
         advanced_settings: Default::default(),
+        variables: Default::default(),
     }
 }
 
@@ -72,6 +80,7 @@ fn bench_simple_note_to_action(c: &mut Criterion) {
     c.bench_function("e2e::simple_note_press", |b| {
         b.iter(|| {
             let midi_event = black_box(MidiEvent::NoteOn {
+                channel: 0,
                 note: 36,
                 velocity: 64,
                 time: black_box(now),
@@ -99,6 +108,7 @@ fn bench_pipeline_without_execution(c: &mut Criterion) {
     c.bench_function("e2e::pipeline_no_execution", |b| {
         b.iter(|| {
             let midi_event = black_box(MidiEvent::NoteOn {
+                channel: 0,
                 note: 36,
                 velocity: 64,
                 time: black_box(now),
@@ -131,6 +141,7 @@ fn bench_pipeline_scaling(c: &mut Criterion) {
             |b, _| {
                 b.iter(|| {
                     let midi_event = black_box(MidiEvent::NoteOn {
+                        channel: 0,
                         note: 36 + (*size as u8 / 2),
                         velocity: 64,
                         time: black_box(now),
@@ -161,6 +172,7 @@ fn bench_rapid_note_input(c: &mut Criterion) {
         b.iter(|| {
             for &note in &notes {
                 let midi_event = MidiEvent::NoteOn {
+                    channel: 0,
                     note,
                     velocity: 64,
                     time: black_box(now),
@@ -190,17 +202,26 @@ fn bench_encoder_input(c: &mut Criterion) {
                 trigger: Trigger::CC {
                     cc: 14,
                     value_min: None,
+                    value_max: None,
+                    channel: None,
                 },
                 action: ActionConfig::Keystroke {
                     keys: "a".to_string(),
                     modifiers: vec![],
                 },
                 description: None,
+                immediate: false,
+                on_release: None,
+                momentary: false,
+                continuous: None,
+                while_held: vec![],
+                concurrency: Default::default(),
             }],
         }],
         global_mappings: vec![],
         logging: None,
         advanced_settings: Default::default(),
+        variables: Default::default(),
     };
 
     let mut processor = EventProcessor::new();
@@ -211,6 +232,7 @@ fn bench_encoder_input(c: &mut Criterion) {
     c.bench_function("e2e::encoder_input", |b| {
         b.iter(|| {
             let midi_event = black_box(MidiEvent::ControlChange {
+                channel: 0,
                 cc: 14,
                 value: 64,
                 time: black_box(now),
@@ -234,6 +256,7 @@ fn bench_note_release_processing(c: &mut Criterion) {
     c.bench_function("e2e::note_release", |b| {
         b.iter(|| {
             let midi_event = black_box(MidiEvent::NoteOff {
+                channel: 0,
                 note: 36,
                 time: black_box(now),
             });
@@ -259,6 +282,7 @@ fn bench_velocity_sensitive_processing(c: &mut Criterion) {
         group.bench_with_input(BenchmarkId::from_parameter(label), velocity, |b, &vel| {
             b.iter(|| {
                 let midi_event = black_box(MidiEvent::NoteOn {
+                    channel: 0,
                     note: 36,
                     velocity: vel,
                     time: black_box(now),
@@ -288,6 +312,7 @@ fn bench_mode_switching(c: &mut Criterion) {
 
             for &mode in &modes {
                 let midi_event = MidiEvent::NoteOn {
+                    channel: 0,
                     note: 36,
                     velocity: 64,
                     time: black_box(now),
@@ -314,20 +339,24 @@ fn bench_mixed_input_pattern(c: &mut Criterion) {
             // Simulate: note on, encoder turn, note release, another note with different velocity
             let events = black_box(vec![
                 MidiEvent::NoteOn {
+                    channel: 0,
                     note: 36,
                     velocity: 50,
                     time: now,
                 },
                 MidiEvent::ControlChange {
+                    channel: 0,
                     cc: 14,
                     value: 65,
                     time: now,
                 },
                 MidiEvent::NoteOff {
+                    channel: 0,
                     note: 36,
                     time: now,
                 },
                 MidiEvent::NoteOn {
+                    channel: 0,
                     note: 37,
                     velocity: 100,
                     time: now,
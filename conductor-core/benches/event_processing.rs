@@ -25,6 +25,7 @@ fn bench_note_on_event(c: &mut Criterion) {
     c.bench_function("event_processing::note_on", |b| {
         b.iter(|| {
             let event = black_box(MidiEvent::NoteOn {
+                channel: 0,
                 note: 36,
                 velocity: 64,
                 time: black_box(now),
@@ -42,6 +43,7 @@ fn bench_note_off_event(c: &mut Criterion) {
 
     // First send a note on
     processor.process(MidiEvent::NoteOn {
+        channel: 0,
         note: 36,
         velocity: 64,
         time: now,
@@ -50,6 +52,7 @@ fn bench_note_off_event(c: &mut Criterion) {
     c.bench_function("event_processing::note_off", |b| {
         b.iter(|| {
             let event = black_box(MidiEvent::NoteOff {
+                channel: 0,
                 note: 36,
                 time: black_box(now),
             });
@@ -69,6 +72,7 @@ fn bench_velocity_detection(c: &mut Criterion) {
         group.bench_with_input(BenchmarkId::from_parameter(label), velocity, |b, &vel| {
             b.iter(|| {
                 let event = black_box(MidiEvent::NoteOn {
+                    channel: 0,
                     note: 36,
                     velocity: vel,
                     time: black_box(now),
@@ -89,6 +93,7 @@ fn bench_control_change_event(c: &mut Criterion) {
     c.bench_function("event_processing::control_change", |b| {
         b.iter(|| {
             let event = black_box(MidiEvent::ControlChange {
+                channel: 0,
                 cc: 14,
                 value: 64,
                 time: black_box(now),
@@ -107,6 +112,7 @@ fn bench_encoder_direction_detection(c: &mut Criterion) {
     // Clockwise (64 -> 65)
     let mut processor_cw = EventProcessor::new();
     processor_cw.process(MidiEvent::ControlChange {
+        channel: 0,
         cc: 14,
         value: 64,
         time: now,
@@ -115,6 +121,7 @@ fn bench_encoder_direction_detection(c: &mut Criterion) {
     group.bench_function("clockwise", |b| {
         b.iter(|| {
             let event = black_box(MidiEvent::ControlChange {
+                channel: 0,
                 cc: 14,
                 value: 65,
                 time: black_box(now),
@@ -126,6 +133,7 @@ fn bench_encoder_direction_detection(c: &mut Criterion) {
     // Counter-clockwise (64 -> 63)
     let mut processor_ccw = EventProcessor::new();
     processor_ccw.process(MidiEvent::ControlChange {
+        channel: 0,
         cc: 14,
         value: 64,
         time: now,
@@ -134,6 +142,7 @@ fn bench_encoder_direction_detection(c: &mut Criterion) {
     group.bench_function("counter_clockwise", |b| {
         b.iter(|| {
             let event = black_box(MidiEvent::ControlChange {
+                channel: 0,
                 cc: 14,
                 value: 63,
                 time: black_box(now),
@@ -154,6 +163,7 @@ fn bench_aftertouch_event(c: &mut Criterion) {
     c.bench_function("event_processing::aftertouch", |b| {
         b.iter(|| {
             let event = black_box(MidiEvent::Aftertouch {
+                channel: 0,
                 pressure: 80,
                 time: black_box(now),
             });
@@ -171,6 +181,7 @@ fn bench_pitch_bend_event(c: &mut Criterion) {
     c.bench_function("event_processing::pitch_bend", |b| {
         b.iter(|| {
             let event = black_box(MidiEvent::PitchBend {
+                channel: 0,
                 value: 8192,
                 time: black_box(now),
             });
@@ -188,6 +199,7 @@ fn bench_program_change_event(c: &mut Criterion) {
     c.bench_function("event_processing::program_change", |b| {
         b.iter(|| {
             let event = black_box(MidiEvent::ProgramChange {
+                channel: 0,
                 program: 5,
                 time: black_box(now),
             });
@@ -211,6 +223,7 @@ fn bench_consecutive_notes(c: &mut Criterion) {
             let mut local_processor = EventProcessor::new();
             for &note in &notes {
                 local_processor.process(MidiEvent::NoteOn {
+                    channel: 0,
                     note,
                     velocity: 64,
                     time: black_box(now),
@@ -25,12 +25,19 @@ fn create_config_with_mappings(num_mappings: usize) -> Config {
             trigger: Trigger::Note {
                 note: (36 + (i % 50)) as u8,
                 velocity_min: None,
+                channel: None,
             },
             action: ActionConfig::Keystroke {
                 keys: "a".to_string(),
                 modifiers: vec![],
             },
             description: None,
+            immediate: false,
+            on_release: None,
+            momentary: false,
+            continuous: None,
+            while_held: vec![],
+            concurrency: Default::default(),
         });
     }
 
@@ -51,6 +58,7 @@ fn create_config_with_mappings(num_mappings: usize) -> Config {
         global_mappings: vec![],
         logging: None,
         advanced_settings: Default::default(),
+        variables: Default::default(),
     }
 }
 
@@ -65,6 +73,7 @@ fn bench_simple_note_mapping(c: &mut Criterion) {
     c.bench_function("mapping_engine::simple_note", |b| {
         b.iter(|| {
             let event = black_box(MidiEvent::NoteOn {
+                channel: 0,
                 note: 36,
                 velocity: 64,
                 time: black_box(now),
@@ -85,6 +94,7 @@ fn bench_no_match_lookup(c: &mut Criterion) {
     c.bench_function("mapping_engine::no_match", |b| {
         b.iter(|| {
             let event = black_box(MidiEvent::NoteOn {
+                channel: 0,
                 note: 127, // Note not in mappings
                 velocity: 64,
                 time: black_box(now),
@@ -111,6 +121,7 @@ fn bench_mapping_set_scaling(c: &mut Criterion) {
             |b, _| {
                 b.iter(|| {
                     let event = black_box(MidiEvent::NoteOn {
+                        channel: 0,
                         note: 36 + (*size as u8 / 2),
                         velocity: 64,
                         time: black_box(now),
@@ -135,6 +146,7 @@ fn bench_mode_specific_lookup(c: &mut Criterion) {
     c.bench_function("mapping_engine::mode_lookup", |b| {
         b.iter(|| {
             let event = black_box(MidiEvent::NoteOn {
+                channel: 0,
                 note: 40,
                 velocity: 64,
                 time: black_box(now),
@@ -161,17 +173,26 @@ fn bench_cc_mapping_lookup(c: &mut Criterion) {
                 trigger: Trigger::CC {
                     cc: 14,
                     value_min: None,
+                    value_max: None,
+                    channel: None,
                 },
                 action: ActionConfig::Keystroke {
                     keys: "a".to_string(),
                     modifiers: vec![],
                 },
                 description: None,
+                immediate: false,
+                on_release: None,
+                momentary: false,
+                continuous: None,
+                while_held: vec![],
+                concurrency: Default::default(),
             }],
         }],
         global_mappings: vec![],
         logging: None,
         advanced_settings: Default::default(),
+        variables: Default::default(),
     };
 
     let mut engine = MappingEngine::new();
@@ -181,6 +202,7 @@ fn bench_cc_mapping_lookup(c: &mut Criterion) {
     c.bench_function("mapping_engine::cc_lookup", |b| {
         b.iter(|| {
             let event = black_box(MidiEvent::ControlChange {
+                channel: 0,
                 cc: 14,
                 value: 64,
                 time: black_box(now),
@@ -204,6 +226,7 @@ fn bench_mode_switching_with_lookup(c: &mut Criterion) {
 
             for &mode in &modes {
                 let event = black_box(MidiEvent::NoteOn {
+                    channel: 0,
                     note: 36,
                     velocity: 64,
                     time: black_box(now),
@@ -228,6 +251,7 @@ fn bench_rapid_note_lookups(c: &mut Criterion) {
         b.iter(|| {
             for note in 36..44 {
                 let event = black_box(MidiEvent::NoteOn {
+                    channel: 0,
                     note,
                     velocity: 64,
                     time: black_box(now),
@@ -243,6 +267,7 @@ fn bench_rapid_note_lookups(c: &mut Criterion) {
         b.iter(|| {
             for &note in &random_notes {
                 let event = black_box(MidiEvent::NoteOn {
+                    channel: 0,
                     note,
                     velocity: 64,
                     time: black_box(now),
@@ -266,6 +291,7 @@ fn bench_note_off_lookup(c: &mut Criterion) {
     c.bench_function("mapping_engine::note_off_lookup", |b| {
         b.iter(|| {
             let event = black_box(MidiEvent::NoteOff {
+                channel: 0,
                 note: 40,
                 time: black_box(now),
             });
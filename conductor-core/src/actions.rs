@@ -10,7 +10,8 @@
 //! The daemon layer (midimon-daemon/action_executor.rs) is responsible for converting
 //! these domain types to platform-specific types (e.g., enigo::Key) for execution.
 
-use crate::config::ActionConfig;
+use crate::config::{ActionConfig, CycleReset};
+use crate::variables::VariableValue;
 use serde::{Deserialize, Serialize};
 
 /// Platform-independent keyboard key codes
@@ -158,6 +159,36 @@ pub enum Condition {
         mode: String,
     },
 
+    /// The transport is playing, according to incoming MIDI Start, Continue
+    /// and Stop messages
+    TransportPlaying,
+
+    /// The tempo derived from incoming MIDI clock is within range
+    TempoRange {
+        /// Lowest tempo in BPM (inclusive)
+        min: f64,
+        /// Highest tempo in BPM (inclusive)
+        max: f64,
+    },
+
+    /// A runtime variable is set to a value
+    VariableEquals {
+        /// Variable name
+        name: String,
+        /// Value to compare with (boolean, number or string)
+        value: VariableValue,
+    },
+
+    /// A runtime variable is a number within range
+    VariableInRange {
+        /// Variable name
+        name: String,
+        /// Lowest value (inclusive)
+        min: f64,
+        /// Highest value (inclusive)
+        max: f64,
+    },
+
     /// Logical AND of multiple conditions
     And {
         /// Conditions that must all be true
@@ -191,12 +222,29 @@ pub enum Action {
     Launch(String),
     Shell(String),
     Sequence(Vec<Action>),
+    /// Run `actions` alongside each other, finishing when all have finished
+    /// if `wait` is set
+    Parallel {
+        actions: Vec<Action>,
+        wait: bool,
+    },
     Delay(u64),
     MouseClick {
         button: MouseButton,
         x: Option<i32>,
         y: Option<i32>,
     },
+    /// Move the pointer to `x`/`y`, or by `x`/`y` when `relative`
+    MouseMove {
+        x: Option<i32>,
+        y: Option<i32>,
+        relative: bool,
+    },
+    /// Scroll by `amount` wheel steps (positive = down or right)
+    MouseScroll {
+        amount: i32,
+        horizontal: bool,
+    },
     Repeat {
         action: Box<Action>,
         count: usize,
@@ -219,6 +267,8 @@ pub enum Action {
         message_type: MidiMessageType,
         channel: u8,
         params: MidiMessageParams,
+        /// Offset the note or controller by the trigger's range offset
+        relative: bool,
     },
     /// Plugin action (v2.3)
     ///
@@ -230,6 +280,34 @@ pub enum Action {
         /// Plugin-specific parameters (JSON object)
         params: serde_json::Value,
     },
+    SetVariable {
+        name: String,
+        value: VariableValue,
+    },
+    ToggleVariable {
+        name: String,
+    },
+    /// Add `amount` to a variable, clamped or wrapped to `min`..=`max`
+    IncrementVariable {
+        name: String,
+        amount: f64,
+        min: Option<f64>,
+        max: Option<f64>,
+        wrap: bool,
+    },
+    /// Run the next of `actions` each time, wrapping around (also used for
+    /// Toggle)
+    Cycle {
+        /// Identifies the cycle's position, see [`Action::assign_cycle_ids`]
+        id: String,
+        actions: Vec<Action>,
+        reset: CycleReset,
+    },
+    /// Stop the running actions of a mapping (by description or id), or all
+    /// other running actions
+    CancelAction {
+        mapping: Option<String>,
+    },
 }
 
 /// MIDI message type (v2.1)
@@ -332,6 +410,117 @@ pub enum VolumeOperation {
     Set,
 }
 
+impl Action {
+    /// Give each Cycle in the action an id derived from `id` and its place in
+    /// the action, so every mapping's cycles keep their own position
+    ///
+    /// Nested actions are numbered by their index, and the branches of a
+    /// Conditional are `then` and `else` (e.g. `Default/3/then/1`).
+    pub fn assign_cycle_ids(&mut self, id: &str) {
+        match self {
+            Action::Cycle {
+                id: cycle_id,
+                actions,
+                ..
+            } => {
+                *cycle_id = id.to_string();
+                for (i, action) in actions.iter_mut().enumerate() {
+                    action.assign_cycle_ids(&format!("{}/{}", id, i));
+                }
+            }
+            Action::Sequence(actions) | Action::Parallel { actions, .. } => {
+                for (i, action) in actions.iter_mut().enumerate() {
+                    action.assign_cycle_ids(&format!("{}/{}", id, i));
+                }
+            }
+            Action::Repeat { action, .. } => action.assign_cycle_ids(id),
+            Action::Conditional {
+                then_action,
+                else_action,
+                ..
+            } => {
+                then_action.assign_cycle_ids(&format!("{}/then", id));
+                if let Some(else_action) = else_action {
+                    else_action.assign_cycle_ids(&format!("{}/else", id));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Replace the action's value with the scaled value of a continuous mapping
+    ///
+    /// Sets the volume of a `VolumeControl`, the value of a `SendMidi` CC,
+    /// pitch bend or aftertouch, the missing coordinate of a `MouseMove`, the
+    /// amount of a `MouseScroll`, the `value` parameter of a `Plugin` and the
+    /// value of a `SetVariable`. The value is rounded and clamped to the
+    /// target's range (except for plugins and variables); other actions are
+    /// returned unchanged.
+    pub fn with_value(self, value: f64) -> Self {
+        let clamp = |min: f64, max: f64| value.round().clamp(min, max);
+        match self {
+            Action::VolumeControl { operation, .. } => Action::VolumeControl {
+                operation,
+                value: Some(clamp(0.0, 100.0) as u8),
+            },
+            Action::SendMidi {
+                port,
+                message_type,
+                channel,
+                params,
+                relative,
+            } => {
+                let params = match params {
+                    MidiMessageParams::CC { controller, .. } => MidiMessageParams::CC {
+                        controller,
+                        value: clamp(0.0, 127.0) as u8,
+                    },
+                    MidiMessageParams::PitchBend { .. } => MidiMessageParams::PitchBend {
+                        value: clamp(-8192.0, 8191.0) as i16,
+                    },
+                    MidiMessageParams::Aftertouch { .. } => MidiMessageParams::Aftertouch {
+                        pressure: clamp(0.0, 127.0) as u8,
+                    },
+                    params => params,
+                };
+                Action::SendMidi {
+                    port,
+                    message_type,
+                    channel,
+                    params,
+                    relative,
+                }
+            }
+            Action::MouseMove { x, y, relative } => {
+                // Only the coordinate left out of the pair takes the value
+                let value = clamp(i32::MIN as f64, i32::MAX as f64) as i32;
+                Action::MouseMove {
+                    x: x.or(y.map(|_| value)),
+                    y: y.or(x.map(|_| value)),
+                    relative,
+                }
+            }
+            Action::MouseScroll { horizontal, .. } => Action::MouseScroll {
+                amount: clamp(i32::MIN as f64, i32::MAX as f64) as i32,
+                horizontal,
+            },
+            Action::Plugin { plugin, mut params } => {
+                if let serde_json::Value::Object(map) = &mut params {
+                    map.insert("value".to_string(), serde_json::json!(value));
+                } else {
+                    params = serde_json::json!({ "value": value });
+                }
+                Action::Plugin { plugin, params }
+            }
+            Action::SetVariable { name, .. } => Action::SetVariable {
+                name,
+                value: VariableValue::Number(value),
+            },
+            action => action,
+        }
+    }
+}
+
 // ActionExecutor has been moved to midimon-daemon (Phase 2 refactor)
 // Only pure Action types and parsing remain in core
 
@@ -348,12 +537,20 @@ impl From<ActionConfig> for Action {
             ActionConfig::Sequence { actions } => {
                 Action::Sequence(actions.into_iter().map(Into::into).collect())
             }
+            ActionConfig::Parallel { actions, wait } => Action::Parallel {
+                actions: actions.into_iter().map(Into::into).collect(),
+                wait,
+            },
             ActionConfig::Delay { ms } => Action::Delay(ms),
             ActionConfig::MouseClick { button, x, y } => Action::MouseClick {
                 button: parse_mouse_button(&button),
                 x,
                 y,
             },
+            ActionConfig::MouseMove { x, y, relative } => Action::MouseMove { x, y, relative },
+            ActionConfig::MouseScroll { amount, horizontal } => {
+                Action::MouseScroll { amount, horizontal }
+            }
             ActionConfig::VolumeControl { operation, value } => Action::VolumeControl {
                 operation: parse_volume_operation(&operation),
                 value,
@@ -388,6 +585,7 @@ impl From<ActionConfig> for Action {
                 program,
                 pitch,
                 pressure,
+                relative,
             } => {
                 let msg_type = parse_midi_message_type(&message_type);
                 let params = match msg_type {
@@ -422,8 +620,40 @@ impl From<ActionConfig> for Action {
                     message_type: msg_type,
                     channel,
                     params,
+                    relative,
                 }
             }
+            ActionConfig::Plugin { plugin, params } => Action::Plugin {
+                plugin,
+                params: serde_json::Value::Object(params),
+            },
+            ActionConfig::SetVariable { name, value } => Action::SetVariable { name, value },
+            ActionConfig::ToggleVariable { name } => Action::ToggleVariable { name },
+            ActionConfig::IncrementVariable {
+                name,
+                amount,
+                min,
+                max,
+                wrap,
+            } => Action::IncrementVariable {
+                name,
+                amount,
+                min,
+                max,
+                wrap,
+            },
+            // Ids are assigned per mapping when the mapping is compiled
+            ActionConfig::Cycle { actions, reset } => Action::Cycle {
+                id: String::new(),
+                actions: actions.into_iter().map(Into::into).collect(),
+                reset,
+            },
+            ActionConfig::Toggle { on, off, reset } => Action::Cycle {
+                id: String::new(),
+                actions: vec![(*on).into(), (*off).into()],
+                reset,
+            },
+            ActionConfig::CancelAction { mapping } => Action::CancelAction { mapping },
         }
     }
 }
@@ -689,4 +919,96 @@ mod tests {
             _ => panic!("Expected ModeChange action"),
         }
     }
+
+    #[test]
+    fn test_plugin_action_conversion() {
+        use crate::config::ActionConfig;
+
+        let config: ActionConfig = toml::from_str(
+            r#"
+            type = "Plugin"
+            plugin = "obs"
+            params = { scene = "Camera 2", fade_ms = 300 }
+        "#,
+        )
+        .expect("Plugin action should parse");
+        let action: Action = config.into();
+
+        match action {
+            Action::Plugin { plugin, params } => {
+                assert_eq!(plugin, "obs");
+                assert_eq!(
+                    params,
+                    serde_json::json!({ "scene": "Camera 2", "fade_ms": 300 })
+                );
+            }
+            _ => panic!("Expected Plugin action"),
+        }
+
+        // Params are optional
+        let config: ActionConfig = toml::from_str("type = \"Plugin\"\nplugin = \"obs\"").unwrap();
+        assert!(matches!(
+            Action::from(config),
+            Action::Plugin { params, .. } if params == serde_json::json!({})
+        ));
+    }
+
+    #[test]
+    fn test_with_value() {
+        let volume = Action::VolumeControl {
+            operation: VolumeOperation::Set,
+            value: None,
+        };
+        assert!(matches!(
+            volume.with_value(142.0),
+            Action::VolumeControl {
+                value: Some(100),
+                ..
+            }
+        ));
+
+        let bend = Action::SendMidi {
+            port: "Out".to_string(),
+            message_type: MidiMessageType::PitchBend,
+            channel: 0,
+            params: MidiMessageParams::PitchBend { value: 0 },
+            relative: false,
+        };
+        assert!(matches!(
+            bend.with_value(-4095.6),
+            Action::SendMidi {
+                params: MidiMessageParams::PitchBend { value: -4096 },
+                ..
+            }
+        ));
+
+        let move_x = Action::MouseMove {
+            x: None,
+            y: Some(300),
+            relative: false,
+        };
+        assert!(matches!(
+            move_x.with_value(640.0),
+            Action::MouseMove {
+                x: Some(640),
+                y: Some(300),
+                ..
+            }
+        ));
+
+        let plugin = Action::Plugin {
+            plugin: "mixer".to_string(),
+            params: serde_json::json!({ "track": 1 }),
+        };
+        match plugin.with_value(0.25) {
+            Action::Plugin { params, .. } => {
+                assert_eq!(params, serde_json::json!({ "track": 1, "value": 0.25 }));
+            }
+            _ => panic!("Expected Plugin action"),
+        }
+
+        // Actions without a value are unchanged
+        let text = Action::Text("fader".to_string()).with_value(64.0);
+        assert!(matches!(text, Action::Text(ref t) if t == "fader"));
+    }
 }
@@ -6,11 +6,29 @@
 //! This module provides functionality to load configuration from files,
 //! save configuration to files, and validate configuration correctness.
 
+use crate::Condition;
 use crate::error::ConfigError;
+use crate::event_processor::{DEFAULT_BPM_MAX, DEFAULT_BPM_MIN};
 use std::collections::HashSet;
 use std::path::Path;
 
-use super::types::{ActionConfig, Config, DeviceConfig, Mapping, Mode, Trigger};
+use super::types::{
+    ActionConfig, Config, ContinuousValue, DeviceConfig, EncoderEncoding, Mapping, Mode,
+    SequenceStep, SysExByte, Trigger,
+};
+
+/// Largest accepted encoder `acceleration` (fast turns step up to 17x)
+const MAX_ENCODER_ACCELERATION: f32 = 16.0;
+
+/// The installed plugins, for checking the `Plugin` actions of a config
+///
+/// Implemented by the daemon's plugin manager. Core can only check a plugin
+/// action's structure; see [`Config::validate_with_plugins`].
+pub trait PluginCatalog {
+    /// Check that `plugin` is installed and may run, or explain why not in a
+    /// message naming the plugin
+    fn check_plugin(&self, plugin: &str) -> Result<(), String>;
+}
 
 impl Config {
     /// Load configuration from a TOML file
@@ -282,12 +300,19 @@ impl Config {
                         trigger: Trigger::Note {
                             note: 60,
                             velocity_min: Some(1),
+                            channel: None,
                         },
                         action: ActionConfig::Keystroke {
                             keys: "space".to_string(),
                             modifiers: vec!["cmd".to_string()],
                         },
                         description: Some("Spotlight Search".to_string()),
+                        immediate: false,
+                        on_release: None,
+                        momentary: false,
+                        continuous: None,
+                        while_held: vec![],
+                        concurrency: Default::default(),
                     }],
                 },
                 Mode {
@@ -297,17 +322,25 @@ impl Config {
                         trigger: Trigger::Note {
                             note: 60,
                             velocity_min: None,
+                            channel: None,
                         },
                         action: ActionConfig::Shell {
                             command: "git status".to_string(),
                         },
                         description: Some("Git status".to_string()),
+                        immediate: false,
+                        on_release: None,
+                        momentary: false,
+                        continuous: None,
+                        while_held: vec![],
+                        concurrency: Default::default(),
                     }],
                 },
             ],
             global_mappings: vec![],
             logging: None,
             advanced_settings: Default::default(),
+            variables: Default::default(),
         }
     }
 
@@ -349,17 +382,152 @@ impl Config {
 
         Ok(())
     }
+
+    /// Validate the configuration, and check that every plugin used by a
+    /// `Plugin` action is installed and has its capabilities granted
+    ///
+    /// # Returns
+    /// * `Ok(())` - Configuration is valid and its plugins can run
+    /// * `Err(ConfigError)` - Configuration has issues, or a plugin can't run
+    pub fn validate_with_plugins(&self, plugins: &dyn PluginCatalog) -> Result<(), ConfigError> {
+        self.validate()?;
+
+        for plugin in self.plugins() {
+            plugins
+                .check_plugin(plugin)
+                .map_err(ConfigError::InvalidAction)?;
+        }
+        Ok(())
+    }
+
+    /// Names of the plugins run by `Plugin` actions, including nested actions,
+    /// each listed once
+    pub fn plugins(&self) -> Vec<&str> {
+        let mut used = Vec::new();
+        for mapping in self
+            .global_mappings
+            .iter()
+            .chain(self.modes.iter().flat_map(|mode| &mode.mappings))
+        {
+            plugins_used(&mapping.action, &mut used);
+            if let Some(on_release) = &mapping.on_release {
+                plugins_used(on_release, &mut used);
+            }
+        }
+        used
+    }
+}
+
+/// Collect the plugins run by an action, including nested actions
+fn plugins_used<'a>(action: &'a ActionConfig, used: &mut Vec<&'a str>) {
+    match action {
+        ActionConfig::Plugin { plugin, .. } if !used.contains(&plugin.as_str()) => {
+            used.push(plugin);
+        }
+        ActionConfig::Sequence { actions }
+        | ActionConfig::Parallel { actions, .. }
+        | ActionConfig::Cycle { actions, .. } => {
+            for action in actions {
+                plugins_used(action, used);
+            }
+        }
+        ActionConfig::Repeat { action, .. } => plugins_used(action, used),
+        ActionConfig::Toggle { on, off, .. } => {
+            plugins_used(on, used);
+            plugins_used(off, used);
+        }
+        ActionConfig::Conditional {
+            then_action,
+            else_action,
+            ..
+        } => {
+            plugins_used(then_action, used);
+            if let Some(else_action) = else_action {
+                plugins_used(else_action, used);
+            }
+        }
+        _ => {}
+    }
 }
 
 /// Validate a single mapping
 fn validate_mapping(mapping: &Mapping) -> Result<(), ConfigError> {
     validate_trigger(&mapping.trigger)?;
-    validate_action(&mapping.action)?;
+    match &mapping.continuous {
+        Some(continuous) => validate_continuous(continuous, &mapping.action)?,
+        None => validate_action(&mapping.action)?,
+    }
+    if let Some(on_release) = &mapping.on_release {
+        validate_action(on_release)?;
+    }
+    if mapping.momentary
+        && !matches!(
+            mapping.action,
+            ActionConfig::Keystroke { .. } | ActionConfig::ModeChange { .. }
+        )
+    {
+        return Err(ConfigError::InvalidAction(
+            "momentary mappings require a Keystroke or ModeChange action".to_string(),
+        ));
+    }
     Ok(())
 }
 
+/// Validate a continuous mapping's scaling and value-taking action
+fn validate_continuous(
+    continuous: &ContinuousValue,
+    action: &ActionConfig,
+) -> Result<(), ConfigError> {
+    if !continuous.min.is_finite() || !continuous.max.is_finite() {
+        return Err(ConfigError::InvalidAction(
+            "continuous min and max must be finite numbers".to_string(),
+        ));
+    }
+    if !(0.0..=1.0).contains(&continuous.intensity) {
+        return Err(ConfigError::InvalidAction(format!(
+            "continuous curve intensity must be 0.0-1.0, got {}",
+            continuous.intensity
+        )));
+    }
+
+    let takes_value = match action {
+        ActionConfig::VolumeControl { operation, .. } => operation == "Set",
+        ActionConfig::SendMidi { message_type, .. } => matches!(
+            message_type.to_lowercase().replace(['_', '-'], "").as_str(),
+            "cc" | "controlchange" | "pitchbend" | "aftertouch"
+        ),
+        ActionConfig::MouseMove { x, y, .. } => x.is_none() != y.is_none(),
+        ActionConfig::MouseScroll { .. }
+        | ActionConfig::Plugin { .. }
+        | ActionConfig::SetVariable { .. } => true,
+        _ => false,
+    };
+    if !takes_value {
+        return Err(ConfigError::InvalidAction(
+            "continuous mappings require a VolumeControl Set, SendMidi CC/PitchBend/Aftertouch, \
+             MouseMove with one coordinate left out, MouseScroll, Plugin or SetVariable action"
+                .to_string(),
+        ));
+    }
+
+    // The control supplies the volume that Set otherwise requires
+    match action {
+        ActionConfig::VolumeControl { .. } => Ok(()),
+        _ => validate_action(action),
+    }
+}
+
 /// Validate a trigger configuration
 fn validate_trigger(trigger: &Trigger) -> Result<(), ConfigError> {
+    if let Some(channel) = trigger.channel()
+        && channel > 15
+    {
+        return Err(ConfigError::InvalidTrigger(format!(
+            "MIDI channel out of range: {} (must be 0-15)",
+            channel
+        )));
+    }
+
     match trigger {
         Trigger::Note { note, .. } => {
             if *note > 127 {
@@ -369,21 +537,41 @@ fn validate_trigger(trigger: &Trigger) -> Result<(), ConfigError> {
                 )));
             }
         }
-        Trigger::VelocityRange { note, .. } => {
+        Trigger::NoteRange { min, max, .. } => {
+            validate_range("Note number", *min, *max)?;
+        }
+        Trigger::VelocityRange { note, level, .. } => {
             if *note > 127 {
                 return Err(ConfigError::InvalidTrigger(format!(
                     "Note number out of range: {} (must be 0-127)",
                     note
                 )));
             }
+            if let Some(level) = level
+                && level != "Soft"
+                && level != "Medium"
+                && level != "Hard"
+            {
+                return Err(ConfigError::InvalidTrigger(format!(
+                    "Invalid velocity level: '{}' (must be 'Soft', 'Medium' or 'Hard')",
+                    level
+                )));
+            }
         }
-        Trigger::LongPress { note, .. } => {
+        Trigger::LongPress {
+            note, repeat_ms, ..
+        } => {
             if *note > 127 {
                 return Err(ConfigError::InvalidTrigger(format!(
                     "Note number out of range: {} (must be 0-127)",
                     note
                 )));
             }
+            if *repeat_ms == Some(0) {
+                return Err(ConfigError::InvalidTrigger(
+                    "LongPress repeat_ms must be greater than 0".to_string(),
+                ));
+            }
         }
         Trigger::DoubleTap { note, .. } => {
             if *note > 127 {
@@ -408,12 +596,58 @@ fn validate_trigger(trigger: &Trigger) -> Result<(), ConfigError> {
                 ));
             }
         }
-        Trigger::EncoderTurn { cc, direction } => {
-            if *cc > 127 {
-                return Err(ConfigError::InvalidTrigger(format!(
-                    "CC number out of range: {} (must be 0-127)",
-                    cc
-                )));
+        Trigger::Sequence {
+            steps, max_gap_ms, ..
+        } => {
+            if steps.is_empty() {
+                return Err(ConfigError::InvalidTrigger(
+                    "Sequence must have at least one step".to_string(),
+                ));
+            }
+            if *max_gap_ms == Some(0) {
+                return Err(ConfigError::InvalidTrigger(
+                    "Sequence max_gap_ms must be greater than 0".to_string(),
+                ));
+            }
+            for step in steps {
+                match step {
+                    SequenceStep::Note { note } if *note > 127 => {
+                        return Err(ConfigError::InvalidTrigger(format!(
+                            "Note number out of range: {} (must be 0-127)",
+                            note
+                        )));
+                    }
+                    SequenceStep::CC { cc } if *cc > 127 => {
+                        return Err(ConfigError::InvalidTrigger(format!(
+                            "CC number out of range: {} (must be 0-127)",
+                            cc
+                        )));
+                    }
+                    SequenceStep::GamepadButton { button } if *button < 128 => {
+                        return Err(ConfigError::InvalidTrigger(format!(
+                            "Gamepad button ID out of range: {} (must be 128-255 to avoid MIDI conflicts)",
+                            button
+                        )));
+                    }
+                    _ => {}
+                }
+            }
+        }
+        Trigger::EncoderTurn {
+            cc,
+            direction,
+            encoding,
+            decrement_cc,
+            acceleration,
+            ..
+        } => {
+            for cc in std::iter::once(cc).chain(decrement_cc) {
+                if *cc > 127 {
+                    return Err(ConfigError::InvalidTrigger(format!(
+                        "CC number out of range: {} (must be 0-127)",
+                        cc
+                    )));
+                }
             }
             if let Some(dir) = direction
                 && dir != "Clockwise"
@@ -424,20 +658,218 @@ fn validate_trigger(trigger: &Trigger) -> Result<(), ConfigError> {
                     dir
                 )));
             }
+            match (encoding, decrement_cc) {
+                (EncoderEncoding::IncDec, None) => {
+                    return Err(ConfigError::InvalidTrigger(
+                        "IncDec encoders require decrement_cc".to_string(),
+                    ));
+                }
+                (EncoderEncoding::IncDec, Some(decrement)) if decrement == cc => {
+                    return Err(ConfigError::InvalidTrigger(format!(
+                        "decrement_cc must differ from cc ({})",
+                        cc
+                    )));
+                }
+                (EncoderEncoding::IncDec, Some(_)) | (_, None) => {}
+                (_, Some(_)) => {
+                    return Err(ConfigError::InvalidTrigger(
+                        "decrement_cc is only used by IncDec encoders".to_string(),
+                    ));
+                }
+            }
+            if let Some(acceleration) = acceleration
+                && !(0.0..=MAX_ENCODER_ACCELERATION).contains(acceleration)
+            {
+                return Err(ConfigError::InvalidTrigger(format!(
+                    "Encoder acceleration must be 0.0-{}, got {}",
+                    MAX_ENCODER_ACCELERATION, acceleration
+                )));
+            }
         }
-        Trigger::CC { cc, .. } => {
+        Trigger::CC {
+            cc,
+            value_min,
+            value_max,
+            ..
+        } => {
             if *cc > 127 {
                 return Err(ConfigError::InvalidTrigger(format!(
                     "CC number out of range: {} (must be 0-127)",
                     cc
                 )));
             }
+            validate_range("CC value", value_min.unwrap_or(0), value_max.unwrap_or(127))?;
+        }
+        Trigger::CCZone {
+            cc,
+            zones,
+            zone,
+            hysteresis,
+            ..
+        } => {
+            validate_range("CC number", *cc, *cc)?;
+            if !(2..=128).contains(zones) {
+                return Err(ConfigError::InvalidTrigger(format!(
+                    "CCZone zones out of range: {} (must be 2-128)",
+                    zones
+                )));
+            }
+            if let Some(zone) = zone
+                && zone >= zones
+            {
+                return Err(ConfigError::InvalidTrigger(format!(
+                    "CCZone zone {} out of range (must be 0-{})",
+                    zone,
+                    zones - 1
+                )));
+            }
+            validate_range("CCZone hysteresis", hysteresis.unwrap_or(0), 127)?;
+        }
+        Trigger::CCThreshold {
+            cc,
+            threshold,
+            hysteresis,
+            ..
+        } => {
+            validate_range("CC number", *cc, *cc)?;
+            validate_range("CCThreshold threshold", *threshold, *threshold)?;
+            validate_range("CCThreshold hysteresis", hysteresis.unwrap_or(0), 127)?;
+        }
+        Trigger::CCRange { min, max, .. } => {
+            validate_range("CC number", *min, *max)?;
+        }
+        Trigger::Transport { .. } => {
+            // Valid trigger, no specific validation needed
+        }
+        Trigger::SongPosition {
+            position_min,
+            position_max,
+        } => {
+            if let (Some(min), Some(max)) = (position_min, position_max)
+                && min > max
+            {
+                return Err(ConfigError::InvalidTrigger(format!(
+                    "Song position_min ({}) is greater than position_max ({})",
+                    min, max
+                )));
+            }
+        }
+        Trigger::Tempo { bpm_min, bpm_max } => {
+            let min = bpm_min.unwrap_or(DEFAULT_BPM_MIN);
+            let max = bpm_max.unwrap_or(DEFAULT_BPM_MAX);
+            if !(min.is_finite() && max.is_finite() && min >= 0.0 && min < max) {
+                return Err(ConfigError::InvalidTrigger(format!(
+                    "Tempo range must be 0 <= bpm_min < bpm_max, got {} to {}",
+                    min, max
+                )));
+            }
+        }
+        Trigger::SysEx { pattern } => {
+            SysExByte::parse_pattern(pattern).map_err(ConfigError::InvalidTrigger)?;
         }
         Trigger::Aftertouch { .. } => {
             // Valid trigger, no specific validation needed
         }
-        Trigger::PitchBend { .. } => {
-            // Valid trigger, range validation is optional as values can be None
+        Trigger::PolyPressure { note, .. } => {
+            if *note > 127 {
+                return Err(ConfigError::InvalidTrigger(format!(
+                    "Note number out of range: {} (must be 0-127)",
+                    note
+                )));
+            }
+        }
+        Trigger::ProgramChange { program, .. } => {
+            if *program > 127 {
+                return Err(ConfigError::InvalidTrigger(format!(
+                    "Program number out of range: {} (must be 0-127)",
+                    program
+                )));
+            }
+        }
+        Trigger::PitchBend {
+            value_min,
+            value_max,
+            ..
+        } => {
+            validate_14bit_range("Pitch bend", *value_min, *value_max)?;
+        }
+        Trigger::TouchSwipe {
+            min_speed,
+            min_distance,
+            cc,
+            ..
+        } => {
+            validate_touch_source(*cc)?;
+            if let Some(speed) = min_speed
+                && !(speed.is_finite() && *speed >= 0.0)
+            {
+                return Err(ConfigError::InvalidTrigger(format!(
+                    "TouchSwipe min_speed must be a non-negative number, got {}",
+                    speed
+                )));
+            }
+            validate_touch_distance("TouchSwipe", *min_distance)?;
+        }
+        Trigger::TouchTap {
+            zones, zone, cc, ..
+        } => {
+            validate_touch_source(*cc)?;
+            let zones = zones.unwrap_or(1);
+            if !(1..=128).contains(&zones) {
+                return Err(ConfigError::InvalidTrigger(format!(
+                    "TouchTap zones out of range: {} (must be 1-128)",
+                    zones
+                )));
+            }
+            if let Some(zone) = zone
+                && *zone >= zones
+            {
+                return Err(ConfigError::InvalidTrigger(format!(
+                    "TouchTap zone {} out of range (must be 0-{})",
+                    zone,
+                    zones - 1
+                )));
+            }
+        }
+        Trigger::TouchSlideHold {
+            min_distance, cc, ..
+        } => {
+            validate_touch_source(*cc)?;
+            validate_touch_distance("TouchSlideHold", *min_distance)?;
+        }
+        Trigger::HighResCC {
+            cc,
+            value_min,
+            value_max,
+            ..
+        } => {
+            if *cc > 31 {
+                return Err(ConfigError::InvalidTrigger(format!(
+                    "High-resolution CC number out of range: {} (must be 0-31)",
+                    cc
+                )));
+            }
+            validate_14bit_range("High-resolution CC", *value_min, *value_max)?;
+        }
+        Trigger::Nrpn {
+            parameter,
+            value_min,
+            value_max,
+            ..
+        }
+        | Trigger::Rpn {
+            parameter,
+            value_min,
+            value_max,
+            ..
+        } => {
+            if *parameter > 16383 {
+                return Err(ConfigError::InvalidTrigger(format!(
+                    "Parameter number out of range: {} (must be 0-16383)",
+                    parameter
+                )));
+            }
+            validate_14bit_range("Parameter", *value_min, *value_max)?;
         }
         // Gamepad triggers (v3.0)
         Trigger::GamepadButton { button, .. } => {
@@ -492,6 +924,103 @@ fn validate_trigger(trigger: &Trigger) -> Result<(), ConfigError> {
     Ok(())
 }
 
+/// Validate a `min`..=`max` range of 7-bit note or CC numbers
+fn validate_range(what: &str, min: u8, max: u8) -> Result<(), ConfigError> {
+    for value in [min, max] {
+        if value > 127 {
+            return Err(ConfigError::InvalidTrigger(format!(
+                "{} out of range: {} (must be 0-127)",
+                what, value
+            )));
+        }
+    }
+    if min > max {
+        return Err(ConfigError::InvalidTrigger(format!(
+            "{} range min ({}) is greater than max ({})",
+            what, min, max
+        )));
+    }
+    Ok(())
+}
+
+/// Validate the CC a touch strip gesture trigger reads, if any
+fn validate_touch_source(cc: Option<u8>) -> Result<(), ConfigError> {
+    match cc {
+        Some(cc) => validate_range("CC number", cc, cc),
+        None => Ok(()),
+    }
+}
+
+/// Validate a gesture `min_distance`, a fraction of the strip
+fn validate_touch_distance(what: &str, min_distance: Option<f32>) -> Result<(), ConfigError> {
+    if let Some(distance) = min_distance
+        && !(distance > 0.0 && distance <= 1.0)
+    {
+        return Err(ConfigError::InvalidTrigger(format!(
+            "{} min_distance must be above 0.0 and at most 1.0, got {}",
+            what, distance
+        )));
+    }
+    Ok(())
+}
+
+/// Validate the ranges of a condition and its nested conditions
+fn validate_condition(condition: &Condition) -> Result<(), ConfigError> {
+    match condition {
+        Condition::TempoRange { min, max }
+            if !(min.is_finite() && max.is_finite() && min <= max) =>
+        {
+            return Err(ConfigError::InvalidAction(format!(
+                "TempoRange min ({}) must not be greater than max ({})",
+                min, max
+            )));
+        }
+        Condition::VariableEquals { name, .. } => validate_variable_name(name)?,
+        Condition::VariableInRange { name, min, max } => {
+            validate_variable_name(name)?;
+            if !(min.is_finite() && max.is_finite() && min <= max) {
+                return Err(ConfigError::InvalidAction(format!(
+                    "VariableInRange min ({}) must not be greater than max ({})",
+                    min, max
+                )));
+            }
+        }
+        Condition::And { conditions } | Condition::Or { conditions } => {
+            for condition in conditions {
+                validate_condition(condition)?;
+            }
+        }
+        Condition::Not { condition } => validate_condition(condition)?,
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Validate an optional 14-bit value_min/value_max range
+fn validate_14bit_range(
+    what: &str,
+    value_min: Option<u16>,
+    value_max: Option<u16>,
+) -> Result<(), ConfigError> {
+    for value in [value_min, value_max].into_iter().flatten() {
+        if value > 16383 {
+            return Err(ConfigError::InvalidTrigger(format!(
+                "{} value out of range: {} (must be 0-16383)",
+                what, value
+            )));
+        }
+    }
+    if let (Some(min), Some(max)) = (value_min, value_max)
+        && min > max
+    {
+        return Err(ConfigError::InvalidTrigger(format!(
+            "{} value_min ({}) is greater than value_max ({})",
+            what, min, max
+        )));
+    }
+    Ok(())
+}
+
 /// Validate shell command for security (prevents command injection)
 ///
 /// Blocks dangerous patterns that could enable command injection attacks:
@@ -632,6 +1161,30 @@ fn validate_action(action: &ActionConfig) -> Result<(), ConfigError> {
                 validate_action(action)?;
             }
         }
+        ActionConfig::Parallel { actions, .. } => {
+            if actions.is_empty() {
+                return Err(ConfigError::InvalidAction(
+                    "Parallel requires at least one action".to_string(),
+                ));
+            }
+            for action in actions {
+                validate_action(action)?;
+            }
+        }
+        ActionConfig::Cycle { actions, .. } => {
+            if actions.is_empty() {
+                return Err(ConfigError::InvalidAction(
+                    "Cycle requires at least one action".to_string(),
+                ));
+            }
+            for action in actions {
+                validate_action(action)?;
+            }
+        }
+        ActionConfig::Toggle { on, off, .. } => {
+            validate_action(on)?;
+            validate_action(off)?;
+        }
         ActionConfig::Delay { ms } => {
             if *ms == 0 {
                 return Err(ConfigError::InvalidAction(
@@ -649,6 +1202,14 @@ fn validate_action(action: &ActionConfig) -> Result<(), ConfigError> {
                 )));
             }
         }
+        ActionConfig::MouseMove { x, y, .. } => {
+            if x.is_none() && y.is_none() {
+                return Err(ConfigError::InvalidAction(
+                    "MouseMove requires x or y".to_string(),
+                ));
+            }
+        }
+        ActionConfig::MouseScroll { .. } => {}
         ActionConfig::VolumeControl { operation, value } => {
             let valid_ops = ["Up", "Down", "Mute", "Unmute", "Set"];
             if !valid_ops.contains(&operation.as_str()) {
@@ -684,10 +1245,11 @@ fn validate_action(action: &ActionConfig) -> Result<(), ConfigError> {
             validate_action(action)?;
         }
         ActionConfig::Conditional {
+            condition,
             then_action,
             else_action,
-            ..
         } => {
+            validate_condition(condition)?;
             validate_action(then_action)?;
             if let Some(else_act) = else_action {
                 validate_action(else_act)?;
@@ -704,6 +1266,7 @@ fn validate_action(action: &ActionConfig) -> Result<(), ConfigError> {
             program,
             pitch,
             pressure,
+            ..
         } => {
             // Validate port name
             if port.is_empty() {
@@ -806,6 +1369,64 @@ fn validate_action(action: &ActionConfig) -> Result<(), ConfigError> {
                 )));
             }
         }
+        ActionConfig::Plugin { plugin, .. } => {
+            if plugin.is_empty() {
+                return Err(ConfigError::InvalidAction(
+                    "Plugin action requires plugin name".to_string(),
+                ));
+            }
+        }
+        ActionConfig::SetVariable { name, .. } | ActionConfig::ToggleVariable { name } => {
+            validate_variable_name(name)?;
+        }
+        ActionConfig::IncrementVariable {
+            name,
+            amount,
+            min,
+            max,
+            wrap,
+        } => {
+            validate_variable_name(name)?;
+            if !amount.is_finite() {
+                return Err(ConfigError::InvalidAction(format!(
+                    "IncrementVariable amount must be a finite number, got {}",
+                    amount
+                )));
+            }
+            if let (Some(min), Some(max)) = (min, max)
+                && min > max
+            {
+                return Err(ConfigError::InvalidAction(format!(
+                    "IncrementVariable min ({}) must not be greater than max ({})",
+                    min, max
+                )));
+            }
+            if *wrap && (min.is_none() || max.is_none()) {
+                return Err(ConfigError::InvalidAction(
+                    "IncrementVariable wrap requires both min and max".to_string(),
+                ));
+            }
+        }
+        ActionConfig::CancelAction { mapping } => {
+            if mapping
+                .as_ref()
+                .is_some_and(|mapping| mapping.trim().is_empty())
+            {
+                return Err(ConfigError::InvalidAction(
+                    "CancelAction mapping must not be empty".to_string(),
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Validate the name of a runtime variable
+fn validate_variable_name(name: &str) -> Result<(), ConfigError> {
+    if name.trim().is_empty() {
+        return Err(ConfigError::InvalidAction(
+            "Variable name must not be empty".to_string(),
+        ));
     }
     Ok(())
 }
@@ -813,6 +1434,8 @@ fn validate_action(action: &ActionConfig) -> Result<(), ConfigError> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::ConcurrencyPolicy;
+    use crate::variables::VariableValue;
 
     #[test]
     fn test_config_default() {
@@ -848,6 +1471,7 @@ mod tests {
         config.modes[0].mappings[0].trigger = Trigger::Note {
             note: 128,
             velocity_min: None,
+            channel: None,
         };
 
         let result = config.validate();
@@ -874,6 +1498,10 @@ mod tests {
         config.modes[0].mappings[0].trigger = Trigger::EncoderTurn {
             cc: 1,
             direction: Some("Invalid".to_string()),
+            channel: None,
+            encoding: EncoderEncoding::Absolute,
+            decrement_cc: None,
+            acceleration: None,
         };
 
         let result = config.validate();
@@ -920,6 +1548,10 @@ mod tests {
         config.modes[0].mappings[0].trigger = Trigger::EncoderTurn {
             cc: 1,
             direction: Some("Clockwise".to_string()),
+            channel: None,
+            encoding: EncoderEncoding::Absolute,
+            decrement_cc: None,
+            acceleration: None,
         };
 
         let result = config.validate();
@@ -932,22 +1564,492 @@ mod tests {
         config.modes[0].mappings[0].trigger = Trigger::EncoderTurn {
             cc: 1,
             direction: Some("CounterClockwise".to_string()),
+            channel: None,
+            encoding: EncoderEncoding::Absolute,
+            decrement_cc: None,
+            acceleration: None,
         };
 
         let result = config.validate();
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_validate_encoder_encoding() {
+        let encoder = |encoding, decrement_cc, acceleration| Trigger::EncoderTurn {
+            cc: 20,
+            direction: None,
+            channel: None,
+            encoding,
+            decrement_cc,
+            acceleration,
+        };
+        let mut config = Config::default_config();
+
+        config.modes[0].mappings[0].trigger = encoder(EncoderEncoding::IncDec, None, None);
+        assert!(config.validate().is_err(), "IncDec needs a decrement CC");
+
+        config.modes[0].mappings[0].trigger = encoder(EncoderEncoding::IncDec, Some(20), None);
+        assert!(config.validate().is_err(), "Decrement CC must differ");
+
+        config.modes[0].mappings[0].trigger =
+            encoder(EncoderEncoding::TwosComplement, Some(21), None);
+        assert!(
+            config.validate().is_err(),
+            "Only IncDec uses a decrement CC"
+        );
+
+        config.modes[0].mappings[0].trigger = encoder(EncoderEncoding::SignedBit, None, Some(-1.0));
+        assert!(config.validate().is_err());
+
+        config.modes[0].mappings[0].trigger = encoder(EncoderEncoding::IncDec, Some(21), Some(3.0));
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_high_res_triggers() {
+        let mut config = Config::default_config();
+
+        config.modes[0].mappings[0].trigger = Trigger::HighResCC {
+            cc: 39,
+            value_min: None,
+            value_max: None,
+            channel: None,
+        };
+        assert!(
+            config.validate().is_err(),
+            "LSB CCs are not MSB controllers"
+        );
+
+        config.modes[0].mappings[0].trigger = Trigger::Nrpn {
+            parameter: 16384,
+            value_min: None,
+            value_max: None,
+            channel: None,
+        };
+        assert!(config.validate().is_err());
+
+        config.modes[0].mappings[0].trigger = Trigger::Rpn {
+            parameter: 0,
+            value_min: Some(200),
+            value_max: Some(100),
+            channel: None,
+        };
+        assert!(config.validate().is_err());
+
+        config.modes[0].mappings[0].trigger = Trigger::HighResCC {
+            cc: 7,
+            value_min: Some(8192),
+            value_max: None,
+            channel: Some(0),
+        };
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_cc_zone_and_threshold_triggers() {
+        let mut config = Config::default_config();
+
+        config.modes[0].mappings[0].trigger = Trigger::CC {
+            cc: 1,
+            value_min: Some(100),
+            value_max: Some(50),
+            channel: None,
+        };
+        assert!(config.validate().is_err());
+
+        config.modes[0].mappings[0].trigger = Trigger::CCZone {
+            cc: 7,
+            zones: 1,
+            zone: None,
+            hysteresis: None,
+            channel: None,
+        };
+        assert!(config.validate().is_err(), "A single zone never changes");
+
+        config.modes[0].mappings[0].trigger = Trigger::CCZone {
+            cc: 7,
+            zones: 4,
+            zone: Some(4),
+            hysteresis: None,
+            channel: None,
+        };
+        assert!(config.validate().is_err());
+
+        config.modes[0].mappings[0].trigger = Trigger::CCThreshold {
+            cc: 7,
+            threshold: 128,
+            direction: crate::CrossingDirection::Rising,
+            hysteresis: None,
+            channel: None,
+        };
+        assert!(config.validate().is_err());
+
+        config.modes[0].mappings[0].trigger = Trigger::CCThreshold {
+            cc: 7,
+            threshold: 64,
+            direction: crate::CrossingDirection::Falling,
+            hysteresis: Some(8),
+            channel: None,
+        };
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_invalid_velocity_level() {
+        let mut config = Config::default_config();
+        config.modes[0].mappings[0].trigger = Trigger::VelocityRange {
+            note: 36,
+            soft_max: None,
+            medium_max: None,
+            level: Some("Loud".to_string()),
+            channel: None,
+        };
+
+        let result = config.validate();
+        assert!(result.is_err());
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("Invalid velocity level")
+        );
+    }
+
+    #[test]
+    fn test_validate_pitch_bend_inverted_range() {
+        let mut config = Config::default_config();
+        config.modes[0].mappings[0].trigger = Trigger::PitchBend {
+            value_min: Some(12000),
+            value_max: Some(4000),
+            channel: None,
+        };
+
+        let result = config.validate();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_validate_invalid_midi_channel() {
+        let mut config = Config::default_config();
+        config.modes[0].mappings[0].trigger = Trigger::Note {
+            note: 36,
+            velocity_min: None,
+            channel: Some(16),
+        };
+
+        let result = config.validate();
+        assert!(result.is_err());
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("MIDI channel out of range")
+        );
+
+        config.modes[0].mappings[0].trigger = Trigger::Note {
+            note: 36,
+            velocity_min: None,
+            channel: Some(15),
+        };
+        assert!(config.validate().is_ok());
+    }
+
     #[test]
     fn test_validate_note_chord_with_empty_notes() {
         let mut config = Config::default_config();
         config.modes[0].mappings[0].trigger = Trigger::NoteChord {
             notes: vec![],
             timeout_ms: None,
+            channel: None,
+        };
+
+        let result = config.validate();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_validate_long_press_zero_repeat() {
+        let mut config = Config::default_config();
+        config.modes[0].mappings[0].trigger = Trigger::LongPress {
+            note: 36,
+            duration_ms: None,
+            repeat_ms: Some(0),
+            channel: None,
+        };
+
+        let result = config.validate();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_validate_momentary_requires_keystroke_or_mode_change() {
+        let mut config = Config::default_config();
+        config.modes[0].mappings[0].action = ActionConfig::Text {
+            text: "hello".to_string(),
         };
+        config.modes[0].mappings[0].momentary = true;
 
         let result = config.validate();
         assert!(result.is_err());
+
+        config.modes[0].mappings[0].action = ActionConfig::Keystroke {
+            keys: "shift".to_string(),
+            modifiers: vec![],
+        };
+        assert!(config.validate().is_ok());
+        config.modes[0].mappings[0].action = ActionConfig::ModeChange {
+            mode: "Default".to_string(),
+        };
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_invalid_on_release_action() {
+        let mut config = Config::default_config();
+        config.modes[0].mappings[0].on_release = Some(ActionConfig::Keystroke {
+            keys: "".to_string(),
+            modifiers: vec![],
+        });
+
+        let result = config.validate();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_validate_sequence() {
+        let mut config = Config::default_config();
+        config.modes[0].mappings[0].trigger = Trigger::Sequence {
+            steps: vec![],
+            max_gap_ms: None,
+            suppress_steps: false,
+            channel: None,
+        };
+        assert!(config.validate().is_err());
+
+        config.modes[0].mappings[0].trigger = Trigger::Sequence {
+            steps: vec![
+                SequenceStep::Note { note: 36 },
+                SequenceStep::GamepadButton { button: 12 },
+            ],
+            max_gap_ms: None,
+            suppress_steps: false,
+            channel: None,
+        };
+        assert!(config.validate().is_err());
+
+        config.modes[0].mappings[0].trigger = Trigger::Sequence {
+            steps: vec![SequenceStep::Note { note: 36 }, SequenceStep::CC { cc: 20 }],
+            max_gap_ms: Some(400),
+            suppress_steps: true,
+            channel: None,
+        };
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_note_range() {
+        let mut config = Config::default_config();
+        config.modes[0].mappings[0].trigger = Trigger::NoteRange {
+            min: 51,
+            max: 36,
+            velocity_min: None,
+            channel: None,
+        };
+        assert!(config.validate().is_err());
+
+        config.modes[0].mappings[0].trigger = Trigger::CCRange {
+            min: 100,
+            max: 128,
+            value_min: None,
+            channel: None,
+        };
+        assert!(config.validate().is_err());
+
+        config.modes[0].mappings[0].trigger = Trigger::NoteRange {
+            min: 36,
+            max: 51,
+            velocity_min: None,
+            channel: None,
+        };
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_continuous() {
+        let mut config = Config::default_config();
+        let mapping = &mut config.modes[0].mappings[0];
+        mapping.continuous = Some(ContinuousValue {
+            min: 0.0,
+            max: 100.0,
+            curve: None,
+            intensity: 0.5,
+        });
+
+        // The control supplies the value a Set would otherwise need
+        mapping.action = ActionConfig::VolumeControl {
+            operation: "Set".to_string(),
+            value: None,
+        };
+        assert!(config.validate().is_ok());
+
+        // Actions without a value are rejected
+        config.modes[0].mappings[0].action = ActionConfig::Text {
+            text: "fader".to_string(),
+        };
+        assert!(config.validate().is_err());
+
+        // MouseMove must leave exactly one coordinate to the control
+        config.modes[0].mappings[0].action = ActionConfig::MouseMove {
+            x: None,
+            y: None,
+            relative: false,
+        };
+        assert!(config.validate().is_err());
+        config.modes[0].mappings[0].action = ActionConfig::MouseMove {
+            x: None,
+            y: Some(500),
+            relative: false,
+        };
+        assert!(config.validate().is_ok());
+
+        config.modes[0].mappings[0].continuous = Some(ContinuousValue {
+            min: 0.0,
+            max: 100.0,
+            curve: None,
+            intensity: 2.0,
+        });
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_validate_variables() {
+        let config: Config = toml::from_str(
+            r#"
+            [device]
+            name = "Test"
+            auto_connect = false
+
+            [variables]
+            persist = true
+            initial = { scene = 1, muted = false }
+
+            [[modes]]
+            name = "Default"
+
+            [[modes.mappings]]
+            trigger = { type = "Note", note = 36 }
+            action = { type = "IncrementVariable", name = "scene", min = 1, max = 3, wrap = true }
+
+            [[modes.mappings]]
+            trigger = { type = "Note", note = 37 }
+            action = { type = "Conditional", condition = { VariableEquals = { name = "muted", value = true } }, then_action = { type = "SetVariable", name = "muted", value = false }, else_action = { type = "ToggleVariable", name = "muted" } }
+        "#,
+        )
+        .unwrap();
+        assert!(config.validate().is_ok());
+        assert!(config.variables.persist);
+        assert_eq!(
+            config.variables.initial.get("scene"),
+            Some(&VariableValue::Number(1.0))
+        );
+
+        let increment = |min, max, wrap| ActionConfig::IncrementVariable {
+            name: "scene".to_string(),
+            amount: 1.0,
+            min,
+            max,
+            wrap,
+        };
+        let mut config = Config::default_config();
+        config.modes[0].mappings[0].action = increment(Some(3.0), Some(1.0), false);
+        assert!(config.validate().is_err());
+        // Wrapping needs both bounds
+        config.modes[0].mappings[0].action = increment(Some(1.0), None, true);
+        assert!(config.validate().is_err());
+
+        config.modes[0].mappings[0].action = ActionConfig::ToggleVariable {
+            name: " ".to_string(),
+        };
+        assert!(config.validate().is_err());
+
+        config.modes[0].mappings[0].action = ActionConfig::Conditional {
+            condition: Condition::VariableInRange {
+                name: "scene".to_string(),
+                min: 3.0,
+                max: 1.0,
+            },
+            then_action: Box::new(ActionConfig::Text {
+                text: "scene".to_string(),
+            }),
+            else_action: None,
+        };
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_validate_parallel() {
+        let action: ActionConfig = toml::from_str(
+            r#"
+            type = "Parallel"
+            actions = [{ type = "Keystroke", keys = "space", modifiers = [] }, { type = "Delay", ms = 100 }]
+        "#,
+        )
+        .unwrap();
+        assert!(matches!(action, ActionConfig::Parallel { wait: true, .. }));
+
+        let mut config = Config::default_config();
+        config.modes[0].mappings[0].action = action;
+        assert!(config.validate().is_ok());
+
+        // Nested actions are validated, and there must be at least one
+        for actions in [vec![ActionConfig::Delay { ms: 0 }], Vec::new()] {
+            config.modes[0].mappings[0].action = ActionConfig::Parallel {
+                actions,
+                wait: false,
+            };
+            assert!(config.validate().is_err());
+        }
+    }
+
+    #[test]
+    fn test_validate_cancel_action() {
+        let config: Config = toml::from_str(
+            r#"
+            [device]
+            name = "Test"
+            auto_connect = false
+
+            [[modes]]
+            name = "Default"
+
+            [[modes.mappings]]
+            description = "Intro"
+            trigger = { type = "Note", note = 36 }
+            action = { type = "Sequence", actions = [{ type = "Text", text = "intro" }, { type = "Delay", ms = 5000 }] }
+            concurrency = "CancelOnRetrigger"
+
+            [[modes.mappings]]
+            trigger = { type = "Note", note = 37 }
+            action = { type = "CancelAction", mapping = "Intro" }
+        "#,
+        )
+        .unwrap();
+        assert!(config.validate().is_ok());
+        let mappings = &config.modes[0].mappings;
+        assert_eq!(
+            mappings[0].concurrency,
+            ConcurrencyPolicy::CancelOnRetrigger
+        );
+        assert_eq!(mappings[1].concurrency, ConcurrencyPolicy::Queue);
+
+        let mut config = Config::default_config();
+        config.modes[0].mappings[0].action = ActionConfig::CancelAction { mapping: None };
+        assert!(config.validate().is_ok());
+        config.modes[0].mappings[0].action = ActionConfig::CancelAction {
+            mapping: Some(" ".to_string()),
+        };
+        assert!(config.validate().is_err());
     }
 
     #[test]
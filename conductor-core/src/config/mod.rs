@@ -28,7 +28,11 @@
 pub mod loader;
 pub mod types;
 
+pub use loader::PluginCatalog;
+
 // Re-export types for convenience
 pub use types::{
-    ActionConfig, AdvancedSettings, Config, DeviceConfig, LoggingConfig, Mapping, Mode, Trigger,
+    ActionConfig, AdvancedSettings, ConcurrencyPolicy, Config, ContinuousValue, CrossingDirection,
+    CycleReset, DeviceConfig, EncoderEncoding, LoggingConfig, Mapping, Mode, SequenceStep,
+    SwipeDirection, SysExByte, TransportCommand, Trigger, VariablesConfig,
 };
@@ -7,7 +7,11 @@
 //! triggers, and actions in the configuration file.
 
 use crate::Condition;
+use crate::actions::VelocityCurve;
+use crate::event_processor::{DEFAULT_MEDIUM_MAX, DEFAULT_SOFT_MAX};
+use crate::variables::VariableValue;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::path::PathBuf;
 
 /// Top-level configuration structure
@@ -28,6 +32,9 @@ pub struct Config {
     /// Advanced settings for event processing
     #[serde(default)]
     pub advanced_settings: AdvancedSettings,
+    /// Runtime variables: initial values and persistence
+    #[serde(default)]
+    pub variables: VariablesConfig,
 }
 
 /// Logging configuration
@@ -47,10 +54,25 @@ fn default_log_level() -> String {
     "info".to_string()
 }
 
+/// Runtime variables configuration
+///
+/// Variables are set by `SetVariable`, `ToggleVariable` and
+/// `IncrementVariable` actions and read by `VariableEquals` and
+/// `VariableInRange` conditions.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+pub struct VariablesConfig {
+    /// Keep variable values across daemon restarts (default: false)
+    #[serde(default)]
+    pub persist: bool,
+    /// Values of variables before any action sets them
+    #[serde(default)]
+    pub initial: HashMap<String, VariableValue>,
+}
+
 /// Advanced settings for event processing and timing
 ///
 /// Fine-tunes behavior of event detection algorithms.
-#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct AdvancedSettings {
     /// Time window in milliseconds for chord detection (default: 50ms)
     #[serde(default = "default_chord_timeout_ms")]
@@ -61,6 +83,20 @@ pub struct AdvancedSettings {
     /// Hold threshold in milliseconds for long press detection (default: 2000ms)
     #[serde(default = "default_hold_threshold_ms")]
     pub hold_threshold_ms: u64,
+    /// Presses released sooner than this many milliseconds are short presses
+    /// (default: 200ms)
+    #[serde(default = "default_short_press_ms")]
+    pub short_press_ms: u64,
+    /// Presses released sooner than this many milliseconds, but not short,
+    /// are medium presses (default: 1000ms)
+    #[serde(default = "default_medium_press_ms")]
+    pub medium_press_ms: u64,
+    /// Highest velocity of a soft press (default: 40)
+    #[serde(default = "default_velocity_soft_max")]
+    pub velocity_soft_max: u8,
+    /// Highest velocity of a medium press (default: 80)
+    #[serde(default = "default_velocity_medium_max")]
+    pub velocity_medium_max: u8,
 }
 
 fn default_chord_timeout_ms() -> u64 {
@@ -75,10 +111,40 @@ fn default_hold_threshold_ms() -> u64 {
     2000
 }
 
+fn default_short_press_ms() -> u64 {
+    200
+}
+
+fn default_medium_press_ms() -> u64 {
+    1000
+}
+
+fn default_velocity_soft_max() -> u8 {
+    DEFAULT_SOFT_MAX
+}
+
+fn default_velocity_medium_max() -> u8 {
+    DEFAULT_MEDIUM_MAX
+}
+
+impl Default for AdvancedSettings {
+    fn default() -> Self {
+        Self {
+            chord_timeout_ms: default_chord_timeout_ms(),
+            double_tap_timeout_ms: default_double_tap_timeout_ms(),
+            hold_threshold_ms: default_hold_threshold_ms(),
+            short_press_ms: default_short_press_ms(),
+            medium_press_ms: default_medium_press_ms(),
+            velocity_soft_max: default_velocity_soft_max(),
+            velocity_medium_max: default_velocity_medium_max(),
+        }
+    }
+}
+
 /// Device-specific configuration
 ///
 /// Defines the MIDI device to connect to and connection preferences.
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct DeviceConfig {
     /// Human-readable device name
     pub name: String,
@@ -122,6 +188,62 @@ pub struct Mapping {
     pub action: ActionConfig,
     /// Optional human-readable description of this mapping
     pub description: Option<String>,
+    /// Fire a single press immediately instead of waiting to rule out a
+    /// double-tap or chord on the same pad (default: false)
+    #[serde(default)]
+    pub immediate: bool,
+    /// Optional action to run when the pad that fired this mapping is released
+    #[serde(default)]
+    pub on_release: Option<ActionConfig>,
+    /// Hold a Keystroke's modifiers and keys down, or layer a ModeChange's
+    /// mode on top of the active mode, while the pad is held (default: false)
+    #[serde(default)]
+    pub momentary: bool,
+    /// Feed the triggering control's value, scaled through a range and
+    /// curve, into the action's value
+    #[serde(default)]
+    pub continuous: Option<ContinuousValue>,
+    /// Notes or gamepad buttons that must be held for this mapping to match,
+    /// e.g. hold pad 12 and turn an encoder (default: none)
+    #[serde(default)]
+    pub while_held: Vec<u8>,
+    /// What a retrigger does while this mapping's action is still running
+    /// (default: Queue)
+    #[serde(default)]
+    pub concurrency: ConcurrencyPolicy,
+}
+
+/// Scaling of a knob, fader, pitch bend, aftertouch or analog stick value for
+/// a continuous mapping
+///
+/// The control's position (0.0 at the bottom of its travel, 1.0 at the top) is
+/// shaped by `curve` and then mapped linearly onto `min`..`max`.
+///
+/// # Examples
+/// ```toml
+/// [modes.mappings.continuous]
+/// min = 0
+/// max = 100
+/// curve = "Logarithmic"  # Optional: default is linear
+/// intensity = 0.3
+/// ```
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ContinuousValue {
+    /// Output value at the bottom of the control's travel
+    pub min: f64,
+    /// Output value at the top of the control's travel (may be below `min`
+    /// to invert the control)
+    pub max: f64,
+    /// Curve applied to the control's position, None = linear
+    #[serde(default)]
+    pub curve: Option<VelocityCurve>,
+    /// Curve strength (0.0-1.0, default: 0.5)
+    #[serde(default = "default_curve_intensity")]
+    pub intensity: f32,
+}
+
+fn default_curve_intensity() -> f32 {
+    0.5
 }
 
 /// MIDI trigger types
@@ -138,35 +260,80 @@ pub enum Trigger {
     /// type = "Note"
     /// note = 60
     /// velocity_min = 1
+    /// channel = 9  # Optional: only match channel 10 (zero-based)
     /// ```
     Note {
         /// MIDI note number (0-127)
         note: u8,
         /// Minimum velocity to trigger (0-127), None = any velocity
         velocity_min: Option<u8>,
+        /// MIDI channel filter (0-15), None = any channel
+        channel: Option<u8>,
+    },
+
+    /// Range of notes sharing one mapping
+    ///
+    /// Matches a press of any note from `min` to `max` (inclusive). The
+    /// matched note, its offset from `min` and the velocity are passed to the
+    /// action.
+    ///
+    /// # Examples
+    /// ```toml
+    /// [trigger]
+    /// type = "NoteRange"
+    /// min = 36
+    /// max = 51  # All 16 pads
+    /// ```
+    NoteRange {
+        /// Lowest MIDI note number of the range (0-127)
+        min: u8,
+        /// Highest MIDI note number of the range (0-127)
+        max: u8,
+        /// Minimum velocity to trigger (0-127), None = any velocity
+        velocity_min: Option<u8>,
+        /// MIDI channel filter (0-15), None = any channel
+        channel: Option<u8>,
     },
 
     /// Velocity-sensitive trigger with different actions per velocity level
     ///
     /// Classifies note presses into soft, medium, and hard based on velocity thresholds.
-    /// Used with `VelocityRange` action type for velocity-dependent behavior.
+    /// Use `level` to bind a separate mapping to each velocity level of the same note.
+    ///
+    /// # Examples
+    /// ```toml
+    /// [trigger]
+    /// type = "VelocityRange"
+    /// note = 36
+    /// level = "Hard"
+    /// ```
     VelocityRange {
         /// MIDI note number (0-127)
         note: u8,
-        /// Maximum velocity for soft (default 40), velocities below this are soft
+        /// Maximum velocity for soft (default 40), velocities up to this are soft
         soft_max: Option<u8>,
-        /// Maximum velocity for medium (default 80), velocities below this are medium (after soft_max)
+        /// Maximum velocity for medium (default 80), velocities up to this are medium (after soft_max)
         medium_max: Option<u8>,
+        /// Velocity level filter: "Soft", "Medium", "Hard", or None for any level
+        #[serde(default)]
+        level: Option<String>,
+        /// MIDI channel filter (0-15), None = any channel
+        channel: Option<u8>,
     },
 
     /// Long press detection (hold threshold in ms)
     ///
-    /// Triggers when a note is held for longer than the specified duration.
+    /// Triggers as soon as a note has been held for the specified duration,
+    /// while it is still held.
     LongPress {
         /// MIDI note number (0-127)
         note: u8,
-        /// Duration in milliseconds to trigger long press (default 2000ms)
+        /// Duration in milliseconds to trigger long press (default: `hold_threshold_ms`)
         duration_ms: Option<u64>,
+        /// Fire again every `repeat_ms` while the note stays held (default: fire once)
+        repeat_ms: Option<u64>,
+        /// MIDI channel filter (0-15), None = any channel
+        channel: Option<u8>,
     },
 
     /// Double-tap detection
@@ -175,8 +342,10 @@ pub enum Trigger {
     DoubleTap {
         /// MIDI note number (0-127)
         note: u8,
-        /// Time window in milliseconds for detecting double-tap (default 300ms)
+        /// Time window in milliseconds for detecting double-tap (default: `double_tap_timeout_ms`)
         timeout_ms: Option<u64>,
+        /// MIDI channel filter (0-15), None = any channel
+        channel: Option<u8>,
     },
 
     /// Chord detection (multiple notes pressed simultaneously)
@@ -185,19 +354,73 @@ pub enum Trigger {
     NoteChord {
         /// List of MIDI note numbers that form this chord
         notes: Vec<u8>,
-        /// Time window in milliseconds for detecting simultaneous presses (default 50ms)
+        /// Time window in milliseconds for detecting simultaneous presses (default: `chord_timeout_ms`)
         timeout_ms: Option<u64>,
+        /// MIDI channel filter (0-15), None = any channel
+        channel: Option<u8>,
+    },
+
+    /// Ordered sequence of inputs (combo or leader-key trigger)
+    ///
+    /// Triggers when the steps are pressed one after another, each within
+    /// `max_gap_ms` of the previous one. Any other step input in between
+    /// breaks the sequence.
+    ///
+    /// # Examples
+    /// ```toml
+    /// [trigger]
+    /// type = "Sequence"
+    /// steps = [{ type = "Note", note = 36 }, { type = "Note", note = 38 }, { type = "CC", cc = 20 }]
+    /// max_gap_ms = 400
+    /// suppress_steps = true  # Steps don't fire their own mappings
+    /// ```
+    Sequence {
+        /// Inputs that must be pressed in order
+        steps: Vec<SequenceStep>,
+        /// Maximum time in milliseconds between consecutive steps (default: 500ms)
+        max_gap_ms: Option<u64>,
+        /// Hold back the events of matched steps while the sequence can still
+        /// complete, and drop them if it does (default: false)
+        #[serde(default)]
+        suppress_steps: bool,
+        /// MIDI channel filter (0-15), None = any channel
+        channel: Option<u8>,
     },
 
     /// Encoder turn with direction
     ///
     /// Triggers on continuous controller (CC) messages from encoder/knob rotation.
     /// Can filter by direction (clockwise/counter-clockwise) or respond to both.
+    ///
+    /// Endless encoders that send relative values need their `encoding` set so
+    /// direction and step count are decoded correctly.
+    ///
+    /// # Examples
+    /// ```toml
+    /// [trigger]
+    /// type = "EncoderTurn"
+    /// cc = 20
+    /// encoding = "TwosComplement"
+    /// acceleration = 3.0  # Optional: fire once per step, up to 4x when spun fast
+    /// ```
     EncoderTurn {
         /// Control Change number (0-127)
         cc: u8,
         /// Direction filter: "Clockwise", "CounterClockwise", or None for either
         direction: Option<String>,
+        /// MIDI channel filter (0-15), None = any channel
+        channel: Option<u8>,
+        /// How the encoder reports movement (default: Absolute)
+        #[serde(default)]
+        encoding: EncoderEncoding,
+        /// CC that steps counter-clockwise, for `IncDec` encoders (`cc` steps
+        /// clockwise)
+        #[serde(default)]
+        decrement_cc: Option<u8>,
+        /// Fire the action once per step, multiplying the steps of fast turns
+        /// by up to `1 + acceleration` (None = fire once per message)
+        #[serde(default)]
+        acceleration: Option<f32>,
     },
 
     /// Aftertouch/pressure sensitivity
@@ -206,6 +429,30 @@ pub enum Trigger {
     Aftertouch {
         /// Minimum pressure value to trigger (0-127)
         pressure_min: Option<u8>,
+        /// MIDI channel filter (0-15), None = any channel
+        channel: Option<u8>,
+    },
+
+    /// Polyphonic aftertouch on a single pad
+    ///
+    /// With `pressure_min`, fires once each time the pad's pressure rises
+    /// through it, so pressing a pad harder can trigger a second action.
+    /// Without it, fires on every pressure change.
+    ///
+    /// # Examples
+    /// ```toml
+    /// [trigger]
+    /// type = "PolyPressure"
+    /// note = 36
+    /// pressure_min = 100
+    /// ```
+    PolyPressure {
+        /// MIDI note number of the pad (0-127)
+        note: u8,
+        /// Pressure threshold to cross (0-127)
+        pressure_min: Option<u8>,
+        /// MIDI channel filter (0-15), None = any channel
+        channel: Option<u8>,
     },
 
     /// Pitch bend
@@ -216,6 +463,8 @@ pub enum Trigger {
         value_min: Option<u16>,
         /// Maximum value range (0-16383)
         value_max: Option<u16>,
+        /// MIDI channel filter (0-15), None = any channel
+        channel: Option<u8>,
     },
 
     /// Control Change (generic CC)
@@ -226,6 +475,272 @@ pub enum Trigger {
         cc: u8,
         /// Minimum value to trigger (0-127)
         value_min: Option<u8>,
+        /// Maximum value to trigger (0-127)
+        #[serde(default)]
+        value_max: Option<u8>,
+        /// MIDI channel filter (0-15), None = any channel
+        channel: Option<u8>,
+    },
+
+    /// Range of Control Change numbers sharing one mapping
+    ///
+    /// Matches a change of any CC from `min` to `max` (inclusive). The
+    /// matched CC number, its offset from `min` and the value are passed to
+    /// the action.
+    CCRange {
+        /// Lowest Control Change number of the range (0-127)
+        min: u8,
+        /// Highest Control Change number of the range (0-127)
+        max: u8,
+        /// Minimum value to trigger (0-127)
+        value_min: Option<u8>,
+        /// MIDI channel filter (0-15), None = any channel
+        channel: Option<u8>,
+    },
+
+    /// Entry into one of `zones` equal zones of a CC's range
+    ///
+    /// Fires once when the control moves into a new zone, so a fader can act
+    /// as a multi-position switch. The zone index is passed to the action as
+    /// `{offset}`.
+    ///
+    /// # Examples
+    /// ```toml
+    /// [trigger]
+    /// type = "CCZone"
+    /// cc = 7
+    /// zones = 3
+    /// zone = 2       # Top third only
+    /// hysteresis = 2
+    /// ```
+    CCZone {
+        /// Control Change number (0-127)
+        cc: u8,
+        /// Number of zones the range is split into (2-128)
+        zones: u8,
+        /// Zone to fire on (0 = lowest), None = any zone
+        zone: Option<u8>,
+        /// How far past a zone boundary the control must move before the
+        /// zone changes (default: 0)
+        hysteresis: Option<u8>,
+        /// MIDI channel filter (0-15), None = any channel
+        channel: Option<u8>,
+    },
+
+    /// A CC value crossing a threshold
+    ///
+    /// `Rising` fires when the value reaches `threshold` from below, and
+    /// `Falling` when it drops below it. After firing, the value must move
+    /// back past the threshold by more than `hysteresis` before it can fire
+    /// again.
+    ///
+    /// # Examples
+    /// ```toml
+    /// [trigger]
+    /// type = "CCThreshold"
+    /// cc = 1
+    /// threshold = 100
+    /// direction = "Rising"
+    /// hysteresis = 10
+    /// ```
+    CCThreshold {
+        /// Control Change number (0-127)
+        cc: u8,
+        /// Threshold value (0-127)
+        threshold: u8,
+        /// Direction of the crossing (default: Rising)
+        #[serde(default)]
+        direction: CrossingDirection,
+        /// Distance the value must move back before re-arming (default: 0)
+        hysteresis: Option<u8>,
+        /// MIDI channel filter (0-15), None = any channel
+        channel: Option<u8>,
+    },
+
+    /// Swipe along a touch strip
+    ///
+    /// Fires when the finger lifts after moving at least `min_distance` of
+    /// the strip at `min_speed` or faster. The strip reports its position as
+    /// pitch bend, or as `cc` if set.
+    ///
+    /// # Examples
+    /// ```toml
+    /// [trigger]
+    /// type = "TouchSwipe"
+    /// direction = "Right"
+    /// min_speed = 2.0  # Strip lengths per second
+    /// ```
+    TouchSwipe {
+        /// Direction of the swipe
+        direction: SwipeDirection,
+        /// Minimum speed in strip lengths per second (default: 1.0)
+        min_speed: Option<f32>,
+        /// Minimum distance as a fraction of the strip (default: 0.25)
+        min_distance: Option<f32>,
+        /// CC the strip sends, None = pitch bend
+        cc: Option<u8>,
+        /// MIDI channel filter (0-15), None = any channel
+        channel: Option<u8>,
+    },
+
+    /// Tap on a touch strip split into `zones` equal zones
+    ///
+    /// Fires when the finger lifts after a short touch without moving. The
+    /// zone tapped is passed to the action as `{offset}`.
+    TouchTap {
+        /// Number of zones the strip is split into (default: 1)
+        zones: Option<u8>,
+        /// Zone to fire on (0 = left end), None = any zone
+        zone: Option<u8>,
+        /// CC the strip sends, None = pitch bend
+        cc: Option<u8>,
+        /// MIDI channel filter (0-15), None = any channel
+        channel: Option<u8>,
+    },
+
+    /// Slide along a touch strip, then rest the finger
+    ///
+    /// Fires once the finger has rested for `hold_ms` after sliding at least
+    /// `min_distance`. Needs a strip that keeps reporting while touched.
+    TouchSlideHold {
+        /// Resting time in milliseconds (default: 500)
+        hold_ms: Option<u64>,
+        /// Minimum distance slid as a fraction of the strip (default: 0.1)
+        min_distance: Option<f32>,
+        /// CC the strip sends, None = pitch bend
+        cc: Option<u8>,
+        /// MIDI channel filter (0-15), None = any channel
+        channel: Option<u8>,
+    },
+
+    /// High-resolution (14-bit) Control Change
+    ///
+    /// Triggers on a fader or knob that sends its value as an MSB on `cc`
+    /// (0-31) followed by an LSB on `cc + 32`.
+    ///
+    /// # Examples
+    /// ```toml
+    /// [trigger]
+    /// type = "HighResCC"
+    /// cc = 7
+    /// value_min = 8192  # Upper half of the fader
+    /// ```
+    HighResCC {
+        /// MSB Control Change number (0-31)
+        cc: u8,
+        /// Minimum value range (0-16383)
+        value_min: Option<u16>,
+        /// Maximum value range (0-16383)
+        value_max: Option<u16>,
+        /// MIDI channel filter (0-15), None = any channel
+        channel: Option<u8>,
+    },
+
+    /// Non-registered parameter number (NRPN)
+    ///
+    /// Triggers when the parameter selected with CC 99/98 is set through
+    /// data entry CC 6/38.
+    ///
+    /// # Examples
+    /// ```toml
+    /// [trigger]
+    /// type = "Nrpn"
+    /// parameter = 1280  # MSB 10, LSB 0
+    /// ```
+    Nrpn {
+        /// Parameter number (0-16383)
+        parameter: u16,
+        /// Minimum value range (0-16383)
+        value_min: Option<u16>,
+        /// Maximum value range (0-16383)
+        value_max: Option<u16>,
+        /// MIDI channel filter (0-15), None = any channel
+        channel: Option<u8>,
+    },
+
+    /// Registered parameter number (RPN)
+    ///
+    /// Triggers when the parameter selected with CC 101/100 is set through
+    /// data entry CC 6/38, e.g. parameter 0 for pitch bend sensitivity.
+    Rpn {
+        /// Parameter number (0-16383)
+        parameter: u16,
+        /// Minimum value range (0-16383)
+        value_min: Option<u16>,
+        /// Maximum value range (0-16383)
+        value_max: Option<u16>,
+        /// MIDI channel filter (0-15), None = any channel
+        channel: Option<u8>,
+    },
+
+    /// Program Change
+    ///
+    /// Triggers when a controller selects `program` (0-127).
+    ProgramChange {
+        /// Program number (0-127)
+        program: u8,
+        /// MIDI channel filter (0-15), None = any channel
+        channel: Option<u8>,
+    },
+
+    /// System Exclusive message matching a byte pattern
+    ///
+    /// The pattern is the whole message as space-separated hex bytes, from
+    /// `F0` to `F7`. `??` matches any byte, and `(??)` matches any byte and
+    /// passes it to the action as `{capture0}`, `{capture1}`, ...
+    ///
+    /// # Examples
+    /// ```toml
+    /// [trigger]
+    /// type = "SysEx"
+    /// pattern = "F0 7F ?? 06 (??) F7"  # MMC command, captured
+    /// ```
+    SysEx {
+        /// Byte pattern of the message
+        pattern: String,
+    },
+
+    /// MIDI transport command (System Real-Time Start, Stop or Continue)
+    ///
+    /// # Examples
+    /// ```toml
+    /// [trigger]
+    /// type = "Transport"
+    /// command = "Start"
+    /// ```
+    Transport {
+        /// Transport command to match
+        command: TransportCommand,
+    },
+
+    /// MIDI Song Position Pointer
+    ///
+    /// Position is counted in MIDI beats (sixteenth notes) from the start of
+    /// the song.
+    SongPosition {
+        /// Minimum position to trigger
+        position_min: Option<u16>,
+        /// Maximum position to trigger
+        position_max: Option<u16>,
+    },
+
+    /// Tempo derived from incoming MIDI clock
+    ///
+    /// Fires when the tempo changes. With a `continuous` table the tempo's
+    /// position within `bpm_min`..`bpm_max` is passed to the action.
+    ///
+    /// # Examples
+    /// ```toml
+    /// [trigger]
+    /// type = "Tempo"
+    /// bpm_min = 60.0
+    /// bpm_max = 180.0
+    /// ```
+    Tempo {
+        /// Lowest tempo to trigger (default: 20 BPM)
+        bpm_min: Option<f64>,
+        /// Highest tempo to trigger (default: 300 BPM)
+        bpm_max: Option<f64>,
     },
 
     // ===== Gamepad Triggers (v3.0) =====
@@ -270,7 +785,7 @@ pub enum Trigger {
     GamepadButtonChord {
         /// List of gamepad button IDs that form this chord (128-255)
         buttons: Vec<u8>,
-        /// Time window in milliseconds for detecting simultaneous presses (default 50ms)
+        /// Time window in milliseconds for detecting simultaneous presses (default: `chord_timeout_ms`)
         timeout_ms: Option<u64>,
     },
 
@@ -317,6 +832,185 @@ pub enum Trigger {
     },
 }
 
+impl Trigger {
+    /// MIDI channel filter of this trigger, if any
+    ///
+    /// Always None for gamepad triggers, which have no MIDI channel.
+    pub fn channel(&self) -> Option<u8> {
+        match self {
+            Trigger::Note { channel, .. }
+            | Trigger::NoteRange { channel, .. }
+            | Trigger::VelocityRange { channel, .. }
+            | Trigger::LongPress { channel, .. }
+            | Trigger::DoubleTap { channel, .. }
+            | Trigger::NoteChord { channel, .. }
+            | Trigger::Sequence { channel, .. }
+            | Trigger::EncoderTurn { channel, .. }
+            | Trigger::Aftertouch { channel, .. }
+            | Trigger::PolyPressure { channel, .. }
+            | Trigger::PitchBend { channel, .. }
+            | Trigger::CC { channel, .. }
+            | Trigger::CCRange { channel, .. }
+            | Trigger::CCZone { channel, .. }
+            | Trigger::CCThreshold { channel, .. }
+            | Trigger::TouchSwipe { channel, .. }
+            | Trigger::TouchTap { channel, .. }
+            | Trigger::TouchSlideHold { channel, .. }
+            | Trigger::HighResCC { channel, .. }
+            | Trigger::Nrpn { channel, .. }
+            | Trigger::Rpn { channel, .. }
+            | Trigger::ProgramChange { channel, .. } => *channel,
+            Trigger::SysEx { .. }
+            | Trigger::Transport { .. }
+            | Trigger::SongPosition { .. }
+            | Trigger::Tempo { .. }
+            | Trigger::GamepadButton { .. }
+            | Trigger::GamepadButtonChord { .. }
+            | Trigger::GamepadAnalogStick { .. }
+            | Trigger::GamepadTrigger { .. } => None,
+        }
+    }
+}
+
+/// One step of a [`Trigger::Sequence`]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(tag = "type")]
+pub enum SequenceStep {
+    /// A note press (0-127)
+    Note { note: u8 },
+    /// A CC button press: the value rising to 64 or above (0-127)
+    CC { cc: u8 },
+    /// A gamepad button press (128-255)
+    GamepadButton { button: u8 },
+}
+
+/// MIDI transport command matched by a [`Trigger::Transport`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum TransportCommand {
+    /// Start playing from the beginning of the song
+    Start,
+    /// Stop playing
+    Stop,
+    /// Continue playing from the current song position
+    Continue,
+}
+
+/// One byte of a [`Trigger::SysEx`] pattern
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SysExByte {
+    /// This exact byte
+    Exact(u8),
+    /// Any byte (`??`)
+    Any,
+    /// Any byte, passed on to the action (`(??)`)
+    Capture,
+}
+
+impl SysExByte {
+    /// Parse a SysEx pattern such as `"F0 00 20 29 ?? (??) F7"`
+    ///
+    /// The pattern must start with `F0` and end with `F7`, and the bytes in
+    /// between must be data bytes (00-7F) or wildcards.
+    pub fn parse_pattern(pattern: &str) -> Result<Vec<SysExByte>, String> {
+        let bytes = pattern
+            .split_whitespace()
+            .map(|token| match token {
+                "??" => Ok(SysExByte::Any),
+                "(??)" => Ok(SysExByte::Capture),
+                _ if token.len() == 2 => u8::from_str_radix(token, 16)
+                    .map(SysExByte::Exact)
+                    .map_err(|_| format!("Invalid SysEx byte '{}'", token)),
+                _ => Err(format!("Invalid SysEx byte '{}'", token)),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        match (bytes.first(), bytes.last()) {
+            (Some(SysExByte::Exact(0xF0)), Some(SysExByte::Exact(0xF7))) => {}
+            _ => return Err("SysEx pattern must start with F0 and end with F7".to_string()),
+        }
+        if let Some(SysExByte::Exact(byte)) = bytes[1..bytes.len() - 1]
+            .iter()
+            .find(|byte| matches!(byte, SysExByte::Exact(b) if *b > 0x7F))
+        {
+            return Err(format!(
+                "SysEx data byte out of range: {:02X} (must be 00-7F)",
+                byte
+            ));
+        }
+        Ok(bytes)
+    }
+}
+
+/// How an [`Trigger::EncoderTurn`] encoder reports movement
+///
+/// Relative encodings send the number of steps turned since the previous
+/// message; the common 7-bit conventions differ in how they mark direction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+pub enum EncoderEncoding {
+    /// Absolute position (0-127); direction is the change from the last value
+    #[default]
+    Absolute,
+    /// Two's complement: 1-63 clockwise, 127 down to 65 counter-clockwise
+    /// (127 = one step back)
+    TwosComplement,
+    /// Sign and magnitude: 1-63 clockwise, 65-127 counter-clockwise
+    /// (65 = one step back)
+    SignedBit,
+    /// Offset from 64: 65 and up clockwise, 63 and down counter-clockwise
+    BinaryOffset,
+    /// A pair of buttons: any non-zero value on `cc` steps clockwise, on
+    /// `decrement_cc` counter-clockwise
+    IncDec,
+}
+
+/// Direction of a [`Trigger::CCThreshold`] crossing
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+pub enum CrossingDirection {
+    /// The value rises to or above the threshold
+    #[default]
+    Rising,
+    /// The value falls below the threshold
+    Falling,
+}
+
+/// When a [`ActionConfig::Cycle`] or [`ActionConfig::Toggle`] goes back to
+/// its first action
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+pub enum CycleReset {
+    /// When the config is reloaded
+    #[default]
+    Reload,
+    /// When the config is reloaded or the active mode changes
+    ModeChange,
+    /// Only when the daemon restarts
+    Never,
+}
+
+/// What retriggering a mapping does while its previous action is still
+/// running
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+pub enum ConcurrencyPolicy {
+    /// Run the new action after the running one finishes
+    #[default]
+    Queue,
+    /// Cancel the running action and start the new one
+    Restart,
+    /// Drop the new trigger
+    IgnoreWhileRunning,
+    /// Cancel the running action without starting a new one, so the pad
+    /// stops what it started
+    CancelOnRetrigger,
+}
+
+/// Direction of a [`Trigger::TouchSwipe`] along the strip
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum SwipeDirection {
+    /// Towards lower values
+    Left,
+    /// Towards higher values
+    Right,
+}
+
 /// Action configuration types
 ///
 /// Defines different actions that can be executed when a trigger is detected.
@@ -372,6 +1066,18 @@ pub enum ActionConfig {
         actions: Vec<ActionConfig>,
     },
 
+    /// Start several actions at once
+    ///
+    /// Each action runs alongside the others, so delays inside them overlap.
+    Parallel {
+        /// Actions to start together (at least one)
+        actions: Vec<ActionConfig>,
+        /// Finish only when every action has finished, so a surrounding
+        /// Sequence waits for all of them (default: true)
+        #[serde(default = "default_parallel_wait")]
+        wait: bool,
+    },
+
     /// Delay for a specified duration (in milliseconds)
     ///
     /// Pauses execution for the given duration. Useful in sequences.
@@ -392,6 +1098,35 @@ pub enum ActionConfig {
         y: Option<i32>,
     },
 
+    /// Move the mouse pointer
+    ///
+    /// Moves to `x`/`y`, or by `x`/`y` pixels when `relative` is set. A missing
+    /// coordinate is left where it is; in a continuous mapping it is taken
+    /// from the control's value instead.
+    MouseMove {
+        /// X coordinate or horizontal distance in pixels
+        #[serde(default)]
+        x: Option<i32>,
+        /// Y coordinate or vertical distance in pixels
+        #[serde(default)]
+        y: Option<i32>,
+        /// Move relative to the current position (default: false)
+        #[serde(default)]
+        relative: bool,
+    },
+
+    /// Scroll the mouse wheel
+    ///
+    /// Positive amounts scroll down (or right), negative amounts up (or left).
+    MouseScroll {
+        /// Number of wheel steps to scroll
+        #[serde(default)]
+        amount: i32,
+        /// Scroll horizontally instead of vertically (default: false)
+        #[serde(default)]
+        horizontal: bool,
+    },
+
     /// Control system volume
     ///
     /// Adjusts or sets the system volume.
@@ -470,9 +1205,96 @@ pub enum ActionConfig {
         /// Aftertouch pressure (0-127) for Aftertouch messages
         #[serde(default)]
         pressure: Option<u8>,
+        /// Add the triggering note or CC's offset within its range trigger to
+        /// `note` (Note messages) or `controller` (CC messages) (default: false)
+        #[serde(default)]
+        relative: bool,
+    },
+
+    /// Run an installed action plugin
+    ///
+    /// The plugin must be installed and granted its capabilities; the daemon
+    /// loads it along with the config.
+    Plugin {
+        /// Plugin name (as in its manifest)
+        plugin: String,
+        /// Plugin-specific parameters, passed to the plugin as a JSON object
+        #[serde(default)]
+        params: serde_json::Map<String, serde_json::Value>,
+    },
+
+    /// Set a runtime variable
+    SetVariable {
+        /// Variable name
+        name: String,
+        /// New value: a boolean, number or string
+        value: VariableValue,
+    },
+
+    /// Flip a runtime variable between true and false (unset becomes true)
+    ToggleVariable {
+        /// Variable name
+        name: String,
+    },
+
+    /// Add to a numeric runtime variable (unset counts from `min`, or 0)
+    IncrementVariable {
+        /// Variable name
+        name: String,
+        /// Amount to add; negative to count down (default: 1)
+        #[serde(default = "default_increment_amount")]
+        amount: f64,
+        /// Lowest value (default: unbounded)
+        #[serde(default)]
+        min: Option<f64>,
+        /// Highest value (default: unbounded)
+        #[serde(default)]
+        max: Option<f64>,
+        /// Wrap past `max` to `min` and past `min` to `max` instead of
+        /// stopping there; needs both bounds (default: false)
+        #[serde(default)]
+        wrap: bool,
+    },
+
+    /// Run the next action in the list each time, wrapping around
+    ///
+    /// Each mapping keeps its own position.
+    Cycle {
+        /// Actions to step through (at least one)
+        actions: Vec<ActionConfig>,
+        /// When to go back to the first action (default: Reload)
+        #[serde(default)]
+        reset: CycleReset,
+    },
+
+    /// Alternate between two actions, starting with `on`
+    Toggle {
+        /// Action run on the first, third, ... trigger
+        on: Box<ActionConfig>,
+        /// Action run on the second, fourth, ... trigger
+        off: Box<ActionConfig>,
+        /// When to go back to `on` (default: Reload)
+        #[serde(default)]
+        reset: CycleReset,
+    },
+
+    /// Stop running actions
+    CancelAction {
+        /// Mapping whose actions to stop, by its description or its id as
+        /// listed by `conductorctl jobs` (default: every other running action)
+        #[serde(default)]
+        mapping: Option<String>,
     },
 }
 
+fn default_parallel_wait() -> bool {
+    true
+}
+
+fn default_increment_amount() -> f64 {
+    1.0
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -508,11 +1330,21 @@ modifiers = ["cmd"]
         assert_eq!(config.modes[0].name, "Default");
     }
 
+    #[test]
+    fn test_advanced_settings_default_matches_serde_defaults() {
+        let parsed: AdvancedSettings = toml::from_str("").unwrap();
+        assert_eq!(parsed, AdvancedSettings::default());
+        assert_eq!(parsed.chord_timeout_ms, 50);
+        assert_eq!(parsed.double_tap_timeout_ms, 300);
+        assert_eq!(parsed.hold_threshold_ms, 2000);
+    }
+
     #[test]
     fn test_trigger_note() {
         let trigger = Trigger::Note {
             note: 60,
             velocity_min: Some(1),
+            channel: None,
         };
         assert!(matches!(trigger, Trigger::Note { note: 60, .. }));
     }
@@ -0,0 +1,127 @@
+// Copyright 2025 Amiable
+// SPDX-License-Identifier: MIT
+
+//! Positions of Cycle and Toggle actions
+//!
+//! A `Cycle` runs the next of its actions each time it fires and wraps
+//! around; a `Toggle` is a cycle of two. Each cycle is identified by the
+//! mapping it belongs to (see [`Action::assign_cycle_ids`]), so two mappings
+//! with the same actions step independently.
+//!
+//! [`Action::assign_cycle_ids`]: crate::Action::assign_cycle_ids
+
+use crate::config::CycleReset;
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// Where a cycle is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct CyclePosition {
+    /// Index of the action the cycle runs next
+    pub position: usize,
+    /// Number of actions in the cycle
+    pub length: usize,
+    /// When the cycle goes back to its first action
+    pub reset: CycleReset,
+}
+
+/// Positions of the cycles that have fired, by cycle id
+///
+/// Cycles that haven't fired (or were reset) start at their first action.
+#[derive(Debug, Clone, Default)]
+pub struct CyclePositions {
+    positions: HashMap<String, CyclePosition>,
+}
+
+impl CyclePositions {
+    /// Step cycle `id`, returning the index of the action to run now
+    pub fn advance(&mut self, id: &str, length: usize, reset: CycleReset) -> usize {
+        let entry = self
+            .positions
+            .entry(id.to_string())
+            .or_insert(CyclePosition {
+                position: 0,
+                length,
+                reset,
+            });
+
+        // The cycle may have changed length in a reload
+        let current = entry.position % length.max(1);
+        *entry = CyclePosition {
+            position: (current + 1) % length.max(1),
+            length,
+            reset,
+        };
+        current
+    }
+
+    /// The position of cycle `id`, if it has fired
+    pub fn get(&self, id: &str) -> Option<&CyclePosition> {
+        self.positions.get(id)
+    }
+
+    /// All cycles that have fired, by id
+    pub fn positions(&self) -> &HashMap<String, CyclePosition> {
+        &self.positions
+    }
+
+    /// Send the cycles that reset on `event` back to their first action
+    ///
+    /// A reload resets every cycle except those set to `Never`; a mode
+    /// change only resets cycles set to `ModeChange`.
+    pub fn reset(&mut self, event: CycleReset) {
+        self.positions.retain(|_, cycle| match event {
+            CycleReset::Reload => cycle.reset == CycleReset::Never,
+            CycleReset::ModeChange => cycle.reset != CycleReset::ModeChange,
+            CycleReset::Never => true,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_advance_wraps() {
+        let mut cycles = CyclePositions::default();
+        let steps: Vec<usize> = (0..4)
+            .map(|_| cycles.advance("Default/0", 3, CycleReset::Reload))
+            .collect();
+        assert_eq!(steps, vec![0, 1, 2, 0]);
+
+        // Another mapping keeps its own position
+        assert_eq!(cycles.advance("Default/1", 3, CycleReset::Reload), 0);
+        assert_eq!(cycles.get("Default/0").unwrap().position, 1);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut cycles = CyclePositions::default();
+        for (id, reset) in [
+            ("reload", CycleReset::Reload),
+            ("mode", CycleReset::ModeChange),
+            ("never", CycleReset::Never),
+        ] {
+            cycles.advance(id, 2, reset);
+        }
+
+        cycles.reset(CycleReset::ModeChange);
+        assert!(cycles.get("mode").is_none());
+        assert!(cycles.get("reload").is_some());
+
+        cycles.reset(CycleReset::Reload);
+        assert!(cycles.get("reload").is_none());
+        assert_eq!(cycles.get("never").unwrap().position, 1);
+    }
+
+    #[test]
+    fn test_shorter_cycle_after_reload() {
+        let mut cycles = CyclePositions::default();
+        cycles.advance("Default/0", 3, CycleReset::Never);
+        cycles.advance("Default/0", 3, CycleReset::Never);
+
+        // Position 2 no longer exists in a cycle of two
+        assert_eq!(cycles.advance("Default/0", 2, CycleReset::Never), 0);
+    }
+}
@@ -1,48 +1,118 @@
 // Copyright 2025 Amiable
 // SPDX-License-Identifier: MIT
 
+use crate::config::{
+    AdvancedSettings, Config, CrossingDirection, EncoderEncoding, Mapping, SequenceStep,
+    TransportCommand, Trigger,
+};
 use crate::events::InputEvent; // Protocol-agnostic event processing (v3.0)
-use midi_msg::{ChannelVoiceMsg, ControlChange, MidiMsg};
-use std::collections::HashMap;
+use crate::gestures::{Gesture, GestureRecognizer, TouchSource};
+use midi_msg::{ChannelVoiceMsg, ControlChange, MidiMsg, SystemCommonMsg, SystemRealTimeMsg};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::ops::RangeInclusive;
 use std::time::{Duration, Instant};
 use tracing::{debug, trace};
 
+/// Raw MIDI channel voice event
+///
+/// `channel` is the zero-based MIDI channel (0-15) the message arrived on.
 #[derive(Debug, Clone)]
 pub enum MidiEvent {
     NoteOn {
+        channel: u8,
         note: u8,
         velocity: u8,
         time: Instant,
     },
     NoteOff {
+        channel: u8,
         note: u8,
         time: Instant,
     },
     ControlChange {
+        channel: u8,
         cc: u8,
         value: u8,
         time: Instant,
     },
     PolyPressure {
+        channel: u8,
         note: u8,
         pressure: u8,
         time: Instant,
     },
     Aftertouch {
+        channel: u8,
         pressure: u8,
         time: Instant,
     },
     PitchBend {
+        channel: u8,
         value: u16,
         time: Instant,
     },
     ProgramChange {
+        channel: u8,
         program: u8,
         time: Instant,
     },
+    /// 14-bit control change assembled from an MSB (CC 0-31) and its LSB
+    /// (CC 32-63); `cc` is the MSB controller number
+    HighResCC {
+        channel: u8,
+        cc: u8,
+        value: u16,
+        time: Instant,
+    },
+    /// Non-registered parameter (NRPN) set via CC 99/98 and data entry CC 6/38
+    Nrpn {
+        channel: u8,
+        parameter: u16,
+        value: u16,
+        time: Instant,
+    },
+    /// Registered parameter (RPN) set via CC 101/100 and data entry CC 6/38
+    Rpn {
+        channel: u8,
+        parameter: u16,
+        value: u16,
+        time: Instant,
+    },
+    /// System Exclusive message, including the `F0` and `F7` framing bytes
+    SysEx { data: Vec<u8>, time: Instant },
+    /// MIDI timing clock pulse (24 per quarter note)
+    Clock { time: Instant },
+    /// Transport Start, Stop or Continue
+    Transport {
+        command: TransportCommand,
+        time: Instant,
+    },
+    /// Song Position Pointer, in MIDI beats (sixteenth notes)
+    SongPosition { position: u16, time: Instant },
 }
 
 impl MidiEvent {
+    /// The zero-based MIDI channel (0-15) this event arrived on
+    pub fn channel(&self) -> u8 {
+        match self {
+            MidiEvent::NoteOn { channel, .. }
+            | MidiEvent::NoteOff { channel, .. }
+            | MidiEvent::ControlChange { channel, .. }
+            | MidiEvent::PolyPressure { channel, .. }
+            | MidiEvent::Aftertouch { channel, .. }
+            | MidiEvent::PitchBend { channel, .. }
+            | MidiEvent::ProgramChange { channel, .. }
+            | MidiEvent::HighResCC { channel, .. }
+            | MidiEvent::Nrpn { channel, .. }
+            | MidiEvent::Rpn { channel, .. } => *channel,
+            // System messages have no channel
+            MidiEvent::SysEx { .. }
+            | MidiEvent::Clock { .. }
+            | MidiEvent::Transport { .. }
+            | MidiEvent::SongPosition { .. } => 0,
+        }
+    }
+
     /// Parse raw MIDI bytes into a MidiEvent using the midi-msg library.
     ///
     /// This centralizes MIDI message parsing across the codebase, ensuring
@@ -68,42 +138,75 @@ impl MidiEvent {
     pub fn from_midi_msg(msg: &[u8]) -> Result<Self, String> {
         let now = Instant::now();
 
+        if msg.first() == Some(&0xF0) {
+            return Self::from_sysex(msg, now);
+        }
+
         match MidiMsg::from_midi(msg) {
-            Ok((MidiMsg::ChannelVoice { msg: voice_msg, .. }, _))
-            | Ok((MidiMsg::RunningChannelVoice { msg: voice_msg, .. }, _)) => {
+            Ok((
+                MidiMsg::ChannelVoice {
+                    channel,
+                    msg: voice_msg,
+                },
+                _,
+            ))
+            | Ok((
+                MidiMsg::RunningChannelVoice {
+                    channel,
+                    msg: voice_msg,
+                },
+                _,
+            )) => {
+                let channel = channel as u8;
                 match voice_msg {
                     ChannelVoiceMsg::NoteOn { note, velocity } => {
                         if velocity > 0 {
                             Ok(MidiEvent::NoteOn {
+                                channel,
                                 note,
                                 velocity,
                                 time: now,
                             })
                         } else {
                             // Note On with velocity 0 is treated as Note Off
-                            Ok(MidiEvent::NoteOff { note, time: now })
-                        }
-                    }
-
-                    ChannelVoiceMsg::NoteOff { note, .. } => {
-                        Ok(MidiEvent::NoteOff { note, time: now })
-                    }
-
-                    ChannelVoiceMsg::ControlChange { control } => {
-                        // Extract CC number and value from ControlChange enum
-                        if let ControlChange::CC { control: cc, value } = control {
-                            Ok(MidiEvent::ControlChange {
-                                cc,
-                                value,
+                            Ok(MidiEvent::NoteOff {
+                                channel,
+                                note,
                                 time: now,
                             })
-                        } else {
-                            Err(format!("Unsupported ControlChange variant: {:?}", control))
                         }
                     }
 
+                    ChannelVoiceMsg::NoteOff { note, .. } => Ok(MidiEvent::NoteOff {
+                        channel,
+                        note,
+                        time: now,
+                    }),
+
+                    ChannelVoiceMsg::ControlChange { control } => match control {
+                        ControlChange::CCHighRes {
+                            control1: cc,
+                            value,
+                            ..
+                        } => Ok(MidiEvent::HighResCC {
+                            channel,
+                            cc,
+                            value,
+                            time: now,
+                        }),
+                        // Single messages only ever carry one 7-bit value;
+                        // pairs and parameters are assembled by `HighResDecoder`
+                        _ => Ok(MidiEvent::ControlChange {
+                            channel,
+                            cc: control.control(),
+                            value: control.value(),
+                            time: now,
+                        }),
+                    },
+
                     ChannelVoiceMsg::PolyPressure { note, pressure } => {
                         Ok(MidiEvent::PolyPressure {
+                            channel,
                             note,
                             pressure,
                             time: now,
@@ -111,18 +214,22 @@ impl MidiEvent {
                     }
 
                     ChannelVoiceMsg::ChannelPressure { pressure } => Ok(MidiEvent::Aftertouch {
+                        channel,
                         pressure,
                         time: now,
                     }),
 
                     ChannelVoiceMsg::PitchBend { bend } => Ok(MidiEvent::PitchBend {
+                        channel,
                         value: bend,
                         time: now,
                     }),
 
-                    ChannelVoiceMsg::ProgramChange { program } => {
-                        Ok(MidiEvent::ProgramChange { program, time: now })
-                    }
+                    ChannelVoiceMsg::ProgramChange { program } => Ok(MidiEvent::ProgramChange {
+                        channel,
+                        program,
+                        time: now,
+                    }),
 
                     _ => Err(format!(
                         "Unsupported MIDI voice message type: {:?}",
@@ -131,12 +238,33 @@ impl MidiEvent {
                 }
             }
 
-            Ok((MidiMsg::SystemCommon { .. }, _)) => {
-                Err("System Common messages not supported".to_string())
+            Ok((
+                MidiMsg::SystemCommon {
+                    msg: SystemCommonMsg::SongPosition(position),
+                },
+                _,
+            )) => Ok(MidiEvent::SongPosition {
+                position,
+                time: now,
+            }),
+
+            Ok((MidiMsg::SystemCommon { msg }, _)) => {
+                Err(format!("Unsupported System Common message: {:?}", msg))
             }
 
-            Ok((MidiMsg::SystemRealTime { .. }, _)) => {
-                Err("System Real-Time messages not supported".to_string())
+            Ok((MidiMsg::SystemRealTime { msg }, _)) => {
+                let command = match msg {
+                    SystemRealTimeMsg::TimingClock => {
+                        return Ok(MidiEvent::Clock { time: now });
+                    }
+                    SystemRealTimeMsg::Start => TransportCommand::Start,
+                    SystemRealTimeMsg::Stop => TransportCommand::Stop,
+                    SystemRealTimeMsg::Continue => TransportCommand::Continue,
+                    _ => {
+                        return Err(format!("Unsupported System Real-Time message: {:?}", msg));
+                    }
+                };
+                Ok(MidiEvent::Transport { command, time: now })
             }
 
             Err(e) => Err(format!("Failed to parse MIDI message: {:?}", e)),
@@ -144,51 +272,497 @@ impl MidiEvent {
             _ => Err(format!("Unknown MIDI message type: {:02X?}", msg)),
         }
     }
+
+    /// Check the framing and size of a SysEx message
+    fn from_sysex(msg: &[u8], time: Instant) -> Result<Self, String> {
+        if msg.len() > MAX_SYSEX_LEN {
+            return Err(format!(
+                "SysEx message too long: {} bytes (max {})",
+                msg.len(),
+                MAX_SYSEX_LEN
+            ));
+        }
+        if msg.len() < 2 || msg.last() != Some(&0xF7) {
+            return Err(format!("Unterminated SysEx message: {:02X?}", msg));
+        }
+
+        Ok(MidiEvent::SysEx {
+            data: msg.to_vec(),
+            time,
+        })
+    }
+}
+
+/// MIDI clock pulses per quarter note
+pub const CLOCK_PPQ: u64 = 24;
+
+/// Default lower bound of a `Tempo` trigger's BPM range
+pub const DEFAULT_BPM_MIN: f64 = 20.0;
+/// Default upper bound of a `Tempo` trigger's BPM range
+pub const DEFAULT_BPM_MAX: f64 = 300.0;
+
+/// Beats per bar assumed for [`ClockState::bar`] (4/4)
+const BEATS_PER_BAR: u64 = 4;
+
+/// A longer gap between clock pulses restarts the tempo estimate
+const CLOCK_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Smallest tempo change reported as a new `TempoChanged` event, in BPM
+const TEMPO_REPORT_STEP: f64 = 0.5;
+
+/// Tempo and song position derived from incoming MIDI clock
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ClockState {
+    /// Whether the transport is playing (after Start or Continue, until Stop)
+    pub playing: bool,
+    /// Tempo in beats per minute, once a beat's worth of clock has arrived
+    pub bpm: Option<f64>,
+    /// Clock pulses since the start of the song (24 per quarter note)
+    pub pulses: u64,
+}
+
+impl ClockState {
+    /// Quarter-note beat since the start of the song, counting from 0
+    pub fn beat(&self) -> u64 {
+        self.pulses / CLOCK_PPQ
+    }
+
+    /// Bar since the start of the song, counting from 0 (assumes 4/4)
+    pub fn bar(&self) -> u64 {
+        self.beat() / BEATS_PER_BAR
+    }
+
+    /// Beat within the current bar (0-3)
+    pub fn beat_in_bar(&self) -> u64 {
+        self.beat() % BEATS_PER_BAR
+    }
+}
+
+/// Derives [`ClockState`] from clock pulses and transport messages
+#[derive(Debug, Default)]
+struct ClockTracker {
+    state: ClockState,
+    last_pulse: Option<Instant>,
+    /// Intervals between the most recent pulses, at most one beat's worth
+    intervals: VecDeque<Duration>,
+    /// Tempo last reported in a `TempoChanged` event
+    reported_bpm: Option<f64>,
+}
+
+impl ClockTracker {
+    /// Count a clock pulse, returning the new tempo if it changed noticeably
+    fn pulse(&mut self, time: Instant) -> Option<f64> {
+        if self.state.playing {
+            self.state.pulses += 1;
+        }
+
+        match self
+            .last_pulse
+            .map(|last| time.saturating_duration_since(last))
+        {
+            Some(interval) if interval < CLOCK_TIMEOUT => {
+                if self.intervals.len() == CLOCK_PPQ as usize {
+                    self.intervals.pop_front();
+                }
+                self.intervals.push_back(interval);
+            }
+            _ => self.intervals.clear(),
+        }
+        self.last_pulse = Some(time);
+
+        if self.intervals.len() < CLOCK_PPQ as usize {
+            return None;
+        }
+        // One beat's worth of intervals adds up to the length of a beat
+        let beat = self.intervals.iter().sum::<Duration>().as_secs_f64();
+        if beat <= 0.0 {
+            return None;
+        }
+        let bpm = 60.0 / beat;
+        self.state.bpm = Some(bpm);
+
+        if self
+            .reported_bpm
+            .is_some_and(|reported| (bpm - reported).abs() < TEMPO_REPORT_STEP)
+        {
+            return None;
+        }
+        self.reported_bpm = Some(bpm);
+        Some(bpm)
+    }
+
+    fn transport(&mut self, command: TransportCommand) {
+        match command {
+            TransportCommand::Start => {
+                self.state.pulses = 0;
+                self.state.playing = true;
+            }
+            TransportCommand::Continue => self.state.playing = true,
+            TransportCommand::Stop => self.state.playing = false,
+        }
+    }
+
+    /// Jump to a Song Position Pointer (in sixteenth notes)
+    fn song_position(&mut self, position: u16) {
+        self.state.pulses = position as u64 * (CLOCK_PPQ / 4);
+    }
+}
+
+/// Longest SysEx message accepted as input, in bytes including `F0`/`F7`
+///
+/// Larger messages (firmware dumps, sample transfers) are dropped rather than
+/// buffered and matched against every SysEx trigger.
+pub const MAX_SYSEX_LEN: usize = 512;
+
+/// NRPN parameter number MSB/LSB controllers
+const NRPN_MSB: u8 = 99;
+const NRPN_LSB: u8 = 98;
+/// RPN parameter number MSB/LSB controllers
+const RPN_MSB: u8 = 101;
+const RPN_LSB: u8 = 100;
+/// Data entry MSB/LSB controllers
+const DATA_ENTRY_MSB: u8 = 6;
+const DATA_ENTRY_LSB: u8 = 38;
+
+/// Which kind of parameter data entry currently applies to
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ParameterKind {
+    Nrpn,
+    Rpn,
+}
+
+/// Per-channel state of a [`HighResDecoder`]
+#[derive(Debug, Clone, Default)]
+struct HighResChannel {
+    /// Last MSB of CC 0-31, waiting for its LSB
+    msb: [Option<u8>; 32],
+    /// Parameter type selected by the last CC 99/98 or 101/100
+    kind: Option<ParameterKind>,
+    parameter_msb: Option<u8>,
+    parameter_lsb: Option<u8>,
+    /// Data entry MSB (CC 6), waiting for its LSB (CC 38)
+    data_msb: Option<u8>,
+}
+
+impl HighResChannel {
+    /// The selected parameter, once both halves of its number arrived
+    fn parameter(&self) -> Option<(ParameterKind, u16)> {
+        match (self.kind, self.parameter_msb, self.parameter_lsb) {
+            (Some(kind), Some(msb), Some(lsb)) => Some((kind, u14(msb, lsb))),
+            _ => None,
+        }
+    }
+
+    fn select(&mut self, kind: ParameterKind, cc: u8, value: u8) {
+        if self.kind != Some(kind) {
+            self.parameter_msb = None;
+            self.parameter_lsb = None;
+        }
+        self.kind = Some(kind);
+        if cc == NRPN_MSB || cc == RPN_MSB {
+            self.parameter_msb = Some(value);
+        } else {
+            self.parameter_lsb = Some(value);
+        }
+        self.data_msb = None;
+
+        // The RPN null parameter (127/127) deselects data entry
+        if kind == ParameterKind::Rpn && self.parameter() == Some((kind, 0x3FFF)) {
+            self.kind = None;
+        }
+    }
+}
+
+/// Assembles 14-bit controller values from consecutive 7-bit CC messages
+///
+/// MIDI sends high-resolution values as pairs of control changes, which
+/// [`MidiEvent::from_midi_msg`] parses one message at a time. Feed every
+/// parsed event through [`decode`](Self::decode) to pair them up:
+///
+/// - An MSB on CC 0-31 followed by its LSB on CC 32-63 yields a
+///   [`MidiEvent::HighResCC`]. Controllers that only send the MSB keep
+///   reporting plain control changes.
+/// - A parameter number selected with CC 99/98 (NRPN) or CC 101/100 (RPN)
+///   followed by data entry on CC 6 and CC 38 yields a [`MidiEvent::Nrpn`]
+///   or [`MidiEvent::Rpn`]. The RPN null parameter (127/127) ends data entry.
+///
+/// The 7-bit control changes themselves are always passed through, so
+/// existing CC mappings keep working.
+///
+/// # Example
+/// ```rust
+/// use conductor_core::event_processor::{HighResDecoder, MidiEvent};
+///
+/// let mut decoder = HighResDecoder::default();
+/// let mut events = Vec::new();
+/// for msg in [[0xB0, 7, 0x40], [0xB0, 39, 0x01]] {
+///     events.extend(decoder.decode(MidiEvent::from_midi_msg(&msg).unwrap()));
+/// }
+///
+/// assert!(matches!(
+///     events.last(),
+///     Some(MidiEvent::HighResCC { cc: 7, value: 0x2001, .. })
+/// ));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct HighResDecoder {
+    channels: HashMap<u8, HighResChannel>,
+}
+
+impl HighResDecoder {
+    /// Pass an event through, followed by the 14-bit value it completes, if any
+    pub fn decode(&mut self, event: MidiEvent) -> Vec<MidiEvent> {
+        let assembled = match event {
+            MidiEvent::ControlChange {
+                channel,
+                cc,
+                value,
+                time,
+            } => self.control_change(channel, cc, value, time),
+            _ => None,
+        };
+
+        std::iter::once(event).chain(assembled).collect()
+    }
+
+    fn control_change(
+        &mut self,
+        channel: u8,
+        cc: u8,
+        value: u8,
+        time: Instant,
+    ) -> Option<MidiEvent> {
+        let state = self.channels.entry(channel).or_default();
+
+        match cc {
+            NRPN_MSB | NRPN_LSB => {
+                state.select(ParameterKind::Nrpn, cc, value);
+                None
+            }
+            RPN_MSB | RPN_LSB => {
+                state.select(ParameterKind::Rpn, cc, value);
+                None
+            }
+            DATA_ENTRY_MSB if state.parameter().is_some() => {
+                state.data_msb = Some(value);
+                None
+            }
+            DATA_ENTRY_LSB if state.parameter().is_some() => {
+                let (kind, parameter) = state.parameter()?;
+                let value = u14(state.data_msb?, value);
+                Some(match kind {
+                    ParameterKind::Nrpn => MidiEvent::Nrpn {
+                        channel,
+                        parameter,
+                        value,
+                        time,
+                    },
+                    ParameterKind::Rpn => MidiEvent::Rpn {
+                        channel,
+                        parameter,
+                        value,
+                        time,
+                    },
+                })
+            }
+            0..=31 => {
+                state.msb[cc as usize] = Some(value);
+                None
+            }
+            32..=63 => {
+                let msb = state.msb[(cc - 32) as usize]?;
+                Some(MidiEvent::HighResCC {
+                    channel,
+                    cc: cc - 32,
+                    value: u14(msb, value),
+                    time,
+                })
+            }
+            _ => None,
+        }
+    }
 }
 
+/// Combine two 7-bit halves into a 14-bit value
+fn u14(msb: u8, lsb: u8) -> u16 {
+    ((msb as u16 & 0x7F) << 7) | (lsb as u16 & 0x7F)
+}
+
+/// High-level event detected by the EventProcessor
+///
+/// `channel` is the MIDI channel of the input that produced the event
+/// (always 0 for non-MIDI sources such as gamepads).
 #[derive(Debug, Clone)]
 pub enum ProcessedEvent {
     ShortPress {
+        channel: u8,
         note: u8,
     },
     MediumPress {
+        channel: u8,
         note: u8,
         duration_ms: u128,
     },
     LongPress {
+        channel: u8,
         note: u8,
         duration_ms: u128,
     },
+    /// A pad is still held past the shortest long-press threshold
+    ///
+    /// Reported on every [`EventProcessor::tick`] and once more on release.
+    /// `previous_ms` is the hold time at the previous report, so a threshold
+    /// is crossed exactly once when `previous_ms < threshold <= held_ms`.
     HoldDetected {
+        channel: u8,
         note: u8,
+        held_ms: u128,
+        previous_ms: u128,
     },
     PadPressed {
+        channel: u8,
         note: u8,
         velocity: u8,
         velocity_level: VelocityLevel,
     },
     PadReleased {
+        channel: u8,
         note: u8,
         hold_duration_ms: u128,
     },
     EncoderTurned {
+        channel: u8,
         cc: u8,
         value: u8,
         direction: EncoderDirection,
         delta: u8,
     },
     DoubleTap {
+        channel: u8,
         note: u8,
+        /// Time between the two taps
+        gap_ms: u128,
     },
     ChordDetected {
+        channel: u8,
         notes: Vec<u8>,
+        /// Time between the first and last note of the chord
+        span_ms: u128,
     },
     AftertouchChanged {
+        channel: u8,
+        pressure: u8,
+    },
+    /// A pad's polyphonic pressure changed
+    ///
+    /// `previous` is the pad's pressure before this change (0 after release),
+    /// so a threshold is crossed upwards when `previous < threshold <= pressure`.
+    PolyPressureChanged {
+        channel: u8,
+        note: u8,
         pressure: u8,
+        previous: u8,
     },
     PitchBendMoved {
+        channel: u8,
+        value: u16,
+    },
+    /// A 14-bit control change; `cc` is the MSB controller number (0-31)
+    HighResCCChanged {
+        channel: u8,
+        cc: u8,
+        value: u16,
+    },
+    NrpnChanged {
+        channel: u8,
+        parameter: u16,
         value: u16,
     },
+    RpnChanged {
+        channel: u8,
+        parameter: u16,
+        value: u16,
+    },
+    /// A System Exclusive message, including its `F0`/`F7` framing bytes
+    SysExReceived {
+        data: Vec<u8>,
+    },
+    TransportChanged {
+        command: TransportCommand,
+    },
+    /// The song position moved, in MIDI beats (sixteenth notes)
+    SongPositionChanged {
+        position: u16,
+    },
+    /// The tempo derived from MIDI clock changed
+    TempoChanged {
+        bpm: f64,
+    },
+    ProgramChanged {
+        channel: u8,
+        program: u8,
+    },
+    /// A CC moved into another of the zones of a `CCZone` trigger
+    CCZoneEntered {
+        channel: u8,
+        cc: u8,
+        zones: u8,
+        zone: u8,
+        value: u8,
+    },
+    /// A CC crossed the threshold of a `CCThreshold` trigger
+    CCThresholdCrossed {
+        channel: u8,
+        cc: u8,
+        threshold: u8,
+        direction: CrossingDirection,
+        value: u8,
+    },
+    /// A gesture on a touch strip sending pitch bend or a CC
+    GestureRecognized {
+        channel: u8,
+        source: TouchSource,
+        gesture: Gesture,
+    },
+    /// The steps of a `Sequence` trigger were pressed in order
+    SequenceMatched {
+        channel: u8,
+        steps: Vec<SequenceStep>,
+        /// Time between the first and last step
+        span_ms: u128,
+    },
+}
+
+impl ProcessedEvent {
+    /// The MIDI channel (0-15) of the input that produced this event
+    pub fn channel(&self) -> u8 {
+        match self {
+            ProcessedEvent::ShortPress { channel, .. }
+            | ProcessedEvent::MediumPress { channel, .. }
+            | ProcessedEvent::LongPress { channel, .. }
+            | ProcessedEvent::HoldDetected { channel, .. }
+            | ProcessedEvent::PadPressed { channel, .. }
+            | ProcessedEvent::PadReleased { channel, .. }
+            | ProcessedEvent::EncoderTurned { channel, .. }
+            | ProcessedEvent::DoubleTap { channel, .. }
+            | ProcessedEvent::ChordDetected { channel, .. }
+            | ProcessedEvent::AftertouchChanged { channel, .. }
+            | ProcessedEvent::PolyPressureChanged { channel, .. }
+            | ProcessedEvent::PitchBendMoved { channel, .. }
+            | ProcessedEvent::HighResCCChanged { channel, .. }
+            | ProcessedEvent::NrpnChanged { channel, .. }
+            | ProcessedEvent::RpnChanged { channel, .. }
+            | ProcessedEvent::ProgramChanged { channel, .. }
+            | ProcessedEvent::CCZoneEntered { channel, .. }
+            | ProcessedEvent::CCThresholdCrossed { channel, .. }
+            | ProcessedEvent::GestureRecognized { channel, .. }
+            | ProcessedEvent::SequenceMatched { channel, .. } => *channel,
+            ProcessedEvent::SysExReceived { .. }
+            | ProcessedEvent::TransportChanged { .. }
+            | ProcessedEvent::SongPositionChanged { .. }
+            | ProcessedEvent::TempoChanged { .. } => 0,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -198,21 +772,250 @@ pub enum VelocityLevel {
     Hard,
 }
 
+/// Default upper bound (inclusive) of the soft velocity band
+pub const DEFAULT_SOFT_MAX: u8 = 40;
+/// Default upper bound (inclusive) of the medium velocity band
+pub const DEFAULT_MEDIUM_MAX: u8 = 80;
+/// Default maximum time between the steps of a sequence
+pub const DEFAULT_SEQUENCE_GAP_MS: u64 = 500;
+/// Encoder turns arriving faster than this after the previous one are
+/// accelerated, fully so when back to back
+const ENCODER_ACCELERATION_WINDOW: Duration = Duration::from_millis(100);
+
+impl VelocityLevel {
+    /// Classify a velocity using the given band limits (both inclusive)
+    pub fn classify(velocity: u8, soft_max: u8, medium_max: u8) -> Self {
+        if velocity <= soft_max {
+            VelocityLevel::Soft
+        } else if velocity <= medium_max {
+            VelocityLevel::Medium
+        } else {
+            VelocityLevel::Hard
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum EncoderDirection {
     Clockwise,
     CounterClockwise,
 }
 
+/// Per-channel state key: (channel, note or CC number)
+type ChannelKey = (u8, u8);
+
+/// A pad that is currently held down
+struct HeldNote {
+    pressed: Instant,
+    /// Hold time at the last tick
+    reported_ms: u128,
+    /// Whether a tick has already emitted `LongPress` for this hold
+    long_press_sent: bool,
+    /// Whether a `while_held` mapping fired with this pad as a modifier
+    used_as_modifier: bool,
+}
+
+/// Gestures mapped on a pad that compete with its single-press mapping
+#[derive(Debug, Default)]
+struct Competition {
+    /// Window of a competing DoubleTap mapping
+    double_tap: Option<Duration>,
+    /// Sorted notes and window of each competing chord mapping
+    chords: Vec<(Vec<u8>, Duration)>,
+    /// Whether the pad is a `while_held` modifier of another mapping
+    modifier: bool,
+}
+
+impl Competition {
+    /// How long a press must wait before it can no longer start a gesture
+    fn window(&self) -> Duration {
+        self.chords
+            .iter()
+            .map(|(_, window)| *window)
+            .chain(self.double_tap)
+            .max()
+            .unwrap_or_default()
+    }
+}
+
+/// A single press held back until its competing gestures are ruled out
+struct PendingPress {
+    velocity: u8,
+    deadline: Instant,
+    /// Wait for the release as well, as the pad may be used as a modifier
+    until_release: bool,
+    /// Hold duration if the pad was released while the press was pending
+    released_after_ms: Option<u128>,
+}
+
+/// An input that can advance a sequence
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum StepInput {
+    /// Note or gamepad button press
+    Pad(u8),
+    /// CC value rising to 64 or above
+    Control(u8),
+}
+
+impl StepInput {
+    fn matches(self, step: &SequenceStep) -> bool {
+        match (self, step) {
+            (StepInput::Pad(pad), SequenceStep::Note { note: n })
+            | (StepInput::Pad(pad), SequenceStep::GamepadButton { button: n }) => pad == *n,
+            (StepInput::Control(control), SequenceStep::CC { cc }) => control == *cc,
+            _ => false,
+        }
+    }
+}
+
+/// Decoding of an encoder configured on an `EncoderTurn` trigger
+#[derive(Debug, Clone, Copy)]
+struct EncoderSettings {
+    cc: u8,
+    decrement_cc: Option<u8>,
+    channel: Option<u8>,
+    encoding: EncoderEncoding,
+    acceleration: Option<f32>,
+}
+
+impl EncoderSettings {
+    /// Whether a CC on `channel` belongs to this encoder
+    fn handles(&self, channel: u8, cc: u8) -> bool {
+        (self.cc == cc || self.decrement_cc == Some(cc))
+            && self.channel.is_none_or(|filter| filter == channel)
+    }
+}
+
+/// The zones of `CCZone` triggers on one CC, and the zone the control is
+/// in on each channel
+struct ZoneTracker {
+    cc: u8,
+    zones: u8,
+    /// Largest hysteresis of the triggers sharing these zones
+    hysteresis: u8,
+    current: HashMap<u8, u8>,
+}
+
+impl ZoneTracker {
+    /// Move the control to `value`, returning the zone it entered
+    ///
+    /// The first value always enters a zone. Afterwards the zone only changes
+    /// once the value is more than `hysteresis` past the current zone's bounds.
+    fn update(&mut self, channel: u8, value: u8) -> Option<u8> {
+        let zone = zone_of(value, self.zones);
+        if let Some(&current) = self.current.get(&channel) {
+            let lower = zone_start(current, self.zones);
+            let upper = zone_start(current + 1, self.zones) - 1;
+            let outside = (value as u16) + (self.hysteresis as u16) < lower
+                || value as u16 > upper + self.hysteresis as u16;
+            if zone == current || !outside {
+                return None;
+            }
+        }
+        self.current.insert(channel, zone);
+        Some(zone)
+    }
+}
+
+/// Zone (0 to `zones - 1`) of a 7-bit value split into `zones` equal zones
+fn zone_of(value: u8, zones: u8) -> u8 {
+    ((value.min(127) as u16 * zones as u16) / 128) as u8
+}
+
+/// Lowest value of `zone`; `zone_start(zones, zones)` is 128
+fn zone_start(zone: u8, zones: u8) -> u16 {
+    (zone as u16 * 128).div_ceil(zones as u16)
+}
+
+/// A `CCThreshold` trigger, and whether it's armed on each channel
+struct ThresholdTracker {
+    cc: u8,
+    threshold: u8,
+    direction: CrossingDirection,
+    /// Largest hysteresis of the triggers sharing this threshold
+    hysteresis: u8,
+    armed: HashMap<u8, bool>,
+}
+
+impl ThresholdTracker {
+    /// Move the control to `value`, returning whether it crossed the threshold
+    ///
+    /// The first value only arms or disarms the tracker.
+    fn update(&mut self, channel: u8, value: u8) -> bool {
+        let (value, threshold, hysteresis) =
+            (value as u16, self.threshold as u16, self.hysteresis as u16);
+        let (past, rearm) = match self.direction {
+            CrossingDirection::Rising => (value >= threshold, value + hysteresis < threshold),
+            CrossingDirection::Falling => (value < threshold, value >= threshold + hysteresis),
+        };
+
+        match self.armed.insert(channel, !past) {
+            Some(true) if past => true,
+            // Stay disarmed until the value moves back far enough
+            Some(false) if !rearm => {
+                self.armed.insert(channel, false);
+                false
+            }
+            _ => false,
+        }
+    }
+}
+
+/// A sequence trigger and how far it has been matched
+struct SequenceProgress {
+    steps: Vec<SequenceStep>,
+    max_gap: Duration,
+    suppress_steps: bool,
+    channel: Option<u8>,
+    /// Steps matched so far
+    matched: usize,
+    /// Channel and time of the first matched step
+    started: Option<(u8, Instant)>,
+    last_step: Option<Instant>,
+}
+
+impl SequenceProgress {
+    fn reset(&mut self) {
+        self.matched = 0;
+        self.started = None;
+        self.last_step = None;
+    }
+}
+
 pub struct EventProcessor {
-    note_press_times: HashMap<u8, Instant>,
-    held_notes: HashMap<u8, Instant>,
-    last_cc_values: HashMap<u8, u8>,
-    last_note_tap: HashMap<u8, Instant>,
-    chord_buffer: Vec<(u8, Instant)>,
+    note_press_times: HashMap<ChannelKey, Instant>,
+    held_notes: HashMap<ChannelKey, HeldNote>,
+    last_cc_values: HashMap<ChannelKey, u8>,
+    last_note_tap: HashMap<ChannelKey, Instant>,
+    chord_buffer: Vec<(ChannelKey, Instant)>,
     chord_timeout: Duration,
     double_tap_timeout: Duration,
     hold_threshold: Duration,
+    /// Shortest long-press threshold in use; holds are reported from here on
+    hold_report_after: Duration,
+    short_press: Duration,
+    medium_press: Duration,
+    soft_max: u8,
+    medium_max: u8,
+    /// Pads whose single press waits for competing gestures, by note
+    deferred_pads: HashMap<ChannelKey, Competition>,
+    pending_presses: HashMap<ChannelKey, PendingPress>,
+    sequences: Vec<SequenceProgress>,
+    /// Events of steps held back while a `suppress_steps` sequence is partially matched
+    held_back: Vec<ProcessedEvent>,
+    held_back_inputs: HashSet<(u8, StepInput)>,
+    /// Step inputs of a completed sequence whose events are dropped until release
+    dropped_inputs: HashSet<(u8, StepInput)>,
+    /// Encoders with a relative encoding or acceleration
+    encoders: Vec<EncoderSettings>,
+    /// Time of each encoder's last turn, for acceleration
+    last_turns: HashMap<ChannelKey, Instant>,
+    /// Polyphonic pressure of each pad since it was last released
+    pad_pressures: HashMap<ChannelKey, u8>,
+    cc_zones: Vec<ZoneTracker>,
+    cc_thresholds: Vec<ThresholdTracker>,
+    gestures: GestureRecognizer,
+    clock: ClockTracker,
 }
 
 impl Default for EventProcessor {
@@ -222,135 +1025,236 @@ impl Default for EventProcessor {
 }
 
 impl EventProcessor {
+    /// Create a processor with the default timings
     pub fn new() -> Self {
-        Self {
+        Self::with_settings(&AdvancedSettings::default())
+    }
+
+    /// Create a processor using the timings from `[advanced_settings]`
+    pub fn with_settings(settings: &AdvancedSettings) -> Self {
+        let mut processor = Self {
             note_press_times: HashMap::new(),
             held_notes: HashMap::new(),
             last_cc_values: HashMap::new(),
             last_note_tap: HashMap::new(),
             chord_buffer: Vec::new(),
-            chord_timeout: Duration::from_millis(50),
-            double_tap_timeout: Duration::from_millis(300),
-            hold_threshold: Duration::from_secs(2),
-        }
+            chord_timeout: Duration::ZERO,
+            double_tap_timeout: Duration::ZERO,
+            hold_threshold: Duration::ZERO,
+            hold_report_after: Duration::ZERO,
+            short_press: Duration::ZERO,
+            medium_press: Duration::ZERO,
+            soft_max: 0,
+            medium_max: 0,
+            deferred_pads: HashMap::new(),
+            pending_presses: HashMap::new(),
+            sequences: Vec::new(),
+            held_back: Vec::new(),
+            held_back_inputs: HashSet::new(),
+            dropped_inputs: HashSet::new(),
+            encoders: Vec::new(),
+            last_turns: HashMap::new(),
+            pad_pressures: HashMap::new(),
+            cc_zones: Vec::new(),
+            cc_thresholds: Vec::new(),
+            gestures: GestureRecognizer::default(),
+            clock: ClockTracker::default(),
+        };
+        processor.apply_settings(settings);
+        processor
     }
 
-    pub fn process(&mut self, event: MidiEvent) -> Vec<ProcessedEvent> {
-        let mut results = Vec::new();
+    /// Create a processor for a config
+    ///
+    /// See [`EventProcessor::apply_config`].
+    pub fn from_config(config: &Config) -> Self {
+        let mut processor = Self::new();
+        processor.apply_config(config);
+        processor
+    }
 
-        match event {
-            MidiEvent::NoteOn {
-                note,
-                velocity,
-                time,
-            } => {
-                self.note_press_times.insert(note, time);
-                self.held_notes.insert(note, time);
+    /// Apply timing settings from config without discarding in-flight state
+    ///
+    /// Used on config reload so held notes and pending taps survive the change.
+    pub fn apply_settings(&mut self, settings: &AdvancedSettings) {
+        self.chord_timeout = Duration::from_millis(settings.chord_timeout_ms);
+        self.double_tap_timeout = Duration::from_millis(settings.double_tap_timeout_ms);
+        self.hold_threshold = Duration::from_millis(settings.hold_threshold_ms);
+        self.hold_report_after = self.hold_threshold;
+        self.short_press = Duration::from_millis(settings.short_press_ms);
+        self.medium_press = Duration::from_millis(settings.medium_press_ms);
+        self.soft_max = settings.velocity_soft_max;
+        self.medium_max = settings.velocity_medium_max;
+    }
 
-                // Check for double-tap
-                if let Some(&last_tap_time) = self.last_note_tap.get(&note) {
-                    if time.duration_since(last_tap_time) < self.double_tap_timeout {
-                        results.push(ProcessedEvent::DoubleTap { note });
-                        self.last_note_tap.remove(&note);
-                    } else {
-                        self.last_note_tap.insert(note, time);
+    /// Apply `[advanced_settings]` and widen the detection windows to cover
+    /// per-trigger `timeout_ms` and `duration_ms` overrides
+    ///
+    /// Double-taps and chords are reported with their gap/span, and holds with
+    /// their duration, so the MappingEngine can still apply each trigger's own
+    /// (possibly narrower) window.
+    ///
+    /// Also finds pads whose single-press mapping competes with a double-tap or
+    /// chord mapping (in any mode). Their `PadPressed` is held back until the
+    /// gesture window expires and dropped if the gesture completes, so exactly
+    /// one of them fires. Mappings with `immediate = true` opt out. The same
+    /// applies to pads listed in another mapping's `while_held`, whose press
+    /// waits for the release and is dropped if the pad was used as a modifier
+    /// (see [`EventProcessor::mark_modifiers_used`]).
+    ///
+    /// Sequence triggers are tracked from here on, with their progress reset.
+    /// Encoders take the encoding and acceleration of the first `EncoderTurn`
+    /// trigger on their CC. `CCZone` and `CCThreshold` triggers with the same
+    /// zones or threshold share the largest of their hysteresis values.
+    pub fn apply_config(&mut self, config: &Config) {
+        self.apply_settings(&config.advanced_settings);
+
+        let mappings: Vec<&Mapping> = config
+            .global_mappings
+            .iter()
+            .chain(config.modes.iter().flat_map(|mode| &mode.mappings))
+            .collect();
+        self.deferred_pads = competing_gestures(&mappings, &config.advanced_settings);
+        self.sequences = mappings
+            .iter()
+            .filter_map(|mapping| match &mapping.trigger {
+                Trigger::Sequence {
+                    steps,
+                    max_gap_ms,
+                    suppress_steps,
+                    channel,
+                } if !steps.is_empty() => Some(SequenceProgress {
+                    steps: steps.clone(),
+                    max_gap: Duration::from_millis(max_gap_ms.unwrap_or(DEFAULT_SEQUENCE_GAP_MS)),
+                    suppress_steps: *suppress_steps,
+                    channel: *channel,
+                    matched: 0,
+                    started: None,
+                    last_step: None,
+                }),
+                _ => None,
+            })
+            .collect();
+        self.encoders = mappings
+            .iter()
+            .filter_map(|mapping| match &mapping.trigger {
+                Trigger::EncoderTurn {
+                    cc,
+                    channel,
+                    encoding,
+                    decrement_cc,
+                    acceleration,
+                    ..
+                } if *encoding != EncoderEncoding::Absolute || acceleration.is_some() => {
+                    Some(EncoderSettings {
+                        cc: *cc,
+                        decrement_cc: *decrement_cc,
+                        channel: *channel,
+                        encoding: *encoding,
+                        acceleration: *acceleration,
+                    })
+                }
+                _ => None,
+            })
+            .collect();
+
+        self.cc_zones.clear();
+        self.cc_thresholds.clear();
+        for trigger in mappings.iter().map(|mapping| &mapping.trigger) {
+            match *trigger {
+                Trigger::CCZone {
+                    cc,
+                    zones,
+                    hysteresis,
+                    ..
+                } => {
+                    let hysteresis = hysteresis.unwrap_or(0);
+                    match self
+                        .cc_zones
+                        .iter_mut()
+                        .find(|tracker| tracker.cc == cc && tracker.zones == zones)
+                    {
+                        Some(tracker) => tracker.hysteresis = tracker.hysteresis.max(hysteresis),
+                        None => self.cc_zones.push(ZoneTracker {
+                            cc,
+                            zones,
+                            hysteresis,
+                            current: HashMap::new(),
+                        }),
                     }
-                } else {
-                    self.last_note_tap.insert(note, time);
                 }
-
-                // Detect velocity levels
-                let velocity_level = match velocity {
-                    0..=40 => VelocityLevel::Soft,
-                    41..=80 => VelocityLevel::Medium,
-                    81..=127 => VelocityLevel::Hard,
-                    _ => VelocityLevel::Medium,
-                };
-
-                results.push(ProcessedEvent::PadPressed {
-                    note,
-                    velocity,
-                    velocity_level,
-                });
-
-                // Add to chord buffer
-                self.chord_buffer.push((note, time));
-
-                // Check for chord (multiple notes pressed within chord_timeout)
-                self.chord_buffer
-                    .retain(|(_, t)| time.duration_since(*t) < self.chord_timeout);
-
-                if self.chord_buffer.len() > 1 {
-                    let notes: Vec<u8> = self.chord_buffer.iter().map(|(n, _)| *n).collect();
-                    results.push(ProcessedEvent::ChordDetected { notes });
+                Trigger::CCThreshold {
+                    cc,
+                    threshold,
+                    direction,
+                    hysteresis,
+                    ..
+                } => {
+                    let hysteresis = hysteresis.unwrap_or(0);
+                    match self.cc_thresholds.iter_mut().find(|tracker| {
+                        tracker.cc == cc
+                            && tracker.threshold == threshold
+                            && tracker.direction == direction
+                    }) {
+                        Some(tracker) => tracker.hysteresis = tracker.hysteresis.max(hysteresis),
+                        None => self.cc_thresholds.push(ThresholdTracker {
+                            cc,
+                            threshold,
+                            direction,
+                            hysteresis,
+                            armed: HashMap::new(),
+                        }),
+                    }
                 }
+                _ => {}
             }
-
-            MidiEvent::NoteOff { note, time } => {
-                if let Some(press_time) = self.note_press_times.remove(&note) {
-                    let duration = time.duration_since(press_time);
-                    let duration_ms = duration.as_millis();
-
-                    results.push(ProcessedEvent::PadReleased {
-                        note,
-                        hold_duration_ms: duration_ms,
-                    });
-
-                    if duration_ms < 200 {
-                        results.push(ProcessedEvent::ShortPress { note });
-                    } else if duration_ms < 1000 {
-                        results.push(ProcessedEvent::MediumPress { note, duration_ms });
-                    } else {
-                        results.push(ProcessedEvent::LongPress { note, duration_ms });
-                    }
+        }
+        let touch_sources = mappings
+            .iter()
+            .filter_map(|mapping| match mapping.trigger {
+                Trigger::TouchSwipe { cc, .. }
+                | Trigger::TouchTap { cc, .. }
+                | Trigger::TouchSlideHold { cc, .. } => Some(TouchSource::from_cc(cc)),
+                _ => None,
+            })
+            .collect();
+        self.gestures.set_sources(touch_sources);
+
+        for trigger in mappings.iter().map(|mapping| &mapping.trigger) {
+            match trigger {
+                Trigger::DoubleTap {
+                    timeout_ms: Some(ms),
+                    ..
+                } => {
+                    self.double_tap_timeout =
+                        self.double_tap_timeout.max(Duration::from_millis(*ms));
                 }
-                self.held_notes.remove(&note);
-
-                // Remove from chord buffer
-                self.chord_buffer.retain(|(n, _)| *n != note);
+                Trigger::NoteChord {
+                    timeout_ms: Some(ms),
+                    ..
+                }
+                | Trigger::GamepadButtonChord {
+                    timeout_ms: Some(ms),
+                    ..
+                } => {
+                    self.chord_timeout = self.chord_timeout.max(Duration::from_millis(*ms));
+                }
+                Trigger::LongPress {
+                    duration_ms: Some(ms),
+                    ..
+                } => {
+                    self.hold_report_after = self.hold_report_after.min(Duration::from_millis(*ms));
+                }
+                _ => {}
             }
+        }
+    }
 
-            MidiEvent::ControlChange { cc, value, .. } => {
-                // Detect encoder direction
-                if let Some(&last_value) = self.last_cc_values.get(&cc) {
-                    let direction = if value > last_value {
-                        EncoderDirection::Clockwise
-                    } else if value < last_value {
-                        EncoderDirection::CounterClockwise
-                    } else {
-                        // No change
-                        return results;
-                    };
-
-                    let delta = (value as i16 - last_value as i16).unsigned_abs() as u8;
-
-                    results.push(ProcessedEvent::EncoderTurned {
-                        cc,
-                        value,
-                        direction,
-                        delta,
-                    });
-                }
-                self.last_cc_values.insert(cc, value);
-            }
-
-            MidiEvent::PolyPressure { .. } => {
-                // Polyphonic aftertouch is received but not currently processed
-                // into high-level events. This is a placeholder for future support.
-            }
-
-            MidiEvent::Aftertouch { pressure, .. } => {
-                results.push(ProcessedEvent::AftertouchChanged { pressure });
-            }
-
-            MidiEvent::PitchBend { value, .. } => {
-                results.push(ProcessedEvent::PitchBendMoved { value });
-            }
-
-            _ => {}
-        }
-
-        results
+    /// Process a raw MIDI event
+    ///
+    /// Equivalent to `process_input(event.into())`.
+    pub fn process(&mut self, event: MidiEvent) -> Vec<ProcessedEvent> {
+        self.process_input(event.into())
     }
 
     /// Process a protocol-agnostic InputEvent (v3.0)
@@ -377,6 +1281,7 @@ impl EventProcessor {
     ///
     /// // Gamepad button press (button ID 128 = South/A/Cross/B)
     /// let event = InputEvent::PadPressed {
+    ///     channel: 0,
     ///     pad: 128,
     ///     velocity: 100,
     ///     time: Instant::now(),
@@ -386,176 +1291,741 @@ impl EventProcessor {
     /// // Will detect velocity level, double-tap, chords, etc.
     /// ```
     pub fn process_input(&mut self, event: InputEvent) -> Vec<ProcessedEvent> {
+        let time = event.timestamp();
         let mut results = Vec::new();
+        self.resolve_pending(time, &mut results);
 
+        // Before detect_gestures records the new CC value
+        let step = self.step_input(&event);
+        let results = self.detect_gestures(event, results);
+        self.track_sequences(step, time, results)
+    }
+
+    /// Turn an input into gesture events, appended to `results`
+    fn detect_gestures(
+        &mut self,
+        event: InputEvent,
+        mut results: Vec<ProcessedEvent>,
+    ) -> Vec<ProcessedEvent> {
         match event {
             InputEvent::PadPressed {
+                channel,
                 pad,
                 velocity,
                 time,
             } => {
-                self.note_press_times.insert(pad, time);
-                self.held_notes.insert(pad, time);
+                let key = (channel, pad);
+                self.note_press_times.insert(key, time);
+                self.held_notes.insert(
+                    key,
+                    HeldNote {
+                        pressed: time,
+                        reported_ms: 0,
+                        long_press_sent: false,
+                        used_as_modifier: false,
+                    },
+                );
 
                 // Check for double-tap
-                if let Some(&last_tap_time) = self.last_note_tap.get(&pad) {
-                    if time.duration_since(last_tap_time) < self.double_tap_timeout {
-                        results.push(ProcessedEvent::DoubleTap { note: pad });
-                        self.last_note_tap.remove(&pad);
+                let mut double_tap_gap = None;
+                if let Some(&last_tap_time) = self.last_note_tap.get(&key) {
+                    let gap = time.duration_since(last_tap_time);
+                    if gap < self.double_tap_timeout {
+                        results.push(ProcessedEvent::DoubleTap {
+                            channel,
+                            note: pad,
+                            gap_ms: gap.as_millis(),
+                        });
+                        self.last_note_tap.remove(&key);
+                        double_tap_gap = Some(gap);
                     } else {
-                        self.last_note_tap.insert(pad, time);
+                        self.last_note_tap.insert(key, time);
                     }
                 } else {
-                    self.last_note_tap.insert(pad, time);
+                    self.last_note_tap.insert(key, time);
                 }
 
-                // Detect velocity levels
-                let velocity_level = match velocity {
-                    0..=40 => VelocityLevel::Soft,
-                    41..=80 => VelocityLevel::Medium,
-                    81..=127 => VelocityLevel::Hard,
-                    _ => VelocityLevel::Medium,
-                };
-
-                results.push(ProcessedEvent::PadPressed {
-                    note: pad,
-                    velocity,
-                    velocity_level,
-                });
-
                 // Add to chord buffer
-                self.chord_buffer.push((pad, time));
+                self.chord_buffer.push((key, time));
 
-                // Check for chord (multiple pads pressed within chord_timeout)
+                // Check for chord (multiple pads pressed within chord_timeout on this channel)
                 self.chord_buffer
                     .retain(|(_, t)| time.duration_since(*t) < self.chord_timeout);
 
-                if self.chord_buffer.len() > 1 {
-                    let notes: Vec<u8> = self.chord_buffer.iter().map(|(n, _)| *n).collect();
-                    results.push(ProcessedEvent::ChordDetected { notes });
+                let chord: Vec<(u8, Instant)> = self
+                    .chord_buffer
+                    .iter()
+                    .filter(|((c, _), _)| *c == channel)
+                    .map(|((_, n), t)| (*n, *t))
+                    .collect();
+                let chord = (chord.len() > 1).then(|| {
+                    let first = chord.iter().map(|(_, t)| *t).min().unwrap_or(time);
+                    let notes: Vec<u8> = chord.into_iter().map(|(n, _)| n).collect();
+                    (notes, time.duration_since(first))
+                });
+
+                // A press that completes a competing gesture is replaced by it;
+                // otherwise a contested press waits until the gesture is ruled out
+                if !self.resolve_gesture(key, double_tap_gap, chord.as_ref()) {
+                    if let Some(competition) = self.deferred_pads.get(&key) {
+                        self.pending_presses.insert(
+                            key,
+                            PendingPress {
+                                velocity,
+                                deadline: time + competition.window(),
+                                until_release: competition.modifier,
+                                released_after_ms: None,
+                            },
+                        );
+                    } else {
+                        results.push(ProcessedEvent::PadPressed {
+                            channel,
+                            note: pad,
+                            velocity,
+                            velocity_level: VelocityLevel::classify(
+                                velocity,
+                                self.soft_max,
+                                self.medium_max,
+                            ),
+                        });
+                    }
+                }
+
+                if let Some((notes, span)) = chord {
+                    results.push(ProcessedEvent::ChordDetected {
+                        channel,
+                        notes,
+                        span_ms: span.as_millis(),
+                    });
                 }
             }
 
-            InputEvent::PadReleased { pad, time } => {
-                if let Some(press_time) = self.note_press_times.remove(&pad) {
+            InputEvent::PadReleased { channel, pad, time } => {
+                let key = (channel, pad);
+                self.pad_pressures.remove(&key);
+                let held = self.held_notes.remove(&key);
+                if let Some(press_time) = self.note_press_times.remove(&key) {
                     let duration = time.duration_since(press_time);
                     let duration_ms = duration.as_millis();
+                    let reported_ms = held.as_ref().map_or(0, |h| h.reported_ms);
+                    let long_press_sent = held.as_ref().is_some_and(|h| h.long_press_sent);
+                    let used_as_modifier = held.as_ref().is_some_and(|h| h.used_as_modifier);
+
+                    // Final hold report, so a threshold crossed since the last tick still fires
+                    if !used_as_modifier
+                        && duration >= self.hold_report_after
+                        && duration_ms > reported_ms
+                    {
+                        results.push(ProcessedEvent::HoldDetected {
+                            channel,
+                            note: pad,
+                            held_ms: duration_ms,
+                            previous_ms: reported_ms,
+                        });
+                    }
 
-                    results.push(ProcessedEvent::PadReleased {
-                        note: pad,
-                        hold_duration_ms: duration_ms,
-                    });
+                    // The release of a held-back press is reported after the press resolves
+                    if let Some(pending) = self.pending_presses.get_mut(&key) {
+                        pending.released_after_ms = Some(duration_ms);
+                        // A modifier pad that wasn't used as one fires its press now
+                        if pending.until_release {
+                            self.resolve_pending(time, &mut results);
+                        }
+                    } else {
+                        results.push(ProcessedEvent::PadReleased {
+                            channel,
+                            note: pad,
+                            hold_duration_ms: duration_ms,
+                        });
+                    }
 
-                    if duration_ms < 200 {
-                        results.push(ProcessedEvent::ShortPress { note: pad });
-                    } else if duration_ms < 1000 {
+                    // Presses between the medium cut-off and the hold
+                    // threshold are neither
+                    if duration < self.short_press {
+                        results.push(ProcessedEvent::ShortPress { channel, note: pad });
+                    } else if duration < self.medium_press {
                         results.push(ProcessedEvent::MediumPress {
+                            channel,
                             note: pad,
                             duration_ms,
                         });
-                    } else {
+                    } else if duration >= self.hold_threshold
+                        && !long_press_sent
+                        && !used_as_modifier
+                    {
                         results.push(ProcessedEvent::LongPress {
+                            channel,
                             note: pad,
                             duration_ms,
                         });
                     }
                 }
-                self.held_notes.remove(&pad);
 
                 // Remove from chord buffer
-                self.chord_buffer.retain(|(n, _)| *n != pad);
+                self.chord_buffer.retain(|(k, _)| *k != key);
             }
 
+            // Generic control changes are treated like encoders
             InputEvent::EncoderTurned {
-                encoder,
+                channel,
+                encoder: cc,
                 value,
+                time,
+            }
+            | InputEvent::ControlChange {
+                channel,
+                control: cc,
+                value,
+                time,
+            } => {
+                let turned = self.encoder_turn(channel, cc, value, time);
+                // Zones and thresholds follow the virtual position of relative encoders
+                let position = match &turned {
+                    Some(ProcessedEvent::EncoderTurned { cc, value, .. }) => Some((*cc, *value)),
+                    _ => self
+                        .encoders
+                        .iter()
+                        .find(|encoder| encoder.handles(channel, cc))
+                        .is_none_or(|encoder| encoder.encoding == EncoderEncoding::Absolute)
+                        .then_some((cc, value)),
+                };
+                results.extend(turned);
+                if let Some((cc, value)) = position {
+                    results.extend(self.cc_crossings(channel, cc, value));
+                    let source = TouchSource::ControlChange(cc);
+                    results.extend(self.touch(channel, source, value as f32 / 127.0, time));
+                }
+            }
+
+            InputEvent::PolyPressure {
+                channel,
+                pad,
+                pressure,
                 ..
             } => {
-                // Detect encoder direction
-                if let Some(&last_value) = self.last_cc_values.get(&encoder) {
-                    let direction = if value > last_value {
-                        EncoderDirection::Clockwise
-                    } else if value < last_value {
-                        EncoderDirection::CounterClockwise
-                    } else {
-                        // No change
-                        return results;
-                    };
+                let previous = self
+                    .pad_pressures
+                    .insert((channel, pad), pressure)
+                    .unwrap_or(0);
+                results.push(ProcessedEvent::PolyPressureChanged {
+                    channel,
+                    note: pad,
+                    pressure,
+                    previous,
+                });
+            }
 
-                    let delta = (value as i16 - last_value as i16).unsigned_abs() as u8;
+            InputEvent::Aftertouch {
+                channel, pressure, ..
+            } => {
+                results.push(ProcessedEvent::AftertouchChanged { channel, pressure });
+            }
 
-                    results.push(ProcessedEvent::EncoderTurned {
-                        cc: encoder,
-                        value,
-                        direction,
-                        delta,
-                    });
+            InputEvent::PitchBend {
+                channel,
+                value,
+                time,
+            } => {
+                results.push(ProcessedEvent::PitchBendMoved { channel, value });
+                let position = value.min(16383) as f32 / 16383.0;
+                results.extend(self.touch(channel, TouchSource::PitchBend, position, time));
+            }
+
+            InputEvent::HighResControlChange {
+                channel,
+                control,
+                value,
+                ..
+            } => {
+                results.push(ProcessedEvent::HighResCCChanged {
+                    channel,
+                    cc: control,
+                    value,
+                });
+            }
+
+            InputEvent::Nrpn {
+                channel,
+                parameter,
+                value,
+                ..
+            } => {
+                results.push(ProcessedEvent::NrpnChanged {
+                    channel,
+                    parameter,
+                    value,
+                });
+            }
+
+            InputEvent::Rpn {
+                channel,
+                parameter,
+                value,
+                ..
+            } => {
+                results.push(ProcessedEvent::RpnChanged {
+                    channel,
+                    parameter,
+                    value,
+                });
+            }
+
+            InputEvent::SysEx { data, .. } => {
+                results.push(ProcessedEvent::SysExReceived { data });
+            }
+
+            InputEvent::Clock { time } => {
+                if let Some(bpm) = self.clock.pulse(time) {
+                    results.push(ProcessedEvent::TempoChanged { bpm });
                 }
-                self.last_cc_values.insert(encoder, value);
             }
 
-            InputEvent::PolyPressure { .. } => {
-                // Polyphonic aftertouch/pressure is received but not currently processed
-                // into high-level events. This is a placeholder for future support.
+            InputEvent::Transport { command, .. } => {
+                self.clock.transport(command);
+                results.push(ProcessedEvent::TransportChanged { command });
             }
 
-            InputEvent::Aftertouch { pressure, .. } => {
-                results.push(ProcessedEvent::AftertouchChanged { pressure });
+            InputEvent::SongPosition { position, .. } => {
+                self.clock.song_position(position);
+                results.push(ProcessedEvent::SongPositionChanged { position });
             }
 
-            InputEvent::PitchBend { value, .. } => {
-                results.push(ProcessedEvent::PitchBendMoved { value });
+            InputEvent::ProgramChange {
+                channel, program, ..
+            } => {
+                results.push(ProcessedEvent::ProgramChanged { channel, program });
             }
+        }
 
-            InputEvent::ControlChange { control, value, .. } => {
-                // Generic control change - treat like encoder for now
-                if let Some(&last_value) = self.last_cc_values.get(&control) {
-                    let direction = if value > last_value {
-                        EncoderDirection::Clockwise
-                    } else if value < last_value {
-                        EncoderDirection::CounterClockwise
-                    } else {
-                        return results;
-                    };
+        results
+    }
 
-                    let delta = (value as i16 - last_value as i16).unsigned_abs() as u8;
+    /// Decode a CC message into an encoder turn
+    ///
+    /// Absolute encoders turn by the change from their last value, so their
+    /// first message only records the position. Relative encoders turn by the
+    /// steps they report and move a virtual position (starting at 64) so that
+    /// `value` still follows the knob.
+    fn encoder_turn(
+        &mut self,
+        channel: u8,
+        cc: u8,
+        value: u8,
+        time: Instant,
+    ) -> Option<ProcessedEvent> {
+        let encoder = self
+            .encoders
+            .iter()
+            .find(|encoder| encoder.handles(channel, cc))
+            .copied();
+        let encoding = encoder.map_or(EncoderEncoding::Absolute, |encoder| encoder.encoding);
+
+        let (cc, steps) = match (encoding, encoder) {
+            (EncoderEncoding::Absolute, _) => {
+                let last = self.last_cc_values.insert((channel, cc), value)?;
+                (cc, value as i16 - last as i16)
+            }
+            // Button releases send 0
+            (EncoderEncoding::IncDec, _) if value == 0 => return None,
+            (EncoderEncoding::IncDec, Some(encoder)) if cc != encoder.cc => (encoder.cc, -1),
+            (EncoderEncoding::IncDec, _) => (cc, 1),
+            (relative, _) => (cc, relative_steps(relative, value)),
+        };
+        if steps == 0 {
+            return None;
+        }
 
-                    results.push(ProcessedEvent::EncoderTurned {
-                        cc: control,
-                        value,
-                        direction,
-                        delta,
+        let key = (channel, cc);
+        let since_last = self
+            .last_turns
+            .insert(key, time)
+            .map(|last| time.saturating_duration_since(last));
+        let delta = match encoder.and_then(|encoder| encoder.acceleration) {
+            Some(acceleration) => accelerate(steps.unsigned_abs(), acceleration, since_last),
+            None => steps.unsigned_abs().min(127) as u8,
+        };
+        let direction = if steps > 0 {
+            EncoderDirection::Clockwise
+        } else {
+            EncoderDirection::CounterClockwise
+        };
+
+        let value = if encoding == EncoderEncoding::Absolute {
+            value
+        } else {
+            let last = self.last_cc_values.get(&key).copied().unwrap_or(64);
+            let moved = match direction {
+                EncoderDirection::Clockwise => last.saturating_add(delta).min(127),
+                EncoderDirection::CounterClockwise => last.saturating_sub(delta),
+            };
+            self.last_cc_values.insert(key, moved);
+            moved
+        };
+
+        Some(ProcessedEvent::EncoderTurned {
+            channel,
+            cc,
+            value,
+            direction,
+            delta,
+        })
+    }
+
+    /// Zone entries and threshold crossings of a CC moving to `value`
+    fn cc_crossings(&mut self, channel: u8, cc: u8, value: u8) -> Vec<ProcessedEvent> {
+        let mut results = Vec::new();
+        for tracker in self.cc_zones.iter_mut().filter(|tracker| tracker.cc == cc) {
+            if let Some(zone) = tracker.update(channel, value) {
+                results.push(ProcessedEvent::CCZoneEntered {
+                    channel,
+                    cc,
+                    zones: tracker.zones,
+                    zone,
+                    value,
+                });
+            }
+        }
+        for tracker in self
+            .cc_thresholds
+            .iter_mut()
+            .filter(|tracker| tracker.cc == cc)
+        {
+            if tracker.update(channel, value) {
+                results.push(ProcessedEvent::CCThresholdCrossed {
+                    channel,
+                    cc,
+                    threshold: tracker.threshold,
+                    direction: tracker.direction,
+                    value,
+                });
+            }
+        }
+        results
+    }
+
+    /// Gestures of a touch strip moving to `position`
+    fn touch(
+        &mut self,
+        channel: u8,
+        source: TouchSource,
+        position: f32,
+        time: Instant,
+    ) -> Vec<ProcessedEvent> {
+        self.gestures
+            .update(channel, source, position, time)
+            .into_iter()
+            .map(|gesture| ProcessedEvent::GestureRecognized {
+                channel,
+                source,
+                gesture,
+            })
+            .collect()
+    }
+
+    /// Advance hold timers to `now`
+    ///
+    /// Call periodically while pads may be held (the daemon drives this from a
+    /// short interval timer). Every held pad past the shortest long-press
+    /// threshold reports a `HoldDetected` covering the time since the previous
+    /// tick, so long-press triggers fire while the pad is still down. The first
+    /// tick past `hold_threshold_ms` also emits `LongPress`, which is then not
+    /// repeated on release.
+    pub fn tick(&mut self, now: Instant) -> Vec<ProcessedEvent> {
+        let mut results = Vec::new();
+        self.resolve_pending(now, &mut results);
+
+        for (&(channel, note), held) in &mut self.held_notes {
+            let elapsed = now.saturating_duration_since(held.pressed);
+            let held_ms = elapsed.as_millis();
+            if held_ms <= held.reported_ms || held.used_as_modifier {
+                continue;
+            }
+
+            if elapsed >= self.hold_report_after {
+                results.push(ProcessedEvent::HoldDetected {
+                    channel,
+                    note,
+                    held_ms,
+                    previous_ms: held.reported_ms,
+                });
+            }
+
+            if !held.long_press_sent && elapsed >= self.hold_threshold {
+                held.long_press_sent = true;
+                results.push(ProcessedEvent::LongPress {
+                    channel,
+                    note,
+                    duration_ms: held_ms,
+                });
+            }
+
+            held.reported_ms = held_ms;
+        }
+
+        // Touch strips send nothing on release, so lifts are found here
+        for (channel, source, gesture) in self.gestures.tick(now) {
+            results.push(ProcessedEvent::GestureRecognized {
+                channel,
+                source,
+                gesture,
+            });
+        }
+
+        self.track_sequences(None, now, results)
+    }
+
+    /// The sequence step an input represents, if any
+    fn step_input(&self, event: &InputEvent) -> Option<(u8, StepInput)> {
+        match *event {
+            InputEvent::PadPressed { channel, pad, .. } => Some((channel, StepInput::Pad(pad))),
+            InputEvent::EncoderTurned {
+                channel,
+                encoder: control,
+                value,
+                ..
+            }
+            | InputEvent::ControlChange {
+                channel,
+                control,
+                value,
+                ..
+            } => {
+                let last = self.last_cc_values.get(&(channel, control)).copied();
+                (value >= 64 && last.is_none_or(|last| last < 64))
+                    .then_some((channel, StepInput::Control(control)))
+            }
+            _ => None,
+        }
+    }
+
+    /// Reset sequences whose next step is overdue at `now`
+    fn expire_sequences(&mut self, now: Instant) {
+        for sequence in &mut self.sequences {
+            if sequence
+                .last_step
+                .is_some_and(|last| now.saturating_duration_since(last) > sequence.max_gap)
+            {
+                sequence.reset();
+            }
+        }
+    }
+
+    /// Advance sequences with a step input and report completed ones
+    ///
+    /// Events of steps that a `suppress_steps` sequence is waiting on are held
+    /// back: dropped if the sequence completes, replayed in order once no such
+    /// sequence is partially matched any more.
+    fn track_sequences(
+        &mut self,
+        step: Option<(u8, StepInput)>,
+        time: Instant,
+        results: Vec<ProcessedEvent>,
+    ) -> Vec<ProcessedEvent> {
+        if self.sequences.is_empty() && self.held_back.is_empty() && self.dropped_inputs.is_empty()
+        {
+            return results;
+        }
+
+        self.expire_sequences(time);
+
+        let mut matched = Vec::new();
+        let mut suppressed = false;
+        let mut completed = false;
+        if let Some((channel, input)) = step {
+            for sequence in &mut self.sequences {
+                if sequence.channel.is_some_and(|c| c != channel) {
+                    continue;
+                }
+
+                let same_channel = sequence.started.is_none_or(|(c, _)| c == channel);
+                if same_channel && input.matches(&sequence.steps[sequence.matched]) {
+                    sequence.matched += 1;
+                } else if input.matches(&sequence.steps[0]) {
+                    sequence.reset();
+                    sequence.matched = 1;
+                } else {
+                    sequence.reset();
+                    continue;
+                }
+
+                let (_, started) = *sequence.started.get_or_insert((channel, time));
+                sequence.last_step = Some(time);
+                suppressed |= sequence.suppress_steps;
+
+                if sequence.matched == sequence.steps.len() {
+                    matched.push(ProcessedEvent::SequenceMatched {
+                        channel,
+                        steps: sequence.steps.clone(),
+                        span_ms: time.duration_since(started).as_millis(),
                     });
+                    completed |= sequence.suppress_steps;
+                    sequence.reset();
                 }
-                self.last_cc_values.insert(control, value);
             }
+        }
+
+        if suppressed && let Some(step) = step {
+            self.held_back_inputs.insert(step);
+        }
 
-            InputEvent::ProgramChange { .. } => {
-                // Program change events are not currently processed into high-level events
+        let mut output = Vec::new();
+        for event in results {
+            let inputs = event_step_inputs(&event);
+            if inputs
+                .iter()
+                .any(|input| self.dropped_inputs.contains(input))
+            {
+                if let ProcessedEvent::PadReleased { channel, note, .. } = event {
+                    self.dropped_inputs.remove(&(channel, StepInput::Pad(note)));
+                }
+            } else if inputs
+                .iter()
+                .any(|input| self.held_back_inputs.contains(input))
+            {
+                self.held_back.push(event);
+            } else {
+                output.push(event);
             }
         }
 
-        results
+        if completed {
+            // The steps fired the sequence instead; pads still down stay silent
+            self.held_back.clear();
+            for (channel, input) in std::mem::take(&mut self.held_back_inputs) {
+                if let StepInput::Pad(pad) = input {
+                    self.pending_presses.remove(&(channel, pad));
+                    if self.held_notes.contains_key(&(channel, pad)) {
+                        self.dropped_inputs.insert((channel, input));
+                    }
+                }
+            }
+        } else if !self
+            .sequences
+            .iter()
+            .any(|sequence| sequence.suppress_steps && sequence.matched > 0)
+        {
+            self.held_back_inputs.clear();
+            output.splice(0..0, self.held_back.drain(..));
+        }
+
+        output.extend(matched);
+        output
+    }
+
+    /// Drop the held-back single press of each deferred pad that the gesture
+    /// completed by this press replaces
+    ///
+    /// Returns true if the pressed pad itself was part of the gesture.
+    fn resolve_gesture(
+        &mut self,
+        (channel, pad): ChannelKey,
+        double_tap_gap: Option<Duration>,
+        chord: Option<&(Vec<u8>, Duration)>,
+    ) -> bool {
+        let mut resolved = false;
+
+        if let Some(gap) = double_tap_gap
+            && let Some(competition) = self.deferred_pads.get(&(channel, pad))
+            && competition.double_tap.is_some_and(|window| gap < window)
+        {
+            self.pending_presses.remove(&(channel, pad));
+            resolved = true;
+        }
+
+        if let Some((notes, span)) = chord {
+            let mut sorted = notes.clone();
+            sorted.sort_unstable();
+            for &note in notes {
+                let completes =
+                    self.deferred_pads
+                        .get(&(channel, note))
+                        .is_some_and(|competition| {
+                            competition.chords.iter().any(|(chord_notes, window)| {
+                                *chord_notes == sorted && span < window
+                            })
+                        });
+                if completes {
+                    self.pending_presses.remove(&(channel, note));
+                    resolved |= note == pad;
+                }
+            }
+        }
+
+        resolved
+    }
+
+    /// Emit held-back single presses whose gesture windows have expired by `now`,
+    /// each followed by its release if the pad was already let go
+    fn resolve_pending(&mut self, now: Instant, results: &mut Vec<ProcessedEvent>) {
+        let (soft_max, medium_max) = (self.soft_max, self.medium_max);
+        self.pending_presses.retain(|&(channel, note), pending| {
+            if pending.deadline > now
+                || (pending.until_release && pending.released_after_ms.is_none())
+            {
+                return true;
+            }
+            results.push(ProcessedEvent::PadPressed {
+                channel,
+                note,
+                velocity: pending.velocity,
+                velocity_level: VelocityLevel::classify(pending.velocity, soft_max, medium_max),
+            });
+            if let Some(hold_duration_ms) = pending.released_after_ms {
+                results.push(ProcessedEvent::PadReleased {
+                    channel,
+                    note,
+                    hold_duration_ms,
+                });
+            }
+            false
+        });
     }
 
+    /// Check held pads against the hold threshold using the current time
+    ///
+    /// Equivalent to `tick(Instant::now())`.
     pub fn check_holds(&mut self) -> Vec<ProcessedEvent> {
-        let mut results = Vec::new();
-        let now = Instant::now();
+        self.tick(Instant::now())
+    }
+
+    /// Tempo, transport and song position derived from MIDI clock so far
+    pub fn clock(&self) -> ClockState {
+        self.clock.state
+    }
 
-        for (&note, &press_time) in &self.held_notes {
-            if now.duration_since(press_time) >= self.hold_threshold {
-                results.push(ProcessedEvent::HoldDetected { note });
-                // Note: We might want to track which holds we've already reported
-                // to avoid repeated triggers
+    /// Suppress the own actions of held pads, as `(channel, note)`, used as
+    /// `while_held` modifiers
+    ///
+    /// Call when a mapping guarded by `while_held` fires. The held-back press
+    /// of each pad is dropped, and it reports no holds or long press until it
+    /// is released. Its release is still reported.
+    pub fn mark_modifiers_used(&mut self, pads: &[(u8, u8)]) {
+        for (&key, held) in &mut self.held_notes {
+            if pads.contains(&key) {
+                held.used_as_modifier = true;
+                self.pending_presses.remove(&key);
             }
         }
+    }
 
-        results
+    /// Pads currently held down, as `(channel, note, elapsed_ms)` at `now`
+    pub fn held_pads(&self, now: Instant) -> Vec<(u8, u8, u128)> {
+        self.held_notes
+            .iter()
+            .map(|(&(channel, note), held)| {
+                (
+                    channel,
+                    note,
+                    now.saturating_duration_since(held.pressed).as_millis(),
+                )
+            })
+            .collect()
     }
 
     pub fn log_processed_event(event: &ProcessedEvent, mode: u8) {
         match event {
             ProcessedEvent::PadPressed {
+                channel,
                 note,
                 velocity,
                 velocity_level,
@@ -565,33 +2035,63 @@ impl EventProcessor {
                     VelocityLevel::Medium => "MED",
                     VelocityLevel::Hard => "HARD",
                 };
-                debug!(mode, note, velocity, level = level_str, "Pad pressed");
+                debug!(
+                    mode,
+                    channel,
+                    note,
+                    velocity,
+                    level = level_str,
+                    "Pad pressed"
+                );
             }
             ProcessedEvent::PadReleased {
+                channel,
                 note,
                 hold_duration_ms,
             } => {
-                debug!(mode, note, hold_duration_ms, "Pad released");
+                debug!(mode, channel, note, hold_duration_ms, "Pad released");
             }
-            ProcessedEvent::ShortPress { note } => {
-                debug!(mode, note, "Short tap detected");
+            ProcessedEvent::ShortPress { channel, note } => {
+                debug!(mode, channel, note, "Short tap detected");
             }
-            ProcessedEvent::MediumPress { note, duration_ms } => {
-                debug!(mode, note, duration_ms, "Medium press detected");
+            ProcessedEvent::MediumPress {
+                channel,
+                note,
+                duration_ms,
+            } => {
+                debug!(mode, channel, note, duration_ms, "Medium press detected");
             }
-            ProcessedEvent::LongPress { note, duration_ms } => {
-                debug!(mode, note, duration_ms, "Long press detected");
+            ProcessedEvent::LongPress {
+                channel,
+                note,
+                duration_ms,
+            } => {
+                debug!(mode, channel, note, duration_ms, "Long press detected");
             }
-            ProcessedEvent::HoldDetected { note } => {
-                debug!(mode, note, "Hold detected");
+            ProcessedEvent::HoldDetected {
+                channel,
+                note,
+                held_ms,
+                ..
+            } => {
+                trace!(mode, channel, note, held_ms, "Hold detected");
             }
-            ProcessedEvent::DoubleTap { note } => {
-                debug!(mode, note, "Double tap detected");
+            ProcessedEvent::DoubleTap {
+                channel,
+                note,
+                gap_ms,
+            } => {
+                debug!(mode, channel, note, gap_ms, "Double tap detected");
             }
-            ProcessedEvent::ChordDetected { notes } => {
-                debug!(mode, ?notes, "Chord detected");
+            ProcessedEvent::ChordDetected {
+                channel,
+                notes,
+                span_ms,
+            } => {
+                debug!(mode, channel, ?notes, span_ms, "Chord detected");
             }
             ProcessedEvent::EncoderTurned {
+                channel,
                 cc,
                 value,
                 direction,
@@ -603,6 +2103,7 @@ impl EventProcessor {
                 };
                 debug!(
                     mode,
+                    channel,
                     cc,
                     value,
                     direction = direction_str,
@@ -610,12 +2111,234 @@ impl EventProcessor {
                     "Encoder turned"
                 );
             }
-            ProcessedEvent::AftertouchChanged { pressure } => {
-                trace!(mode, pressure, "Aftertouch changed");
+            ProcessedEvent::AftertouchChanged { channel, pressure } => {
+                trace!(mode, channel, pressure, "Aftertouch changed");
+            }
+            ProcessedEvent::PolyPressureChanged {
+                channel,
+                note,
+                pressure,
+                previous,
+            } => {
+                trace!(mode, channel, note, pressure, previous, "Pressure changed");
+            }
+            ProcessedEvent::PitchBendMoved { channel, value } => {
+                trace!(mode, channel, value, "Pitch bend moved");
+            }
+            ProcessedEvent::HighResCCChanged { channel, cc, value } => {
+                trace!(mode, channel, cc, value, "High-resolution CC changed");
+            }
+            ProcessedEvent::NrpnChanged {
+                channel,
+                parameter,
+                value,
+            } => {
+                trace!(mode, channel, parameter, value, "NRPN changed");
+            }
+            ProcessedEvent::RpnChanged {
+                channel,
+                parameter,
+                value,
+            } => {
+                trace!(mode, channel, parameter, value, "RPN changed");
+            }
+            ProcessedEvent::SysExReceived { data } => {
+                debug!(mode, len = data.len(), "SysEx received: {:02X?}", data);
+            }
+            ProcessedEvent::TransportChanged { command } => {
+                debug!(mode, ?command, "Transport changed");
+            }
+            ProcessedEvent::SongPositionChanged { position } => {
+                debug!(mode, position, "Song position changed");
+            }
+            ProcessedEvent::TempoChanged { bpm } => {
+                debug!(mode, bpm, "Tempo changed");
+            }
+            ProcessedEvent::ProgramChanged { channel, program } => {
+                debug!(mode, channel, program, "Program changed");
+            }
+            ProcessedEvent::CCZoneEntered {
+                channel,
+                cc,
+                zones,
+                zone,
+                value,
+            } => {
+                debug!(mode, channel, cc, zones, zone, value, "CC zone entered");
+            }
+            ProcessedEvent::CCThresholdCrossed {
+                channel,
+                cc,
+                threshold,
+                direction,
+                value,
+            } => {
+                debug!(
+                    mode,
+                    channel,
+                    cc,
+                    threshold,
+                    ?direction,
+                    value,
+                    "CC threshold crossed"
+                );
+            }
+            ProcessedEvent::GestureRecognized {
+                channel,
+                source,
+                gesture,
+            } => {
+                debug!(mode, channel, ?source, ?gesture, "Gesture recognized");
             }
-            ProcessedEvent::PitchBendMoved { value } => {
-                trace!(mode, value, "Pitch bend moved");
+            ProcessedEvent::SequenceMatched {
+                channel,
+                steps,
+                span_ms,
+            } => {
+                debug!(mode, channel, ?steps, span_ms, "Sequence matched");
             }
         };
     }
 }
+
+/// Find pads whose single-press mapping competes with a double-tap or chord
+/// mapping, or with being held as a modifier, on each MIDI channel
+///
+/// A pad only competes on the channels where both mappings' channel filters
+/// let them fire.
+fn competing_gestures(
+    mappings: &[&Mapping],
+    settings: &AdvancedSettings,
+) -> HashMap<ChannelKey, Competition> {
+    let single_press: Vec<(Option<u8>, RangeInclusive<u8>)> = mappings
+        .iter()
+        .filter(|mapping| !mapping.immediate)
+        .filter_map(|mapping| {
+            let notes = match &mapping.trigger {
+                Trigger::Note { note, .. } | Trigger::VelocityRange { note, .. } => *note..=*note,
+                Trigger::NoteRange { min, max, .. } => *min..=*max,
+                Trigger::GamepadButton { button, .. } => *button..=*button,
+                _ => return None,
+            };
+            Some((mapping.trigger.channel(), notes))
+        })
+        .collect();
+
+    // Channels on which a gesture with the `filter` on `note` competes
+    let contested = |filter: Option<u8>, note: u8| {
+        let single_press = &single_press;
+        (0..16u8)
+            .filter(move |channel| filter.is_none_or(|filter| filter == *channel))
+            .filter(move |channel| {
+                single_press.iter().any(|(single, notes)| {
+                    notes.contains(&note) && single.is_none_or(|single| single == *channel)
+                })
+            })
+            .map(move |channel| (channel, note))
+    };
+
+    let mut deferred: HashMap<ChannelKey, Competition> = HashMap::new();
+    for mapping in mappings {
+        let filter = mapping.trigger.channel();
+        match &mapping.trigger {
+            Trigger::DoubleTap {
+                note, timeout_ms, ..
+            } => {
+                let window =
+                    Duration::from_millis(timeout_ms.unwrap_or(settings.double_tap_timeout_ms));
+                for key in contested(filter, *note) {
+                    let competition = deferred.entry(key).or_default();
+                    competition.double_tap = competition.double_tap.max(Some(window));
+                }
+            }
+            Trigger::NoteChord {
+                notes: chord_notes,
+                timeout_ms,
+                ..
+            }
+            | Trigger::GamepadButtonChord {
+                buttons: chord_notes,
+                timeout_ms,
+            } => {
+                let window = Duration::from_millis(timeout_ms.unwrap_or(settings.chord_timeout_ms));
+                let mut sorted = chord_notes.clone();
+                sorted.sort_unstable();
+                for key in chord_notes.iter().flat_map(|note| contested(filter, *note)) {
+                    deferred
+                        .entry(key)
+                        .or_default()
+                        .chords
+                        .push((sorted.clone(), window));
+                }
+            }
+            _ => {}
+        }
+
+        for key in mapping
+            .while_held
+            .iter()
+            .flat_map(|pad| contested(filter, *pad))
+        {
+            deferred.entry(key).or_default().modifier = true;
+        }
+    }
+
+    deferred
+}
+
+/// Step inputs an event belongs to, for holding back the events of sequence steps
+fn event_step_inputs(event: &ProcessedEvent) -> Vec<(u8, StepInput)> {
+    match event {
+        ProcessedEvent::PadPressed { channel, note, .. }
+        | ProcessedEvent::PadReleased { channel, note, .. }
+        | ProcessedEvent::ShortPress { channel, note }
+        | ProcessedEvent::MediumPress { channel, note, .. }
+        | ProcessedEvent::LongPress { channel, note, .. }
+        | ProcessedEvent::HoldDetected { channel, note, .. }
+        | ProcessedEvent::DoubleTap { channel, note, .. }
+        | ProcessedEvent::PolyPressureChanged { channel, note, .. } => {
+            vec![(*channel, StepInput::Pad(*note))]
+        }
+        ProcessedEvent::ChordDetected { channel, notes, .. } => notes
+            .iter()
+            .map(|note| (*channel, StepInput::Pad(*note)))
+            .collect(),
+        ProcessedEvent::EncoderTurned { channel, cc, .. }
+        | ProcessedEvent::CCZoneEntered { channel, cc, .. }
+        | ProcessedEvent::CCThresholdCrossed { channel, cc, .. } => {
+            vec![(*channel, StepInput::Control(*cc))]
+        }
+        ProcessedEvent::AftertouchChanged { .. }
+        | ProcessedEvent::PitchBendMoved { .. }
+        | ProcessedEvent::HighResCCChanged { .. }
+        | ProcessedEvent::NrpnChanged { .. }
+        | ProcessedEvent::RpnChanged { .. }
+        | ProcessedEvent::SysExReceived { .. }
+        | ProcessedEvent::TransportChanged { .. }
+        | ProcessedEvent::SongPositionChanged { .. }
+        | ProcessedEvent::TempoChanged { .. }
+        | ProcessedEvent::ProgramChanged { .. }
+        | ProcessedEvent::GestureRecognized { .. }
+        | ProcessedEvent::SequenceMatched { .. } => Vec::new(),
+    }
+}
+
+/// Signed steps reported by a relative encoder's 7-bit value
+fn relative_steps(encoding: EncoderEncoding, value: u8) -> i16 {
+    let value = (value & 0x7F) as i16;
+    match encoding {
+        EncoderEncoding::TwosComplement if value >= 64 => value - 128,
+        EncoderEncoding::SignedBit if value >= 64 => -(value - 64),
+        EncoderEncoding::BinaryOffset => value - 64,
+        _ => value,
+    }
+}
+
+/// Scale a turn's steps by how soon it followed the previous turn
+fn accelerate(steps: u16, acceleration: f32, since_last: Option<Duration>) -> u8 {
+    let window = ENCODER_ACCELERATION_WINDOW.as_secs_f32();
+    let speed = since_last.map_or(0.0, |gap| 1.0 - (gap.as_secs_f32() / window).min(1.0));
+    (steps as f32 * (1.0 + acceleration * speed))
+        .round()
+        .min(127.0) as u8
+}
@@ -13,6 +13,7 @@
 //! using domain terminology (pad, encoder, pressure) instead of MIDI-specific terms
 //! (note, cc). This enables future support for HID and other input protocols.
 
+use crate::config::TransportCommand;
 use std::time::Instant;
 
 pub use crate::event_processor::{EncoderDirection, MidiEvent, ProcessedEvent, VelocityLevel};
@@ -27,6 +28,8 @@ pub use crate::event_processor::{EncoderDirection, MidiEvent, ProcessedEvent, Ve
 ///
 /// - **Protocol-agnostic naming**: Uses domain terms (pad, encoder) not protocol terms (note, cc)
 /// - **Timestamp preservation**: All events carry their original timestamp
+/// - **Channel preservation**: All events carry the MIDI channel (0-15) they arrived on;
+///   non-MIDI sources such as gamepads always use channel 0
 /// - **Direct mapping**: One-to-one correspondence with MIDI events for now
 /// - **Future extensibility**: Designed to support HID and other protocols later
 ///
@@ -37,7 +40,7 @@ pub use crate::event_processor::{EncoderDirection, MidiEvent, ProcessedEvent, Ve
 /// use std::time::Instant;
 ///
 /// let time = Instant::now();
-/// let midi = MidiEvent::NoteOn { note: 36, velocity: 100, time };
+/// let midi = MidiEvent::NoteOn { channel: 0, note: 36, velocity: 100, time };
 /// let input: InputEvent = midi.into();
 ///
 /// match input {
@@ -51,36 +54,84 @@ pub use crate::event_processor::{EncoderDirection, MidiEvent, ProcessedEvent, Ve
 pub enum InputEvent {
     /// Pad pressed (physical button/pad on controller)
     PadPressed {
+        channel: u8,
         pad: u8,
         velocity: u8,
         time: Instant,
     },
     /// Pad released (button/pad released)
-    PadReleased { pad: u8, time: Instant },
+    PadReleased { channel: u8, pad: u8, time: Instant },
     /// Encoder turned (rotary knob or encoder)
     EncoderTurned {
+        channel: u8,
         encoder: u8,
         value: u8,
         time: Instant,
     },
     /// Polyphonic aftertouch/pressure applied to specific pad
     PolyPressure {
+        channel: u8,
         pad: u8,
         pressure: u8,
         time: Instant,
     },
     /// Aftertouch/pressure applied (channel-wide)
-    Aftertouch { pressure: u8, time: Instant },
+    Aftertouch {
+        channel: u8,
+        pressure: u8,
+        time: Instant,
+    },
     /// Pitch bend/touch strip moved
-    PitchBend { value: u16, time: Instant },
+    PitchBend {
+        channel: u8,
+        value: u16,
+        time: Instant,
+    },
     /// Program change
-    ProgramChange { program: u8, time: Instant },
+    ProgramChange {
+        channel: u8,
+        program: u8,
+        time: Instant,
+    },
     /// Generic control change (for unmapped controls)
     ControlChange {
+        channel: u8,
         control: u8,
         value: u8,
         time: Instant,
     },
+    /// High-resolution (14-bit) control, such as a fader sending MSB/LSB pairs
+    HighResControlChange {
+        channel: u8,
+        control: u8,
+        value: u16,
+        time: Instant,
+    },
+    /// Non-registered parameter change (14-bit parameter and value)
+    Nrpn {
+        channel: u8,
+        parameter: u16,
+        value: u16,
+        time: Instant,
+    },
+    /// Registered parameter change (14-bit parameter and value)
+    Rpn {
+        channel: u8,
+        parameter: u16,
+        value: u16,
+        time: Instant,
+    },
+    /// System Exclusive message, including its `F0`/`F7` framing bytes
+    SysEx { data: Vec<u8>, time: Instant },
+    /// Timing clock pulse (24 per quarter note)
+    Clock { time: Instant },
+    /// Transport Start, Stop or Continue
+    Transport {
+        command: TransportCommand,
+        time: Instant,
+    },
+    /// Song position, in sixteenth notes from the start of the song
+    SongPosition { position: u16, time: Instant },
 }
 
 impl InputEvent {
@@ -93,7 +144,7 @@ impl InputEvent {
     /// use std::time::Instant;
     ///
     /// let time = Instant::now();
-    /// let event = InputEvent::PadPressed { pad: 1, velocity: 100, time };
+    /// let event = InputEvent::PadPressed { channel: 0, pad: 1, velocity: 100, time };
     /// assert_eq!(event.timestamp(), time);
     /// ```
     pub fn timestamp(&self) -> Instant {
@@ -105,7 +156,38 @@ impl InputEvent {
             | InputEvent::Aftertouch { time, .. }
             | InputEvent::PitchBend { time, .. }
             | InputEvent::ProgramChange { time, .. }
-            | InputEvent::ControlChange { time, .. } => *time,
+            | InputEvent::ControlChange { time, .. }
+            | InputEvent::HighResControlChange { time, .. }
+            | InputEvent::Nrpn { time, .. }
+            | InputEvent::Rpn { time, .. }
+            | InputEvent::SysEx { time, .. }
+            | InputEvent::Clock { time }
+            | InputEvent::Transport { time, .. }
+            | InputEvent::SongPosition { time, .. } => *time,
+        }
+    }
+
+    /// Returns the MIDI channel (0-15) of this event
+    ///
+    /// Always 0 for system messages (SysEx, clock and transport) and for
+    /// non-MIDI sources such as gamepads.
+    pub fn channel(&self) -> u8 {
+        match self {
+            InputEvent::PadPressed { channel, .. }
+            | InputEvent::PadReleased { channel, .. }
+            | InputEvent::EncoderTurned { channel, .. }
+            | InputEvent::PolyPressure { channel, .. }
+            | InputEvent::Aftertouch { channel, .. }
+            | InputEvent::PitchBend { channel, .. }
+            | InputEvent::ProgramChange { channel, .. }
+            | InputEvent::ControlChange { channel, .. }
+            | InputEvent::HighResControlChange { channel, .. }
+            | InputEvent::Nrpn { channel, .. }
+            | InputEvent::Rpn { channel, .. } => *channel,
+            InputEvent::SysEx { .. }
+            | InputEvent::Clock { .. }
+            | InputEvent::Transport { .. }
+            | InputEvent::SongPosition { .. } => 0,
         }
     }
 
@@ -120,6 +202,7 @@ impl InputEvent {
     /// use std::time::Instant;
     ///
     /// let event = InputEvent::PadPressed {
+    ///     channel: 0,
     ///     pad: 1,
     ///     velocity: 100,
     ///     time: Instant::now()
@@ -136,6 +219,13 @@ impl InputEvent {
             InputEvent::PitchBend { .. } => "PitchBend",
             InputEvent::ProgramChange { .. } => "ProgramChange",
             InputEvent::ControlChange { .. } => "ControlChange",
+            InputEvent::HighResControlChange { .. } => "HighResControlChange",
+            InputEvent::Nrpn { .. } => "Nrpn",
+            InputEvent::Rpn { .. } => "Rpn",
+            InputEvent::SysEx { .. } => "SysEx",
+            InputEvent::Clock { .. } => "Clock",
+            InputEvent::Transport { .. } => "Transport",
+            InputEvent::SongPosition { .. } => "SongPosition",
         }
     }
 }
@@ -152,37 +242,120 @@ impl InputEvent {
 /// - Aftertouch → Aftertouch (pressure preserved)
 /// - PitchBend → PitchBend (value preserved)
 /// - ProgramChange → ProgramChange (program preserved)
+/// - HighResCC → HighResControlChange (cc → control, 14-bit value preserved)
+/// - Nrpn/Rpn → Nrpn/Rpn (parameter and value preserved)
+/// - SysEx → SysEx (message bytes preserved)
+/// - Clock, Transport, SongPosition → same (command and position preserved)
 impl From<MidiEvent> for InputEvent {
     fn from(midi: MidiEvent) -> Self {
         match midi {
             MidiEvent::NoteOn {
+                channel,
                 note,
                 velocity,
                 time,
             } => InputEvent::PadPressed {
+                channel,
                 pad: note,
                 velocity,
                 time,
             },
-            MidiEvent::NoteOff { note, time } => InputEvent::PadReleased { pad: note, time },
-            MidiEvent::ControlChange { cc, value, time } => InputEvent::EncoderTurned {
+            MidiEvent::NoteOff {
+                channel,
+                note,
+                time,
+            } => InputEvent::PadReleased {
+                channel,
+                pad: note,
+                time,
+            },
+            MidiEvent::ControlChange {
+                channel,
+                cc,
+                value,
+                time,
+            } => InputEvent::EncoderTurned {
+                channel,
                 encoder: cc,
                 value,
                 time,
             },
             MidiEvent::PolyPressure {
+                channel,
                 note,
                 pressure,
                 time,
             } => InputEvent::PolyPressure {
+                channel,
                 pad: note,
                 pressure,
                 time,
             },
-            MidiEvent::Aftertouch { pressure, time } => InputEvent::Aftertouch { pressure, time },
-            MidiEvent::PitchBend { value, time } => InputEvent::PitchBend { value, time },
-            MidiEvent::ProgramChange { program, time } => {
-                InputEvent::ProgramChange { program, time }
+            MidiEvent::Aftertouch {
+                channel,
+                pressure,
+                time,
+            } => InputEvent::Aftertouch {
+                channel,
+                pressure,
+                time,
+            },
+            MidiEvent::PitchBend {
+                channel,
+                value,
+                time,
+            } => InputEvent::PitchBend {
+                channel,
+                value,
+                time,
+            },
+            MidiEvent::ProgramChange {
+                channel,
+                program,
+                time,
+            } => InputEvent::ProgramChange {
+                channel,
+                program,
+                time,
+            },
+            MidiEvent::HighResCC {
+                channel,
+                cc,
+                value,
+                time,
+            } => InputEvent::HighResControlChange {
+                channel,
+                control: cc,
+                value,
+                time,
+            },
+            MidiEvent::Nrpn {
+                channel,
+                parameter,
+                value,
+                time,
+            } => InputEvent::Nrpn {
+                channel,
+                parameter,
+                value,
+                time,
+            },
+            MidiEvent::Rpn {
+                channel,
+                parameter,
+                value,
+                time,
+            } => InputEvent::Rpn {
+                channel,
+                parameter,
+                value,
+                time,
+            },
+            MidiEvent::SysEx { data, time } => InputEvent::SysEx { data, time },
+            MidiEvent::Clock { time } => InputEvent::Clock { time },
+            MidiEvent::Transport { command, time } => InputEvent::Transport { command, time },
+            MidiEvent::SongPosition { position, time } => {
+                InputEvent::SongPosition { position, time }
             }
         }
     }
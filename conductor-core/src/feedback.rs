@@ -275,6 +275,17 @@ impl FeedbackManager {
         Ok(())
     }
 
+    /// Show progress of a pad that is being held
+    ///
+    /// Called repeatedly while the pad is down, with the time held so far.
+    pub fn show_long_press_feedback(
+        &mut self,
+        pad: u8,
+        elapsed_ms: u128,
+    ) -> Result<(), Box<dyn Error>> {
+        self.device.show_long_press_feedback(pad, elapsed_ms)
+    }
+
     /// Handle a mode change event
     ///
     /// Updates the current mode and applies mode-specific colors to the device.
@@ -186,6 +186,7 @@ pub fn normalize_axis(value: f32) -> u8 {
 /// InputEvent::PadPressed with button ID in 128-255 range
 pub fn button_pressed_to_input(button: gilrs::Button) -> InputEvent {
     InputEvent::PadPressed {
+        channel: 0,
         pad: button_to_id(button),
         velocity: 100, // Default velocity for digital buttons
         time: Instant::now(),
@@ -205,6 +206,7 @@ pub fn button_pressed_to_input(button: gilrs::Button) -> InputEvent {
 /// InputEvent::PadReleased with button ID in 128-255 range
 pub fn button_released_to_input(button: gilrs::Button) -> InputEvent {
     InputEvent::PadReleased {
+        channel: 0,
         pad: button_to_id(button),
         time: Instant::now(),
     }
@@ -225,6 +227,7 @@ pub fn button_released_to_input(button: gilrs::Button) -> InputEvent {
 /// InputEvent::EncoderTurned with normalized value (0-127)
 pub fn axis_changed_to_input(axis: gilrs::Axis, value: f32) -> InputEvent {
     InputEvent::EncoderTurned {
+        channel: 0,
         encoder: axis_to_encoder_id(axis),
         value: normalize_axis(value),
         time: Instant::now(),
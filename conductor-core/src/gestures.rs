@@ -0,0 +1,299 @@
+// Copyright 2025 Amiable
+// SPDX-License-Identifier: MIT
+
+//! Touch strip gesture recognition
+//!
+//! Turns the position stream of a touch strip, sent as pitch bend or as a
+//! CC, into gestures:
+//!
+//! - **Swipe**: the finger moves along the strip and lifts. Reported with its
+//!   direction, distance and speed when the touch ends.
+//! - **Tap**: the finger touches briefly without moving. Reported with the
+//!   position it touched.
+//! - **Slide and hold**: the finger moves, then rests while the strip keeps
+//!   reporting it. Reported on every message while it rests, with how long it
+//!   has rested.
+//!
+//! Positions and distances are fractions of the strip (0.0-1.0). Strips send
+//! nothing when the finger lifts, so a touch ends once no message has arrived
+//! for [`TOUCH_RELEASE`]; [`GestureRecognizer::tick`] reports the gestures of
+//! touches that ended.
+
+use crate::config::SwipeDirection;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// Silence after which a touch counts as lifted
+pub const TOUCH_RELEASE: Duration = Duration::from_millis(120);
+
+/// Longest touch that counts as a tap
+pub const TAP_MAX_DURATION: Duration = Duration::from_millis(250);
+
+/// Longest rest before lifting that still ends a swipe
+pub const SWIPE_MAX_REST: Duration = Duration::from_millis(250);
+
+/// Movement within which a touch counts as resting (fraction of the strip)
+pub const TOUCH_TOLERANCE: f32 = 0.02;
+
+/// Default `min_speed` of a `TouchSwipe` trigger (strip lengths per second)
+pub const DEFAULT_SWIPE_MIN_SPEED: f32 = 1.0;
+
+/// Default `min_distance` of a `TouchSwipe` trigger
+pub const DEFAULT_SWIPE_MIN_DISTANCE: f32 = 0.25;
+
+/// Default `hold_ms` of a `TouchSlideHold` trigger
+pub const DEFAULT_SLIDE_HOLD_MS: u64 = 500;
+
+/// Default `min_distance` of a `TouchSlideHold` trigger
+pub const DEFAULT_SLIDE_MIN_DISTANCE: f32 = 0.1;
+
+/// The control a touch strip reports its position on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TouchSource {
+    PitchBend,
+    ControlChange(u8),
+}
+
+impl TouchSource {
+    /// The source a trigger's `cc` field refers to (None = pitch bend)
+    pub fn from_cc(cc: Option<u8>) -> Self {
+        cc.map_or(TouchSource::PitchBend, TouchSource::ControlChange)
+    }
+}
+
+/// A recognized touch strip gesture
+#[derive(Debug, Clone, PartialEq)]
+pub enum Gesture {
+    Swipe {
+        direction: SwipeDirection,
+        /// Distance moved (fraction of the strip)
+        distance: f32,
+        /// Strip lengths per second, over the time the finger was moving
+        speed: f32,
+    },
+    Tap {
+        position: f32,
+    },
+    SlideHold {
+        position: f32,
+        /// Distance slid before resting (fraction of the strip)
+        distance: f32,
+        /// Time resting so far
+        held_ms: u128,
+        /// `held_ms` at the previous report, so a hold time is crossed once
+        /// when `previous_ms < hold_ms <= held_ms`
+        previous_ms: u128,
+    },
+}
+
+/// A finger on a strip
+struct Touch {
+    start: f32,
+    started: Instant,
+    /// Position and time of the last movement beyond the tolerance
+    rest: f32,
+    last_moved: Instant,
+    last_message: Instant,
+    /// Resting time at the last SlideHold report
+    reported_ms: u128,
+}
+
+impl Touch {
+    fn new(position: f32, time: Instant) -> Self {
+        Self {
+            start: position,
+            started: time,
+            rest: position,
+            last_moved: time,
+            last_message: time,
+            reported_ms: 0,
+        }
+    }
+
+    /// The swipe or tap the touch made, once lifted
+    ///
+    /// A touch that rested before lifting made neither.
+    fn lifted(&self) -> Option<Gesture> {
+        let moved = self.rest - self.start;
+        let distance = moved.abs();
+        if distance <= TOUCH_TOLERANCE {
+            let duration = self.last_message.saturating_duration_since(self.started);
+            return (duration <= TAP_MAX_DURATION).then_some(Gesture::Tap {
+                position: self.start,
+            });
+        }
+
+        if self.last_message.saturating_duration_since(self.last_moved) > SWIPE_MAX_REST {
+            return None;
+        }
+        let seconds = self
+            .last_moved
+            .saturating_duration_since(self.started)
+            .as_secs_f32();
+        Some(Gesture::Swipe {
+            direction: if moved > 0.0 {
+                SwipeDirection::Right
+            } else {
+                SwipeDirection::Left
+            },
+            distance,
+            speed: if seconds > 0.0 {
+                distance / seconds
+            } else {
+                f32::INFINITY
+            },
+        })
+    }
+}
+
+/// Tracks touches on the strips used by gesture triggers
+#[derive(Default)]
+pub struct GestureRecognizer {
+    sources: Vec<TouchSource>,
+    touches: HashMap<(u8, TouchSource), Touch>,
+}
+
+impl GestureRecognizer {
+    /// Recognize gestures on `sources` only, dropping touches in progress
+    pub fn set_sources(&mut self, sources: Vec<TouchSource>) {
+        self.sources = sources;
+        self.touches.clear();
+    }
+
+    /// Whether any gesture triggers use `source`
+    pub fn tracks(&self, source: TouchSource) -> bool {
+        self.sources.contains(&source)
+    }
+
+    /// Feed a position (0.0-1.0) reported by `source` on `channel`
+    ///
+    /// Returns the gesture of a touch that ended before this message, and a
+    /// SlideHold report while the finger rests after sliding.
+    pub fn update(
+        &mut self,
+        channel: u8,
+        source: TouchSource,
+        position: f32,
+        time: Instant,
+    ) -> Vec<Gesture> {
+        if !self.tracks(source) {
+            return Vec::new();
+        }
+
+        let mut gestures = Vec::new();
+        let key = (channel, source);
+        if let Some(touch) = self.touches.get(&key)
+            && time.saturating_duration_since(touch.last_message) >= TOUCH_RELEASE
+        {
+            gestures.extend(touch.lifted());
+            self.touches.remove(&key);
+        }
+
+        let touch = self
+            .touches
+            .entry(key)
+            .or_insert_with(|| Touch::new(position, time));
+        touch.last_message = time;
+        if (position - touch.rest).abs() > TOUCH_TOLERANCE {
+            touch.rest = position;
+            touch.last_moved = time;
+            touch.reported_ms = 0;
+            return gestures;
+        }
+
+        let distance = (touch.rest - touch.start).abs();
+        let held_ms = time.saturating_duration_since(touch.last_moved).as_millis();
+        if distance > TOUCH_TOLERANCE && held_ms > touch.reported_ms {
+            gestures.push(Gesture::SlideHold {
+                position: touch.rest,
+                distance,
+                held_ms,
+                previous_ms: touch.reported_ms,
+            });
+            touch.reported_ms = held_ms;
+        }
+        gestures
+    }
+
+    /// End the touches that have been silent for [`TOUCH_RELEASE`] at `now`,
+    /// returning their gestures with the channel and source
+    pub fn tick(&mut self, now: Instant) -> Vec<(u8, TouchSource, Gesture)> {
+        let lifted: Vec<(u8, TouchSource)> = self
+            .touches
+            .iter()
+            .filter(|(_, touch)| now.saturating_duration_since(touch.last_message) >= TOUCH_RELEASE)
+            .map(|(key, _)| *key)
+            .collect();
+
+        lifted
+            .into_iter()
+            .filter_map(|(channel, source)| {
+                let touch = self.touches.remove(&(channel, source))?;
+                Some((channel, source, touch.lifted()?))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn recognizer() -> GestureRecognizer {
+        let mut recognizer = GestureRecognizer::default();
+        recognizer.set_sources(vec![TouchSource::PitchBend]);
+        recognizer
+    }
+
+    /// Feed positions 10ms apart, returning the time of the last one
+    fn stroke(recognizer: &mut GestureRecognizer, positions: &[f32], start: Instant) -> Instant {
+        let mut time = start;
+        for (i, position) in positions.iter().enumerate() {
+            time = start + Duration::from_millis(10 * i as u64);
+            recognizer.update(0, TouchSource::PitchBend, *position, time);
+        }
+        time
+    }
+
+    #[test]
+    fn test_swipe_on_lift() {
+        let mut recognizer = recognizer();
+        let end = stroke(&mut recognizer, &[0.2, 0.4, 0.6, 0.8], Instant::now());
+
+        assert!(recognizer.tick(end + Duration::from_millis(50)).is_empty());
+        let gestures = recognizer.tick(end + TOUCH_RELEASE);
+        assert_eq!(gestures.len(), 1);
+        let Gesture::Swipe {
+            direction, speed, ..
+        } = gestures[0].2
+        else {
+            panic!("Expected a swipe, got {:?}", gestures[0]);
+        };
+        assert_eq!(direction, SwipeDirection::Right);
+        // 0.6 of the strip in 30ms
+        assert!((speed - 20.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_tap_and_long_touch() {
+        let mut recognizer = recognizer();
+        let end = stroke(&mut recognizer, &[0.5, 0.51], Instant::now());
+        assert!(matches!(
+            recognizer.tick(end + TOUCH_RELEASE).as_slice(),
+            [(_, _, Gesture::Tap { position })] if *position == 0.5
+        ));
+
+        // Resting without sliding first is neither a tap nor a hold
+        let positions = [0.5; 40];
+        let end = stroke(&mut recognizer, &positions, end + Duration::from_secs(1));
+        assert!(recognizer.tick(end + TOUCH_RELEASE).is_empty());
+    }
+
+    #[test]
+    fn test_untracked_source_ignored() {
+        let mut recognizer = recognizer();
+        let source = TouchSource::ControlChange(1);
+        recognizer.update(0, source, 0.0, Instant::now());
+        assert!(recognizer.touches.is_empty());
+    }
+}
@@ -88,6 +88,7 @@
 // Public modules
 pub mod actions;
 pub mod config;
+pub mod cycles; // Positions of Cycle and Toggle actions
 pub mod device;
 pub mod engine;
 pub mod error;
@@ -95,8 +96,10 @@ pub mod event_processor;
 pub mod events;
 pub mod gamepad_events; // Gamepad/HID input mapping (v3.0)
 pub mod feedback;
+pub mod gestures; // Touch strip gesture recognition
 pub mod mapping; // Public for advanced event processing
 pub mod midi_output; // MIDI output management (v2.1)
+pub mod variables; // Runtime variables set by actions
 pub mod velocity; // Velocity mapping calculations (v2.2)
 
 // Private modules (implementation details)
@@ -110,7 +113,11 @@ pub mod mikro_leds; // Structured logging with tracing
 pub use engine::MidiMonEngine;
 
 // Configuration
-pub use config::{ActionConfig, Config, DeviceConfig, LoggingConfig, Mapping, Mode, Trigger};
+pub use config::{
+    ActionConfig, ConcurrencyPolicy, Config, ContinuousValue, CrossingDirection, CycleReset,
+    DeviceConfig, EncoderEncoding, LoggingConfig, Mapping, Mode, PluginCatalog, SequenceStep,
+    SwipeDirection, SysExByte, TransportCommand, Trigger, VariablesConfig,
+};
 
 // Events
 pub use event_processor::EventProcessor;
@@ -133,11 +140,15 @@ pub use device::{DeviceProfile, PadPageMapping};
 pub use error::{ActionError, ConfigError, EngineError, FeedbackError, ProfileError};
 
 // Mapping
-pub use mapping::MappingEngine;
+pub use mapping::{MappedAction, MappingEngine, MatchedControl};
 
 // MIDI Output (v2.1)
 pub use midi_output::{MidiMessage, MidiOutputManager};
 
+// Variables and cycles
+pub use cycles::{CyclePosition, CyclePositions};
+pub use variables::{VariableStore, VariableValue};
+
 // Plugin System (v2.3)
 pub mod plugin;
 
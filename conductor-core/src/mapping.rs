@@ -3,14 +3,26 @@
 
 use crate::MidiEvent;
 use crate::actions::Action;
-use crate::config::{Config, Mapping, Trigger};
-use crate::event_processor::ProcessedEvent;
+use crate::config::{
+    AdvancedSettings, ConcurrencyPolicy, Config, ContinuousValue, CrossingDirection, Mapping,
+    SequenceStep, SwipeDirection, SysExByte, TransportCommand, Trigger,
+};
+use crate::event_processor::{
+    DEFAULT_BPM_MAX, DEFAULT_BPM_MIN, EncoderDirection, ProcessedEvent, VelocityLevel,
+};
+use crate::gestures::{
+    DEFAULT_SLIDE_HOLD_MS, DEFAULT_SLIDE_MIN_DISTANCE, DEFAULT_SWIPE_MIN_DISTANCE,
+    DEFAULT_SWIPE_MIN_SPEED, Gesture, TouchSource,
+};
+use crate::velocity::scale_value;
 use std::collections::HashMap;
 use tracing::{debug, trace};
 
 pub struct MappingEngine {
     mode_mappings: HashMap<u8, Vec<CompiledMapping>>,
     global_mappings: Vec<CompiledMapping>,
+    /// Momentary mode layers, topmost last
+    layers: Vec<u8>,
 }
 
 impl Default for MappingEngine {
@@ -21,8 +33,56 @@ impl Default for MappingEngine {
 
 struct CompiledMapping {
     trigger: CompiledTrigger,
+    /// MIDI channel filter (None = any channel)
+    channel: Option<u8>,
     action: Action,
+    on_release: Option<Action>,
+    momentary: bool,
+    continuous: Option<ContinuousValue>,
     description: Option<String>,
+    /// Pads that must be held for the mapping to match
+    while_held: Vec<u8>,
+    /// Mode name (or `global_mappings`) and index of the mapping
+    id: String,
+    concurrency: ConcurrencyPolicy,
+}
+
+/// An action matched for a processed event, with its release-phase behavior
+#[derive(Debug, Clone)]
+pub struct MappedAction {
+    pub action: Action,
+    /// Action to run when the pad that fired the mapping is released
+    pub on_release: Option<Action>,
+    /// Hold the Keystroke's keys down until the pad is released
+    pub momentary: bool,
+    /// Note or CC that fired the mapping, for pad and encoder events
+    pub control: Option<MatchedControl>,
+    /// Scaled control value of a continuous mapping, already applied to
+    /// `action`
+    pub value: Option<f64>,
+    /// Bytes captured by the `(??)` wildcards of a SysEx trigger
+    pub captures: Vec<u8>,
+    /// Held pads, as `(channel, note)`, used as the mapping's `while_held`
+    /// modifiers
+    pub modifiers: Vec<(u8, u8)>,
+    /// Mode name (or `global_mappings`) and index of the mapping, e.g.
+    /// `Default/3`
+    pub id: String,
+    pub description: Option<String>,
+    /// What a retrigger does while the mapping's action is still running
+    pub concurrency: ConcurrencyPolicy,
+}
+
+/// The note or CC number an event matched, passed on to the action
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MatchedControl {
+    /// Note or CC number of the event
+    pub number: u8,
+    /// Position of `number` within a NoteRange/CCRange trigger, or the zone
+    /// entered or tapped for a CCZone/TouchTap trigger (0 otherwise)
+    pub offset: u8,
+    /// Note velocity or CC value
+    pub value: u8,
 }
 
 enum CompiledTrigger {
@@ -33,9 +93,115 @@ enum CompiledTrigger {
     CC {
         cc: u8,
         value_min: u8,
+        value_max: u8,
+    },
+    CCZone {
+        cc: u8,
+        zones: u8,
+        zone: Option<u8>,
+    },
+    CCThreshold {
+        cc: u8,
+        threshold: u8,
+        direction: CrossingDirection,
+    },
+    NoteRange {
+        min: u8,
+        max: u8,
+        velocity_min: u8,
+    },
+    CCRange {
+        min: u8,
+        max: u8,
+        value_min: u8,
     },
     NoteChord {
         notes: Vec<u8>,
+        timeout_ms: u128,
+    },
+    Sequence {
+        steps: Vec<SequenceStep>,
+    },
+    SysEx {
+        pattern: Vec<SysExByte>,
+    },
+    Transport {
+        command: TransportCommand,
+    },
+    SongPosition {
+        position_min: u16,
+        position_max: u16,
+    },
+    Tempo {
+        bpm_min: f64,
+        bpm_max: f64,
+    },
+    TouchSwipe {
+        source: TouchSource,
+        direction: SwipeDirection,
+        min_speed: f32,
+        min_distance: f32,
+    },
+    TouchTap {
+        source: TouchSource,
+        zones: u8,
+        zone: Option<u8>,
+    },
+    TouchSlideHold {
+        source: TouchSource,
+        hold_ms: u128,
+        min_distance: f32,
+    },
+    VelocityRange {
+        note: u8,
+        level: Option<VelocityLevel>,
+        soft_max: u8,
+        medium_max: u8,
+    },
+    LongPress {
+        note: u8,
+        duration_ms: u128,
+        repeat_ms: Option<u128>,
+    },
+    DoubleTap {
+        note: u8,
+        timeout_ms: u128,
+    },
+    EncoderTurn {
+        cc: u8,
+        direction: Option<EncoderDirection>,
+        /// Fire the action once per step of the turn
+        per_step: bool,
+    },
+    Aftertouch {
+        pressure_min: u8,
+    },
+    PitchBend {
+        value_min: u16,
+        value_max: u16,
+    },
+    HighResCC {
+        cc: u8,
+        value_min: u16,
+        value_max: u16,
+    },
+    Nrpn {
+        parameter: u16,
+        value_min: u16,
+        value_max: u16,
+    },
+    Rpn {
+        parameter: u16,
+        value_min: u16,
+        value_max: u16,
+    },
+    PolyPressure {
+        note: u8,
+        /// Threshold to cross upwards; None matches every change
+        pressure_min: Option<u8>,
+    },
+    ProgramChange {
+        program: u8,
     },
     // Gamepad triggers (v3.0)
     GamepadButton {
@@ -44,6 +210,7 @@ enum CompiledTrigger {
     },
     GamepadButtonChord {
         buttons: Vec<u8>,
+        timeout_ms: u128,
     },
     GamepadAnalogStick {
         axis: u8,
@@ -60,16 +227,24 @@ impl MappingEngine {
         Self {
             mode_mappings: HashMap::new(),
             global_mappings: Vec::new(),
+            layers: Vec::new(),
         }
     }
 
     pub fn load_from_config(&mut self, config: &Config) {
+        // Layers refer to mode indices of the previous config
+        self.layers.clear();
+
         // Load mode-specific mappings
         for (mode_idx, mode) in config.modes.iter().enumerate() {
             let compiled: Vec<CompiledMapping> = mode
                 .mappings
                 .iter()
-                .map(|m| self.compile_mapping(m))
+                .enumerate()
+                .map(|(i, m)| {
+                    let id = format!("{}/{}", mode.name, i);
+                    self.compile_mapping(m, &id, &config.advanced_settings)
+                })
                 .collect();
 
             self.mode_mappings.insert(mode_idx as u8, compiled);
@@ -79,31 +254,245 @@ impl MappingEngine {
         self.global_mappings = config
             .global_mappings
             .iter()
-            .map(|m| self.compile_mapping(m))
+            .enumerate()
+            .map(|(i, m)| {
+                let id = format!("global_mappings/{}", i);
+                self.compile_mapping(m, &id, &config.advanced_settings)
+            })
             .collect();
     }
 
-    fn compile_mapping(&self, mapping: &Mapping) -> CompiledMapping {
+    /// Compile a mapping, filling unset per-trigger timings from `settings`
+    ///
+    /// `id` identifies the mapping, its Cycle and Toggle actions and its
+    /// running actions (its mode name and index, or `global_mappings` and
+    /// index).
+    fn compile_mapping(
+        &self,
+        mapping: &Mapping,
+        id: &str,
+        settings: &AdvancedSettings,
+    ) -> CompiledMapping {
+        let chord_timeout =
+            |timeout_ms: &Option<u64>| timeout_ms.unwrap_or(settings.chord_timeout_ms) as u128;
+
+        let mut action: Action = mapping.action.clone().into();
+        action.assign_cycle_ids(id);
+        let on_release = mapping.on_release.clone().map(|on_release| {
+            let mut on_release: Action = on_release.into();
+            on_release.assign_cycle_ids(&format!("{}/release", id));
+            on_release
+        });
+
         CompiledMapping {
             trigger: match &mapping.trigger {
-                Trigger::Note { note, velocity_min } => CompiledTrigger::Note {
+                Trigger::Note {
+                    note, velocity_min, ..
+                } => CompiledTrigger::Note {
                     note: *note,
                     velocity_min: velocity_min.unwrap_or(1),
                 },
-                Trigger::CC { cc, value_min } => CompiledTrigger::CC {
+                Trigger::CC {
+                    cc,
+                    value_min,
+                    value_max,
+                    ..
+                } => CompiledTrigger::CC {
                     cc: *cc,
                     value_min: value_min.unwrap_or(0),
+                    value_max: value_max.unwrap_or(127),
+                },
+                Trigger::CCZone {
+                    cc, zones, zone, ..
+                } => CompiledTrigger::CCZone {
+                    cc: *cc,
+                    zones: *zones,
+                    zone: *zone,
+                },
+                Trigger::CCThreshold {
+                    cc,
+                    threshold,
+                    direction,
+                    ..
+                } => CompiledTrigger::CCThreshold {
+                    cc: *cc,
+                    threshold: *threshold,
+                    direction: *direction,
+                },
+                Trigger::NoteRange {
+                    min,
+                    max,
+                    velocity_min,
+                    ..
+                } => CompiledTrigger::NoteRange {
+                    min: *min,
+                    max: *max,
+                    velocity_min: velocity_min.unwrap_or(1),
+                },
+                Trigger::CCRange {
+                    min,
+                    max,
+                    value_min,
+                    ..
+                } => CompiledTrigger::CCRange {
+                    min: *min,
+                    max: *max,
+                    value_min: value_min.unwrap_or(0),
                 },
-                Trigger::NoteChord { notes, .. } => CompiledTrigger::NoteChord {
+                Trigger::NoteChord {
+                    notes, timeout_ms, ..
+                } => CompiledTrigger::NoteChord {
                     notes: notes.clone(),
+                    timeout_ms: chord_timeout(timeout_ms),
+                },
+                // Validation rejects bad patterns; an empty one never matches
+                Trigger::SysEx { pattern } => CompiledTrigger::SysEx {
+                    pattern: SysExByte::parse_pattern(pattern).unwrap_or_default(),
+                },
+                Trigger::Transport { command } => CompiledTrigger::Transport { command: *command },
+                Trigger::SongPosition {
+                    position_min,
+                    position_max,
+                } => CompiledTrigger::SongPosition {
+                    position_min: position_min.unwrap_or(0),
+                    position_max: position_max.unwrap_or(u16::MAX),
+                },
+                Trigger::Tempo { bpm_min, bpm_max } => CompiledTrigger::Tempo {
+                    bpm_min: bpm_min.unwrap_or(DEFAULT_BPM_MIN),
+                    bpm_max: bpm_max.unwrap_or(DEFAULT_BPM_MAX),
+                },
+                Trigger::TouchSwipe {
+                    direction,
+                    min_speed,
+                    min_distance,
+                    cc,
+                    ..
+                } => CompiledTrigger::TouchSwipe {
+                    source: TouchSource::from_cc(*cc),
+                    direction: *direction,
+                    min_speed: min_speed.unwrap_or(DEFAULT_SWIPE_MIN_SPEED),
+                    min_distance: min_distance.unwrap_or(DEFAULT_SWIPE_MIN_DISTANCE),
+                },
+                Trigger::TouchTap {
+                    zones, zone, cc, ..
+                } => CompiledTrigger::TouchTap {
+                    source: TouchSource::from_cc(*cc),
+                    zones: zones.unwrap_or(1),
+                    zone: *zone,
+                },
+                Trigger::TouchSlideHold {
+                    hold_ms,
+                    min_distance,
+                    cc,
+                    ..
+                } => CompiledTrigger::TouchSlideHold {
+                    source: TouchSource::from_cc(*cc),
+                    hold_ms: hold_ms.unwrap_or(DEFAULT_SLIDE_HOLD_MS) as u128,
+                    min_distance: min_distance.unwrap_or(DEFAULT_SLIDE_MIN_DISTANCE),
+                },
+                // Steps and gaps are checked by the EventProcessor
+                Trigger::Sequence { steps, .. } => CompiledTrigger::Sequence {
+                    steps: steps.clone(),
+                },
+                Trigger::VelocityRange {
+                    note,
+                    soft_max,
+                    medium_max,
+                    level,
+                    ..
+                } => CompiledTrigger::VelocityRange {
+                    note: *note,
+                    level: level.as_deref().and_then(parse_velocity_level),
+                    soft_max: soft_max.unwrap_or(settings.velocity_soft_max),
+                    medium_max: medium_max.unwrap_or(settings.velocity_medium_max),
+                },
+                Trigger::LongPress {
+                    note,
+                    duration_ms,
+                    repeat_ms,
+                    ..
+                } => CompiledTrigger::LongPress {
+                    note: *note,
+                    duration_ms: duration_ms.unwrap_or(settings.hold_threshold_ms) as u128,
+                    repeat_ms: repeat_ms.map(u128::from),
+                },
+                Trigger::DoubleTap {
+                    note, timeout_ms, ..
+                } => CompiledTrigger::DoubleTap {
+                    note: *note,
+                    timeout_ms: timeout_ms.unwrap_or(settings.double_tap_timeout_ms) as u128,
+                },
+                Trigger::EncoderTurn {
+                    cc,
+                    direction,
+                    acceleration,
+                    ..
+                } => CompiledTrigger::EncoderTurn {
+                    cc: *cc,
+                    direction: direction.as_deref().and_then(parse_encoder_direction),
+                    per_step: acceleration.is_some(),
+                },
+                Trigger::Aftertouch { pressure_min, .. } => CompiledTrigger::Aftertouch {
+                    pressure_min: pressure_min.unwrap_or(0),
+                },
+                Trigger::PitchBend {
+                    value_min,
+                    value_max,
+                    ..
+                } => CompiledTrigger::PitchBend {
+                    value_min: value_min.unwrap_or(0),
+                    value_max: value_max.unwrap_or(16383),
+                },
+                Trigger::HighResCC {
+                    cc,
+                    value_min,
+                    value_max,
+                    ..
+                } => CompiledTrigger::HighResCC {
+                    cc: *cc,
+                    value_min: value_min.unwrap_or(0),
+                    value_max: value_max.unwrap_or(16383),
+                },
+                Trigger::Nrpn {
+                    parameter,
+                    value_min,
+                    value_max,
+                    ..
+                } => CompiledTrigger::Nrpn {
+                    parameter: *parameter,
+                    value_min: value_min.unwrap_or(0),
+                    value_max: value_max.unwrap_or(16383),
                 },
+                Trigger::Rpn {
+                    parameter,
+                    value_min,
+                    value_max,
+                    ..
+                } => CompiledTrigger::Rpn {
+                    parameter: *parameter,
+                    value_min: value_min.unwrap_or(0),
+                    value_max: value_max.unwrap_or(16383),
+                },
+                Trigger::PolyPressure {
+                    note, pressure_min, ..
+                } => CompiledTrigger::PolyPressure {
+                    note: *note,
+                    pressure_min: *pressure_min,
+                },
+                Trigger::ProgramChange { program, .. } => {
+                    CompiledTrigger::ProgramChange { program: *program }
+                }
                 // Gamepad triggers (v3.0)
                 Trigger::GamepadButton { button, velocity_min } => CompiledTrigger::GamepadButton {
                     button: *button,
                     velocity_min: velocity_min.unwrap_or(1),
                 },
-                Trigger::GamepadButtonChord { buttons, .. } => CompiledTrigger::GamepadButtonChord {
+                Trigger::GamepadButtonChord {
+                    buttons,
+                    timeout_ms,
+                } => CompiledTrigger::GamepadButtonChord {
                     buttons: buttons.clone(),
+                    timeout_ms: chord_timeout(timeout_ms),
                 },
                 Trigger::GamepadAnalogStick { axis, direction } => CompiledTrigger::GamepadAnalogStick {
                     axis: *axis,
@@ -113,39 +502,193 @@ impl MappingEngine {
                     trigger: *trigger,
                     threshold: threshold.unwrap_or(0),
                 },
-                // Other trigger types are not yet fully integrated into CompiledTrigger
-                // Fall back to a default Note trigger for now
-                _ => CompiledTrigger::Note {
-                    note: 0,
-                    velocity_min: 1,
-                },
             },
-            action: mapping.action.clone().into(),
+            channel: mapping.trigger.channel(),
+            action,
+            on_release,
+            momentary: mapping.momentary,
+            continuous: mapping.continuous.clone(),
             description: mapping.description.clone(),
+            while_held: mapping.while_held.clone(),
+            id: id.to_string(),
+            concurrency: mapping.concurrency,
         }
     }
 
-    pub fn get_action(&self, event: &MidiEvent, mode: u8) -> Option<Action> {
-        // Check mode-specific mappings first
-        if let Some(mode_mappings) = self.mode_mappings.get(&mode) {
-            return self.find_matching_action(event, mode_mappings);
+    /// Push a mode onto the layer stack
+    ///
+    /// While layered, the mode's mappings are checked before those of the
+    /// layers below it and of the base mode.
+    pub fn push_layer(&mut self, mode: u8) {
+        self.layers.push(mode);
+    }
+
+    /// Remove the topmost occurrence of a mode from the layer stack
+    ///
+    /// Layers can be released in any order. Returns false if the mode was not
+    /// layered.
+    pub fn pop_layer(&mut self, mode: u8) -> bool {
+        match self.layers.iter().rposition(|&layer| layer == mode) {
+            Some(index) => {
+                self.layers.remove(index);
+                true
+            }
+            None => false,
         }
+    }
+
+    /// Remove all layers, leaving only the base mode
+    pub fn clear_layers(&mut self) {
+        self.layers.clear();
+    }
+
+    /// Active layers, bottom to top
+    pub fn layers(&self) -> &[u8] {
+        &self.layers
+    }
+
+    /// The mode on top of the layer stack, or `base_mode` if none is layered
+    pub fn effective_mode(&self, base_mode: u8) -> u8 {
+        self.layers.last().copied().unwrap_or(base_mode)
+    }
 
-        // Check global mappings
-        self.find_matching_action(event, &self.global_mappings)
+    /// Mapping tables in lookup order: layers from the top down, the base
+    /// mode, then global mappings
+    fn lookup_order(&self, mode: u8) -> impl Iterator<Item = &[CompiledMapping]> {
+        self.layers
+            .iter()
+            .rev()
+            .copied()
+            .chain(std::iter::once(mode))
+            .filter_map(|mode| self.mode_mappings.get(&mode))
+            .map(Vec::as_slice)
+            .chain(std::iter::once(self.global_mappings.as_slice()))
+    }
+
+    pub fn get_action(&self, event: &MidiEvent, mode: u8) -> Option<Action> {
+        self.lookup_order(mode)
+            .find_map(|mappings| self.find_matching_action(event, mappings))
     }
 
     /// Get action for a processed event (supports advanced triggers like chords)
+    ///
+    /// Active layers are checked first, then `mode`, then global mappings.
     pub fn get_action_for_processed(&self, event: &ProcessedEvent, mode: u8) -> Option<Action> {
-        // Check mode-specific mappings first
-        if let Some(mode_mappings) = self.mode_mappings.get(&mode) {
-            if let Some(action) = self.find_matching_action_for_processed(event, mode_mappings) {
-                return Some(action);
+        self.get_mapped_action_for_processed(event, mode)
+            .map(|mapped| mapped.action)
+    }
+
+    /// Get the action for a processed event along with its `on_release` action
+    /// and `momentary` flag
+    ///
+    /// For a continuous mapping, the event's value is scaled and applied to
+    /// the returned action. An accelerated `EncoderTurn` repeats the action
+    /// once per step of the turn.
+    ///
+    /// Mappings with a `while_held` guard don't match; see
+    /// [`MappingEngine::get_mapped_action_while_held`].
+    pub fn get_mapped_action_for_processed(
+        &self,
+        event: &ProcessedEvent,
+        mode: u8,
+    ) -> Option<MappedAction> {
+        self.get_mapped_action_while_held(event, mode, &[])
+    }
+
+    /// Get the mapped action for a processed event while the `held` pads,
+    /// as `(channel, note)`, are down
+    ///
+    /// Mappings whose `while_held` pads are all held are candidates, and the
+    /// one requiring the most held pads wins, so "hold pad 12 + turn encoder 3"
+    /// takes precedence over the plain encoder mapping in any table. Ties go
+    /// to the lookup order. A mapping with a `channel` only counts pads held
+    /// on that channel.
+    pub fn get_mapped_action_while_held(
+        &self,
+        event: &ProcessedEvent,
+        mode: u8,
+        held: &[(u8, u8)],
+    ) -> Option<MappedAction> {
+        let mut found: Option<&CompiledMapping> = None;
+        for mappings in self.lookup_order(mode) {
+            if let Some(mapping) = self.find_matching_mapping_for_processed(event, mappings, held)
+                && is_more_specific(mapping, found)
+            {
+                found = Some(mapping);
             }
         }
+        let mapping = found?;
+        if let Some(desc) = &mapping.description {
+            debug!(
+                mapping = desc,
+                "Executing mapped action for processed event"
+            );
+        }
+
+        let value = mapping
+            .continuous
+            .as_ref()
+            .zip(control_position(&mapping.trigger, event))
+            .map(|(continuous, position)| scale_value(position, continuous));
+        let mut action = match value {
+            Some(value) => mapping.action.clone().with_value(value),
+            None => mapping.action.clone(),
+        };
+        if let (
+            CompiledTrigger::EncoderTurn { per_step: true, .. },
+            ProcessedEvent::EncoderTurned { delta, .. },
+        ) = (&mapping.trigger, event)
+            && *delta > 1
+        {
+            action = Action::Repeat {
+                action: Box::new(action),
+                count: *delta as usize,
+                delay_ms: None,
+            };
+        }
+
+        Some(MappedAction {
+            action,
+            on_release: mapping.on_release.clone(),
+            momentary: mapping.momentary,
+            control: matched_control(&mapping.trigger, event),
+            value,
+            captures: match (&mapping.trigger, event) {
+                (CompiledTrigger::SysEx { pattern }, ProcessedEvent::SysExReceived { data }) => {
+                    sysex_captures(pattern, data).unwrap_or_default()
+                }
+                _ => Vec::new(),
+            },
+            modifiers: held
+                .iter()
+                .filter(|pad| is_modifier(mapping, pad))
+                .copied()
+                .collect(),
+            id: mapping.id.clone(),
+            description: mapping.description.clone(),
+            concurrency: mapping.concurrency,
+        })
+    }
 
-        // Check global mappings
-        self.find_matching_action_for_processed(event, &self.global_mappings)
+    /// Get the mapped action of every event in a batch from the event
+    /// processor, in order
+    ///
+    /// One raw input can produce several events that each have a mapping,
+    /// e.g. a sequence's last step and its `SequenceMatched`, or a CC and the
+    /// `CCZone` it entered, so every matched event fires.
+    pub fn get_mapped_actions_for_batch<'a>(
+        &self,
+        events: &'a [ProcessedEvent],
+        mode: u8,
+        held: &[(u8, u8)],
+    ) -> Vec<(&'a ProcessedEvent, MappedAction)> {
+        events
+            .iter()
+            .filter_map(|event| {
+                self.get_mapped_action_while_held(event, mode, held)
+                    .map(|mapped| (event, mapped))
+            })
+            .collect()
     }
 
     fn find_matching_action(
@@ -154,7 +697,9 @@ impl MappingEngine {
         mappings: &[CompiledMapping],
     ) -> Option<Action> {
         for mapping in mappings {
-            if self.trigger_matches(&mapping.trigger, event) {
+            if channel_matches(mapping.channel, event.channel())
+                && self.trigger_matches(&mapping.trigger, event)
+            {
                 if let Some(desc) = &mapping.description {
                     debug!(mapping = desc, "Executing mapped action");
                 }
@@ -165,21 +710,31 @@ impl MappingEngine {
         None
     }
 
-    fn find_matching_action_for_processed(
+    /// The most specific mapping in a table matching an event while the
+    /// `held` pads are down
+    fn find_matching_mapping_for_processed<'a>(
         &self,
         event: &ProcessedEvent,
-        mappings: &[CompiledMapping],
-    ) -> Option<Action> {
+        mappings: &'a [CompiledMapping],
+        held: &[(u8, u8)],
+    ) -> Option<&'a CompiledMapping> {
+        let mut found = None;
         for mapping in mappings {
-            if self.trigger_matches_processed(&mapping.trigger, event) {
-                if let Some(desc) = &mapping.description {
-                    debug!(mapping = desc, "Executing mapped action for processed event");
-                }
-                return Some(mapping.action.clone());
+            if channel_matches(mapping.channel, event.channel())
+                && mapping.while_held.iter().all(|note| {
+                    held.iter()
+                        .any(|pad| pad.1 == *note && is_modifier(mapping, pad))
+                })
+                && is_more_specific(mapping, found)
+                && self.trigger_matches_processed(&mapping.trigger, event)
+            {
+                found = Some(mapping);
             }
         }
-        trace!("No mapping found for processed event");
-        None
+        if found.is_none() {
+            trace!("No mapping found for processed event");
+        }
+        found
     }
 
     fn trigger_matches(&self, trigger: &CompiledTrigger, event: &MidiEvent) -> bool {
@@ -193,11 +748,31 @@ impl MappingEngine {
                 },
             ) => *note == *ev_note && *velocity >= *velocity_min,
             (
-                CompiledTrigger::CC { cc, value_min },
+                CompiledTrigger::CC {
+                    cc,
+                    value_min,
+                    value_max,
+                },
                 MidiEvent::ControlChange {
                     cc: ev_cc, value, ..
                 },
-            ) => *cc == *ev_cc && *value >= *value_min,
+            ) => *cc == *ev_cc && (*value_min..=*value_max).contains(value),
+            (
+                CompiledTrigger::NoteRange {
+                    min,
+                    max,
+                    velocity_min,
+                },
+                MidiEvent::NoteOn { note, velocity, .. },
+            ) => (*min..=*max).contains(note) && *velocity >= *velocity_min,
+            (
+                CompiledTrigger::CCRange {
+                    min,
+                    max,
+                    value_min,
+                },
+                MidiEvent::ControlChange { cc, value, .. },
+            ) => (*min..=*max).contains(cc) && *value >= *value_min,
             _ => false,
         }
     }
@@ -205,9 +780,185 @@ impl MappingEngine {
     fn trigger_matches_processed(&self, trigger: &CompiledTrigger, event: &ProcessedEvent) -> bool {
         match (trigger, event) {
             (
-                CompiledTrigger::NoteChord { notes },
-                ProcessedEvent::ChordDetected { notes: detected_notes },
+                CompiledTrigger::Note { note, velocity_min },
+                ProcessedEvent::PadPressed {
+                    note: ev_note,
+                    velocity,
+                    ..
+                },
+            ) => *note == *ev_note && *velocity >= *velocity_min && *ev_note < 128,
+            (
+                CompiledTrigger::CC {
+                    cc,
+                    value_min,
+                    value_max,
+                },
+                ProcessedEvent::EncoderTurned {
+                    cc: ev_cc, value, ..
+                },
+            ) => *cc == *ev_cc && (*value_min..=*value_max).contains(value) && *ev_cc < 128,
+            (
+                CompiledTrigger::CCZone { cc, zones, zone },
+                ProcessedEvent::CCZoneEntered {
+                    cc: ev_cc,
+                    zones: ev_zones,
+                    zone: ev_zone,
+                    ..
+                },
+            ) => cc == ev_cc && zones == ev_zones && zone.is_none_or(|zone| zone == *ev_zone),
+            (
+                CompiledTrigger::CCThreshold {
+                    cc,
+                    threshold,
+                    direction,
+                },
+                ProcessedEvent::CCThresholdCrossed {
+                    cc: ev_cc,
+                    threshold: ev_threshold,
+                    direction: ev_direction,
+                    ..
+                },
+            ) => cc == ev_cc && threshold == ev_threshold && direction == ev_direction,
+            (
+                CompiledTrigger::NoteRange {
+                    min,
+                    max,
+                    velocity_min,
+                },
+                ProcessedEvent::PadPressed { note, velocity, .. },
+            ) => (*min..=*max).contains(note) && *velocity >= *velocity_min,
+            (
+                CompiledTrigger::CCRange {
+                    min,
+                    max,
+                    value_min,
+                },
+                ProcessedEvent::EncoderTurned { cc, value, .. },
+            ) => (*min..=*max).contains(cc) && *value >= *value_min,
+            (
+                CompiledTrigger::VelocityRange {
+                    note,
+                    level,
+                    soft_max,
+                    medium_max,
+                },
+                ProcessedEvent::PadPressed {
+                    note: ev_note,
+                    velocity,
+                    ..
+                },
+            ) => {
+                // Classify with this trigger's bands rather than the processor defaults
+                let velocity_level = VelocityLevel::classify(*velocity, *soft_max, *medium_max);
+                *note == *ev_note && level.is_none_or(|level| level == velocity_level)
+            }
+            (
+                CompiledTrigger::LongPress {
+                    note,
+                    duration_ms,
+                    repeat_ms,
+                },
+                ProcessedEvent::HoldDetected {
+                    note: ev_note,
+                    held_ms,
+                    previous_ms,
+                    ..
+                },
+            ) => {
+                *note == *ev_note && hold_crossed(*previous_ms, *held_ms, *duration_ms, *repeat_ms)
+            }
+            (
+                CompiledTrigger::DoubleTap { note, timeout_ms },
+                ProcessedEvent::DoubleTap {
+                    note: ev_note,
+                    gap_ms,
+                    ..
+                },
+            ) => *note == *ev_note && *gap_ms < *timeout_ms,
+            (
+                CompiledTrigger::EncoderTurn { cc, direction, .. },
+                ProcessedEvent::EncoderTurned {
+                    cc: ev_cc,
+                    direction: ev_direction,
+                    ..
+                },
+            ) => {
+                *cc == *ev_cc
+                    && *ev_cc < 128
+                    && direction.is_none_or(|direction| direction == *ev_direction)
+            }
+            (
+                CompiledTrigger::Aftertouch { pressure_min },
+                ProcessedEvent::AftertouchChanged { pressure, .. },
+            ) => *pressure >= *pressure_min,
+            (
+                CompiledTrigger::PitchBend {
+                    value_min,
+                    value_max,
+                },
+                ProcessedEvent::PitchBendMoved { value, .. },
+            ) => *value >= *value_min && *value <= *value_max,
+            (
+                CompiledTrigger::HighResCC {
+                    cc,
+                    value_min,
+                    value_max,
+                },
+                ProcessedEvent::HighResCCChanged {
+                    cc: ev_cc, value, ..
+                },
+            ) => *cc == *ev_cc && (*value_min..=*value_max).contains(value),
+            (
+                CompiledTrigger::Nrpn {
+                    parameter,
+                    value_min,
+                    value_max,
+                },
+                ProcessedEvent::NrpnChanged {
+                    parameter: ev_parameter,
+                    value,
+                    ..
+                },
+            )
+            | (
+                CompiledTrigger::Rpn {
+                    parameter,
+                    value_min,
+                    value_max,
+                },
+                ProcessedEvent::RpnChanged {
+                    parameter: ev_parameter,
+                    value,
+                    ..
+                },
+            ) => *parameter == *ev_parameter && (*value_min..=*value_max).contains(value),
+            (
+                CompiledTrigger::PolyPressure { note, pressure_min },
+                ProcessedEvent::PolyPressureChanged {
+                    note: ev_note,
+                    pressure,
+                    previous,
+                    ..
+                },
             ) => {
+                *note == *ev_note
+                    && pressure_min.is_none_or(|min| *previous < min && *pressure >= min)
+            }
+            (
+                CompiledTrigger::ProgramChange { program },
+                ProcessedEvent::ProgramChanged {
+                    program: ev_program,
+                    ..
+                },
+            ) => program == ev_program,
+            (
+                CompiledTrigger::NoteChord { notes, timeout_ms },
+                ProcessedEvent::ChordDetected {
+                    notes: detected_notes,
+                    span_ms,
+                    ..
+                },
+            ) if *span_ms < *timeout_ms => {
                 // Check if all required notes are present in the detected chord
                 // Sort both lists for comparison
                 let mut required = notes.clone();
@@ -217,6 +968,38 @@ impl MappingEngine {
 
                 required == detected
             }
+            (CompiledTrigger::SysEx { pattern }, ProcessedEvent::SysExReceived { data }) => {
+                sysex_captures(pattern, data).is_some()
+            }
+            (
+                CompiledTrigger::Transport { command },
+                ProcessedEvent::TransportChanged {
+                    command: ev_command,
+                },
+            ) => command == ev_command,
+            (
+                CompiledTrigger::SongPosition {
+                    position_min,
+                    position_max,
+                },
+                ProcessedEvent::SongPositionChanged { position },
+            ) => (*position_min..=*position_max).contains(position),
+            (CompiledTrigger::Tempo { bpm_min, bpm_max }, ProcessedEvent::TempoChanged { bpm }) => {
+                (*bpm_min..=*bpm_max).contains(bpm)
+            }
+            (
+                trigger,
+                ProcessedEvent::GestureRecognized {
+                    source, gesture, ..
+                },
+            ) => gesture_matches(trigger, *source, gesture),
+            (
+                CompiledTrigger::Sequence { steps },
+                ProcessedEvent::SequenceMatched {
+                    steps: matched_steps,
+                    ..
+                },
+            ) => steps == matched_steps,
             // Gamepad button press (v3.0)
             (
                 CompiledTrigger::GamepadButton { button, velocity_min },
@@ -227,9 +1010,16 @@ impl MappingEngine {
             }
             // Gamepad button chord (v3.0)
             (
-                CompiledTrigger::GamepadButtonChord { buttons },
-                ProcessedEvent::ChordDetected { notes: detected_buttons },
-            ) => {
+                CompiledTrigger::GamepadButtonChord {
+                    buttons,
+                    timeout_ms,
+                },
+                ProcessedEvent::ChordDetected {
+                    notes: detected_buttons,
+                    span_ms,
+                    ..
+                },
+            ) if *span_ms < *timeout_ms => {
                 // Check if all required gamepad buttons are present
                 // Sort both lists for comparison
                 let mut required = buttons.clone();
@@ -273,3 +1063,208 @@ impl MappingEngine {
         }
     }
 }
+
+/// Match a touch strip gesture against a gesture trigger
+fn gesture_matches(trigger: &CompiledTrigger, source: TouchSource, gesture: &Gesture) -> bool {
+    match (trigger, gesture) {
+        (
+            CompiledTrigger::TouchSwipe {
+                source: trigger_source,
+                direction,
+                min_speed,
+                min_distance,
+            },
+            Gesture::Swipe {
+                direction: swiped,
+                distance,
+                speed,
+            },
+        ) => {
+            *trigger_source == source
+                && direction == swiped
+                && distance >= min_distance
+                && speed >= min_speed
+        }
+        (
+            CompiledTrigger::TouchTap {
+                source: trigger_source,
+                zones,
+                zone,
+            },
+            Gesture::Tap { position },
+        ) => {
+            *trigger_source == source
+                && zone.is_none_or(|zone| zone == touch_zone(*position, *zones))
+        }
+        (
+            CompiledTrigger::TouchSlideHold {
+                source: trigger_source,
+                hold_ms,
+                min_distance,
+            },
+            Gesture::SlideHold {
+                distance,
+                held_ms,
+                previous_ms,
+                ..
+            },
+        ) => {
+            *trigger_source == source
+                && distance >= min_distance
+                && *previous_ms < *hold_ms
+                && *held_ms >= *hold_ms
+        }
+        _ => false,
+    }
+}
+
+/// Zone (0 to `zones - 1`) of a touch strip position
+fn touch_zone(position: f32, zones: u8) -> u8 {
+    ((position.clamp(0.0, 1.0) * zones as f32) as u8).min(zones.saturating_sub(1))
+}
+
+/// Check an event's MIDI channel against a mapping's channel filter
+fn channel_matches(filter: Option<u8>, channel: u8) -> bool {
+    filter.is_none_or(|filter| filter == channel)
+}
+
+/// Whether the held pad `(channel, note)` is one of `mapping`'s
+/// `while_held` modifiers
+fn is_modifier(mapping: &CompiledMapping, &(channel, note): &(u8, u8)) -> bool {
+    mapping.while_held.contains(&note) && channel_matches(mapping.channel, channel)
+}
+
+/// Whether `mapping` requires more held pads than the best match so far
+fn is_more_specific(mapping: &CompiledMapping, best: Option<&CompiledMapping>) -> bool {
+    best.is_none_or(|best| mapping.while_held.len() > best.while_held.len())
+}
+
+/// The note or CC of a pad press, CC event or touch strip tap, with its
+/// offset within a range trigger or the zone entered or tapped
+fn matched_control(trigger: &CompiledTrigger, event: &ProcessedEvent) -> Option<MatchedControl> {
+    let (number, value) = match event {
+        ProcessedEvent::PadPressed { note, velocity, .. } => (*note, *velocity),
+        ProcessedEvent::EncoderTurned { cc, value, .. }
+        | ProcessedEvent::CCZoneEntered { cc, value, .. }
+        | ProcessedEvent::CCThresholdCrossed { cc, value, .. } => (*cc, *value),
+        ProcessedEvent::GestureRecognized {
+            source,
+            gesture: Gesture::Tap { position },
+            ..
+        } => {
+            let number = match source {
+                TouchSource::ControlChange(cc) => *cc,
+                TouchSource::PitchBend => 0,
+            };
+            (number, (position * 127.0).round() as u8)
+        }
+        _ => return None,
+    };
+    let offset = match (trigger, event) {
+        (CompiledTrigger::NoteRange { min, .. } | CompiledTrigger::CCRange { min, .. }, _) => {
+            number.saturating_sub(*min)
+        }
+        (_, ProcessedEvent::CCZoneEntered { zone, .. }) => *zone,
+        (
+            CompiledTrigger::TouchTap { zones, .. },
+            ProcessedEvent::GestureRecognized {
+                gesture: Gesture::Tap { position },
+                ..
+            },
+        ) => touch_zone(*position, *zones),
+        _ => 0,
+    };
+
+    Some(MatchedControl {
+        number,
+        offset,
+        value,
+    })
+}
+
+/// Match a SysEx message against a pattern, returning the captured bytes
+fn sysex_captures(pattern: &[SysExByte], data: &[u8]) -> Option<Vec<u8>> {
+    if pattern.is_empty() || pattern.len() != data.len() {
+        return None;
+    }
+
+    let mut captures = Vec::new();
+    for (expected, byte) in pattern.iter().zip(data) {
+        match expected {
+            SysExByte::Exact(expected) if expected != byte => return None,
+            SysExByte::Capture => captures.push(*byte),
+            _ => {}
+        }
+    }
+    Some(captures)
+}
+
+/// Position (0.0-1.0) of the control behind a value-carrying event
+///
+/// A tempo's position is relative to the BPM range of its trigger.
+fn control_position(trigger: &CompiledTrigger, event: &ProcessedEvent) -> Option<f32> {
+    match event {
+        ProcessedEvent::TempoChanged { bpm } => match trigger {
+            CompiledTrigger::Tempo { bpm_min, bpm_max } if bpm_max > bpm_min => {
+                Some(((bpm - bpm_min) / (bpm_max - bpm_min)).clamp(0.0, 1.0) as f32)
+            }
+            _ => None,
+        },
+        ProcessedEvent::GestureRecognized {
+            gesture: Gesture::Tap { position } | Gesture::SlideHold { position, .. },
+            ..
+        } => Some(*position),
+        ProcessedEvent::EncoderTurned { value, .. }
+        | ProcessedEvent::CCZoneEntered { value, .. }
+        | ProcessedEvent::CCThresholdCrossed { value, .. } => Some(*value as f32 / 127.0),
+        ProcessedEvent::AftertouchChanged { pressure, .. }
+        | ProcessedEvent::PolyPressureChanged { pressure, .. } => Some(*pressure as f32 / 127.0),
+        ProcessedEvent::PitchBendMoved { value, .. }
+        | ProcessedEvent::HighResCCChanged { value, .. }
+        | ProcessedEvent::NrpnChanged { value, .. }
+        | ProcessedEvent::RpnChanged { value, .. } => Some(*value as f32 / 16383.0),
+        ProcessedEvent::PadPressed { velocity, .. } => Some(*velocity as f32 / 127.0),
+        _ => None,
+    }
+}
+
+/// Check whether a hold report covering `(previous_ms, held_ms]` crosses the
+/// long-press threshold or, with `repeat_ms`, one of its repeats
+fn hold_crossed(
+    previous_ms: u128,
+    held_ms: u128,
+    duration_ms: u128,
+    repeat_ms: Option<u128>,
+) -> bool {
+    if held_ms < duration_ms {
+        return false;
+    }
+    if previous_ms < duration_ms {
+        return true;
+    }
+    match repeat_ms {
+        Some(repeat) if repeat > 0 => {
+            (held_ms - duration_ms) / repeat > (previous_ms - duration_ms) / repeat
+        }
+        _ => false,
+    }
+}
+
+/// Parse a velocity level name from config ("Soft", "Medium", "Hard")
+fn parse_velocity_level(level: &str) -> Option<VelocityLevel> {
+    match level {
+        "Soft" => Some(VelocityLevel::Soft),
+        "Medium" => Some(VelocityLevel::Medium),
+        "Hard" => Some(VelocityLevel::Hard),
+        _ => None,
+    }
+}
+
+/// Parse an encoder direction name from config ("Clockwise", "CounterClockwise")
+fn parse_encoder_direction(direction: &str) -> Option<EncoderDirection> {
+    match direction {
+        "Clockwise" => Some(EncoderDirection::Clockwise),
+        "CounterClockwise" => Some(EncoderDirection::CounterClockwise),
+        _ => None,
+    }
+}
@@ -3,8 +3,10 @@
 
 //! ActionPlugin trait for custom action plugins
 
+use crate::variables::VariableValue;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::collections::HashMap;
 use std::error::Error;
 
 use super::Capability;
@@ -175,6 +177,12 @@ pub struct TriggerContext {
     ///
     /// Use for timing analysis or rate limiting.
     pub timestamp: u64,
+
+    /// Runtime variables set by actions, by name
+    ///
+    /// Plugins can use these to act on state such as a toggle or counter.
+    #[serde(default)]
+    pub variables: HashMap<String, VariableValue>,
 }
 
 impl TriggerContext {
@@ -187,6 +195,7 @@ impl TriggerContext {
                 .duration_since(std::time::UNIX_EPOCH)
                 .unwrap()
                 .as_millis() as u64,
+            variables: HashMap::new(),
         }
     }
 
@@ -199,6 +208,7 @@ impl TriggerContext {
                 .duration_since(std::time::UNIX_EPOCH)
                 .unwrap()
                 .as_millis() as u64,
+            variables: HashMap::new(),
         }
     }
 
@@ -211,6 +221,7 @@ impl TriggerContext {
                 .duration_since(std::time::UNIX_EPOCH)
                 .unwrap()
                 .as_millis() as u64,
+            variables: HashMap::new(),
         }
     }
 }
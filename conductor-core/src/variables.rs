@@ -0,0 +1,202 @@
+// Copyright 2025 Amiable
+// SPDX-License-Identifier: MIT
+
+//! Runtime variables
+//!
+//! Named values that actions write and conditions read, so a mapping can
+//! remember state between presses: a toggle (`muted`), a counter (`scene`)
+//! or a label (`layout`). Variables are untyped until set; a variable that
+//! was never set reads as unset rather than as a default value.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// The value of a variable
+///
+/// Written in config as a plain TOML boolean, number or string.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum VariableValue {
+    Bool(bool),
+    Number(f64),
+    Text(String),
+}
+
+impl VariableValue {
+    /// Whether the value counts as on: true, a non-zero number or a
+    /// non-empty string
+    pub fn is_truthy(&self) -> bool {
+        match self {
+            VariableValue::Bool(value) => *value,
+            VariableValue::Number(value) => *value != 0.0,
+            VariableValue::Text(value) => !value.is_empty(),
+        }
+    }
+
+    /// The value as a number, if it is one
+    pub fn as_number(&self) -> Option<f64> {
+        match self {
+            VariableValue::Number(value) => Some(*value),
+            _ => None,
+        }
+    }
+}
+
+/// The variables set by actions
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct VariableStore {
+    values: HashMap<String, VariableValue>,
+}
+
+impl VariableStore {
+    /// The value of `name`, or None if it was never set
+    pub fn get(&self, name: &str) -> Option<&VariableValue> {
+        self.values.get(name)
+    }
+
+    /// All variables by name
+    pub fn values(&self) -> &HashMap<String, VariableValue> {
+        &self.values
+    }
+
+    /// Set `name` to `value`, whatever it was before
+    pub fn set(&mut self, name: &str, value: VariableValue) {
+        self.values.insert(name.to_string(), value);
+    }
+
+    /// Set the variables in `values` that aren't set yet
+    pub fn set_defaults(&mut self, values: &HashMap<String, VariableValue>) {
+        for (name, value) in values {
+            self.values
+                .entry(name.clone())
+                .or_insert_with(|| value.clone());
+        }
+    }
+
+    /// Flip `name` between true and false, returning the new value
+    ///
+    /// An unset variable becomes true; any other value becomes the opposite
+    /// of whether it [counts as on](VariableValue::is_truthy).
+    pub fn toggle(&mut self, name: &str) -> bool {
+        let value = !self.get(name).is_some_and(VariableValue::is_truthy);
+        self.set(name, VariableValue::Bool(value));
+        value
+    }
+
+    /// Add `amount` to `name`, returning the new value
+    ///
+    /// An unset or non-numeric variable counts from `min`, or from 0 without
+    /// one. The result is clamped to `min`..=`max`, or with `wrap` (and both
+    /// bounds) wraps past one bound to the other, so a counter can step
+    /// through a list of scenes.
+    pub fn increment(
+        &mut self,
+        name: &str,
+        amount: f64,
+        min: Option<f64>,
+        max: Option<f64>,
+        wrap: bool,
+    ) -> f64 {
+        let current = self
+            .get(name)
+            .and_then(VariableValue::as_number)
+            .unwrap_or(min.unwrap_or(0.0));
+        let mut value = current + amount;
+        match (min, max) {
+            (Some(min), Some(max)) if wrap && value > max => value = min,
+            (Some(min), Some(max)) if wrap && value < min => value = max,
+            _ => {
+                if let Some(max) = max {
+                    value = value.min(max);
+                }
+                if let Some(min) = min {
+                    value = value.max(min);
+                }
+            }
+        }
+        self.set(name, VariableValue::Number(value));
+        value
+    }
+
+    /// Whether `name` is set to `value`
+    ///
+    /// Numbers compare by value, so `1` equals `1.0`. An unset variable
+    /// equals nothing.
+    pub fn equals(&self, name: &str, value: &VariableValue) -> bool {
+        self.get(name) == Some(value)
+    }
+
+    /// Whether `name` is a number within `min`..=`max`
+    pub fn in_range(&self, name: &str, min: f64, max: f64) -> bool {
+        self.get(name)
+            .and_then(VariableValue::as_number)
+            .is_some_and(|value| (min..=max).contains(&value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_toggle() {
+        let mut store = VariableStore::default();
+        assert!(store.toggle("muted"));
+        assert!(!store.toggle("muted"));
+        assert!(store.equals("muted", &VariableValue::Bool(false)));
+
+        store.set("layout", VariableValue::Text("grid".to_string()));
+        assert!(!store.toggle("layout"));
+    }
+
+    #[test]
+    fn test_increment_clamps_and_wraps() {
+        let mut store = VariableStore::default();
+        assert_eq!(store.increment("count", 1.0, None, None, false), 1.0);
+        assert_eq!(store.increment("count", 5.0, None, Some(3.0), false), 3.0);
+        assert_eq!(
+            store.increment("count", -10.0, Some(0.0), Some(3.0), false),
+            0.0
+        );
+
+        // Cycle through scenes 1-3
+        let scenes: Vec<f64> = (0..4)
+            .map(|_| store.increment("scene", 1.0, Some(1.0), Some(3.0), true))
+            .collect();
+        assert_eq!(scenes, vec![2.0, 3.0, 1.0, 2.0]);
+        assert_eq!(
+            store.increment("scene", -2.0, Some(1.0), Some(3.0), true),
+            3.0
+        );
+    }
+
+    #[test]
+    fn test_conditions() {
+        let mut store = VariableStore::default();
+        assert!(!store.in_range("volume", 0.0, 10.0));
+        assert!(!store.equals("volume", &VariableValue::Number(0.0)));
+
+        store.set("volume", VariableValue::Number(4.0));
+        assert!(store.in_range("volume", 0.0, 10.0));
+        assert!(!store.in_range("volume", 5.0, 10.0));
+        assert!(store.equals("volume", &VariableValue::Number(4.0)));
+
+        store.set("volume", VariableValue::Text("4".to_string()));
+        assert!(!store.in_range("volume", 0.0, 10.0));
+    }
+
+    #[test]
+    fn test_value_from_toml() {
+        #[derive(Deserialize)]
+        struct Values {
+            flag: VariableValue,
+            count: VariableValue,
+            name: VariableValue,
+        }
+
+        let values: Values = toml::from_str("flag = true\ncount = 2\nname = \"drums\"").unwrap();
+        assert_eq!(values.flag, VariableValue::Bool(true));
+        assert_eq!(values.count, VariableValue::Number(2.0));
+        assert_eq!(values.name, VariableValue::Text("drums".to_string()));
+    }
+}
@@ -26,6 +26,12 @@
 //! - **Logarithmic**: Soft hits become quieter, compresses dynamic range
 //! - **S-Curve**: Smooth acceleration in the middle range
 //!
+//! # Continuous Values
+//!
+//! [`scale_value`] applies the same curves to knob, fader and pitch bend
+//! positions for continuous mappings, scaling the result into the mapping's
+//! output range.
+//!
 //! # Examples
 //!
 //! ```rust
@@ -46,6 +52,7 @@
 //! ```
 
 use crate::actions::{VelocityCurve, VelocityMapping};
+use crate::config::ContinuousValue;
 
 /// Calculate output velocity based on trigger velocity and mapping config
 ///
@@ -122,8 +129,40 @@ fn apply_curve(input: u8, curve_type: VelocityCurve, intensity: f32) -> u8 {
     // Normalize input to 0.0-1.0
     let normalized = input as f32 / 127.0;
 
-    // Apply curve transformation
-    let output = match curve_type {
+    let output = curve_position(normalized, curve_type, intensity);
+
+    // Scale back to 0-127 and clamp
+    (output * 127.0).round().clamp(0.0, 127.0) as u8
+}
+
+/// Scale a continuous control's position into a continuous mapping's range
+///
+/// `position` is the control's position within its travel (0.0-1.0). It is
+/// shaped by the mapping's curve, if any, and then mapped linearly onto
+/// `min`..`max`.
+///
+/// # Examples
+///
+/// ```rust
+/// use conductor_core::ContinuousValue;
+/// use conductor_core::velocity::scale_value;
+///
+/// let volume = ContinuousValue { min: 0.0, max: 100.0, curve: None, intensity: 0.5 };
+/// assert_eq!(scale_value(0.5, &volume), 50.0);
+/// ```
+pub fn scale_value(position: f32, continuous: &ContinuousValue) -> f64 {
+    let position = position.clamp(0.0, 1.0);
+    let shaped = match continuous.curve {
+        Some(curve_type) => curve_position(position, curve_type, continuous.intensity),
+        None => position,
+    };
+
+    continuous.min + (continuous.max - continuous.min) * shaped as f64
+}
+
+/// Shape a normalized (0.0-1.0) position with a velocity curve
+fn curve_position(normalized: f32, curve_type: VelocityCurve, intensity: f32) -> f32 {
+    match curve_type {
         VelocityCurve::Exponential => {
             // Exponential: y = x^(1 - intensity)
             // Makes soft hits louder by reducing the exponent
@@ -166,10 +205,7 @@ fn apply_curve(input: u8, curve_type: VelocityCurve, intensity: f32) -> u8 {
                 (sigmoid(normalized) - s0) / (s1 - s0)
             }
         }
-    };
-
-    // Scale back to 0-127 and clamp
-    (output * 127.0).round().clamp(0.0, 127.0) as u8
+    }
 }
 
 #[cfg(test)]
@@ -336,4 +372,39 @@ mod tests {
             assert!(output_max <= 127, "Max input should produce valid output");
         }
     }
+
+    #[test]
+    fn test_scale_value() {
+        let linear = ContinuousValue {
+            min: -8192.0,
+            max: 8191.0,
+            curve: None,
+            intensity: 0.5,
+        };
+        assert_eq!(scale_value(0.0, &linear), -8192.0);
+        assert_eq!(scale_value(1.0, &linear), 8191.0);
+        // Positions outside the control's travel are clamped
+        assert_eq!(scale_value(1.5, &linear), 8191.0);
+
+        let inverted = ContinuousValue {
+            min: 100.0,
+            max: 0.0,
+            ..linear.clone()
+        };
+        assert_eq!(scale_value(0.25, &inverted), 75.0);
+
+        let curved = ContinuousValue {
+            min: 0.0,
+            max: 100.0,
+            curve: Some(VelocityCurve::Logarithmic),
+            intensity: 0.3,
+        };
+        let halfway = scale_value(0.5, &curved);
+        assert!(
+            halfway < 50.0,
+            "Logarithmic curve lowers the middle, got {}",
+            halfway
+        );
+        assert_eq!(scale_value(1.0, &curved), 100.0);
+    }
 }
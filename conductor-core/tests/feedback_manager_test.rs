@@ -166,6 +166,18 @@ fn test_feedback_manager_on_mode_change() {
     assert_eq!(mock_state.lock().unwrap().mode, 2);
 }
 
+#[test]
+fn test_feedback_manager_show_long_press_feedback() {
+    let mock = MockFeedback::new();
+    let mock_state = mock.state.clone();
+    let mut manager = FeedbackManager::new(Box::new(mock));
+
+    manager.show_long_press_feedback(3, 500).unwrap();
+    manager.show_long_press_feedback(3, 1000).unwrap();
+
+    assert_eq!(mock_state.lock().unwrap().long_press_pads, vec![3, 3]);
+}
+
 #[test]
 fn test_feedback_manager_set_scheme() {
     let mock = MockFeedback::new();
@@ -6,6 +6,7 @@
 //! Tests that InputEvent (from gamepads) triggers correctly process through
 //! the EventProcessor, generating the same ProcessedEvents as MIDI.
 
+use conductor_core::config::AdvancedSettings;
 use conductor_core::events::InputEvent;
 use conductor_core::event_processor::{EncoderDirection, EventProcessor, ProcessedEvent, VelocityLevel};
 use std::time::Instant;
@@ -16,6 +17,7 @@ fn test_gamepad_button_press_detection() {
 
     // Gamepad button press (button ID 128 = South/A/Cross/B)
     let event = InputEvent::PadPressed {
+        channel: 0,
         pad: 128,
         velocity: 100,
         time: Instant::now(),
@@ -31,6 +33,7 @@ fn test_gamepad_button_press_detection() {
             note,
             velocity,
             velocity_level,
+            ..
         } => {
             assert_eq!(*note, 128);
             assert_eq!(*velocity, 100);
@@ -48,6 +51,7 @@ fn test_gamepad_button_release_short_press() {
 
     // Press button
     let press_event = InputEvent::PadPressed {
+        channel: 0,
         pad: 129, // East button (B/Circle/A)
         velocity: 80,
         time: base_time,
@@ -57,6 +61,7 @@ fn test_gamepad_button_release_short_press() {
 
     // Release quickly (100ms later = short press)
     let release_event = InputEvent::PadReleased {
+        channel: 0,
         pad: 129,
         time: base_time + std::time::Duration::from_millis(100),
     };
@@ -69,18 +74,22 @@ fn test_gamepad_button_release_short_press() {
     // Check for ShortPress
     let has_short_press = processed
         .iter()
-        .any(|e| matches!(e, ProcessedEvent::ShortPress { note: 129 }));
+        .any(|e| matches!(e, ProcessedEvent::ShortPress { note: 129, .. }));
     assert!(has_short_press, "Should detect short press");
 }
 
 #[test]
 fn test_gamepad_button_long_press() {
-    let mut processor = EventProcessor::new();
+    let mut processor = EventProcessor::with_settings(&AdvancedSettings {
+        hold_threshold_ms: 1000,
+        ..AdvancedSettings::default()
+    });
 
     let base_time = Instant::now();
 
     // Press button
     let press_event = InputEvent::PadPressed {
+        channel: 0,
         pad: 130, // West button (X/Square/Y)
         velocity: 100,
         time: base_time,
@@ -90,6 +99,7 @@ fn test_gamepad_button_long_press() {
 
     // Release after 1.5 seconds (long press)
     let release_event = InputEvent::PadReleased {
+        channel: 0,
         pad: 130,
         time: base_time + std::time::Duration::from_millis(1500),
     };
@@ -103,7 +113,8 @@ fn test_gamepad_button_long_press() {
     let has_long_press = processed.iter().any(|e| {
         matches!(e, ProcessedEvent::LongPress {
             note: 130,
-            duration_ms
+            duration_ms,
+            ..
         } if *duration_ms >= 1000)
     });
     assert!(has_long_press, "Should detect long press");
@@ -117,6 +128,7 @@ fn test_gamepad_double_tap() {
 
     // First tap
     let press1 = InputEvent::PadPressed {
+        channel: 0,
         pad: 131, // North button (Y/Triangle/X)
         velocity: 100,
         time: base_time,
@@ -124,6 +136,7 @@ fn test_gamepad_double_tap() {
     let _ = processor.process_input(press1);
 
     let release1 = InputEvent::PadReleased {
+        channel: 0,
         pad: 131,
         time: base_time + std::time::Duration::from_millis(50),
     };
@@ -131,6 +144,7 @@ fn test_gamepad_double_tap() {
 
     // Second tap within 300ms window
     let press2 = InputEvent::PadPressed {
+        channel: 0,
         pad: 131,
         velocity: 100,
         time: base_time + std::time::Duration::from_millis(200),
@@ -140,7 +154,7 @@ fn test_gamepad_double_tap() {
     // Should detect DoubleTap
     let has_double_tap = processed
         .iter()
-        .any(|e| matches!(e, ProcessedEvent::DoubleTap { note: 131 }));
+        .any(|e| matches!(e, ProcessedEvent::DoubleTap { note: 131, .. }));
     assert!(has_double_tap, "Should detect double tap");
 }
 
@@ -152,6 +166,7 @@ fn test_gamepad_chord_detection() {
 
     // Press multiple buttons within chord timeout (50ms)
     let button1 = InputEvent::PadPressed {
+        channel: 0,
         pad: 132, // D-Pad Up
         velocity: 100,
         time: base_time,
@@ -159,6 +174,7 @@ fn test_gamepad_chord_detection() {
     let _ = processor.process_input(button1);
 
     let button2 = InputEvent::PadPressed {
+        channel: 0,
         pad: 133, // D-Pad Down
         velocity: 100,
         time: base_time + std::time::Duration::from_millis(20),
@@ -166,6 +182,7 @@ fn test_gamepad_chord_detection() {
     let _ = processor.process_input(button2);
 
     let button3 = InputEvent::PadPressed {
+        channel: 0,
         pad: 134, // D-Pad Left
         velocity: 100,
         time: base_time + std::time::Duration::from_millis(40),
@@ -174,7 +191,7 @@ fn test_gamepad_chord_detection() {
 
     // Should detect ChordDetected
     let chord_event = processed.iter().find(|e| {
-        matches!(e, ProcessedEvent::ChordDetected { notes } if notes.len() == 3)
+        matches!(e, ProcessedEvent::ChordDetected { notes, .. } if notes.len() == 3)
     });
 
     assert!(chord_event.is_some(), "Should detect 3-button chord");
@@ -188,6 +205,7 @@ fn test_gamepad_analog_stick_movement() {
 
     // Initial position (center = 64)
     let initial = InputEvent::EncoderTurned {
+        channel: 0,
         encoder: 128, // Left stick X
         value: 64,
         time: base_time,
@@ -196,6 +214,7 @@ fn test_gamepad_analog_stick_movement() {
 
     // Move right (value increases)
     let moved_right = InputEvent::EncoderTurned {
+        channel: 0,
         encoder: 128,
         value: 95, // Normalized value for 0.5 on stick
         time: base_time + std::time::Duration::from_millis(10),
@@ -211,6 +230,7 @@ fn test_gamepad_analog_stick_movement() {
             value,
             direction,
             delta,
+            ..
         } => {
             assert_eq!(*cc, 128);
             assert_eq!(*value, 95);
@@ -229,6 +249,7 @@ fn test_gamepad_trigger_analog() {
 
     // Initial position (released = 0)
     let initial = InputEvent::EncoderTurned {
+        channel: 0,
         encoder: 132, // Left trigger (L2/LT)
         value: 0,
         time: base_time,
@@ -237,6 +258,7 @@ fn test_gamepad_trigger_analog() {
 
     // Pull trigger halfway (value 64)
     let pulled = InputEvent::EncoderTurned {
+        channel: 0,
         encoder: 132,
         value: 64,
         time: base_time + std::time::Duration::from_millis(10),
@@ -252,6 +274,7 @@ fn test_gamepad_trigger_analog() {
             value,
             direction,
             delta,
+            ..
         } => {
             assert_eq!(*cc, 132);
             assert_eq!(*value, 64);
@@ -269,6 +292,7 @@ fn test_gamepad_velocity_levels() {
 
     // Test soft press (0-40)
     let soft = InputEvent::PadPressed {
+        channel: 0,
         pad: 128,
         velocity: 30,
         time,
@@ -284,6 +308,7 @@ fn test_gamepad_velocity_levels() {
 
     // Test medium press (41-80)
     let medium = InputEvent::PadPressed {
+        channel: 0,
         pad: 129,
         velocity: 60,
         time,
@@ -299,6 +324,7 @@ fn test_gamepad_velocity_levels() {
 
     // Test hard press (81-127)
     let hard = InputEvent::PadPressed {
+        channel: 0,
         pad: 130,
         velocity: 120,
         time,
@@ -322,6 +348,7 @@ fn test_gamepad_exact_chord_matching() {
 
     // Press two buttons
     let button1 = InputEvent::PadPressed {
+        channel: 0,
         pad: 128,
         velocity: 100,
         time: base_time,
@@ -329,6 +356,7 @@ fn test_gamepad_exact_chord_matching() {
     let _ = processor.process_input(button1);
 
     let button2 = InputEvent::PadPressed {
+        channel: 0,
         pad: 129,
         velocity: 100,
         time: base_time + std::time::Duration::from_millis(20),
@@ -337,7 +365,7 @@ fn test_gamepad_exact_chord_matching() {
 
     // Should detect 2-button chord
     let chord = processed.iter().find_map(|e| {
-        if let ProcessedEvent::ChordDetected { notes } = e {
+        if let ProcessedEvent::ChordDetected { notes, .. } = e {
             Some(notes)
         } else {
             None
@@ -349,6 +377,7 @@ fn test_gamepad_exact_chord_matching() {
 
     // Add third button - should create a NEW 3-button chord
     let button3 = InputEvent::PadPressed {
+        channel: 0,
         pad: 130,
         velocity: 100,
         time: base_time + std::time::Duration::from_millis(40),
@@ -356,7 +385,7 @@ fn test_gamepad_exact_chord_matching() {
     let processed = processor.process_input(button3);
 
     let chord = processed.iter().find_map(|e| {
-        if let ProcessedEvent::ChordDetected { notes } = e {
+        if let ProcessedEvent::ChordDetected { notes, .. } = e {
             Some(notes)
         } else {
             None
@@ -13,6 +13,7 @@ use std::time::Instant;
 fn test_pad_pressed_conversion() {
     let time = Instant::now();
     let midi = MidiEvent::NoteOn {
+        channel: 0,
         note: 36,
         velocity: 100,
         time,
@@ -25,6 +26,7 @@ fn test_pad_pressed_conversion() {
             pad,
             velocity,
             time: event_time,
+            ..
         } => {
             assert_eq!(pad, 36, "Pad number should match MIDI note");
             assert_eq!(velocity, 100, "Velocity should be preserved");
@@ -37,7 +39,11 @@ fn test_pad_pressed_conversion() {
 #[test]
 fn test_pad_released_conversion() {
     let time = Instant::now();
-    let midi = MidiEvent::NoteOff { note: 48, time };
+    let midi = MidiEvent::NoteOff {
+        channel: 0,
+        note: 48,
+        time,
+    };
 
     let input: InputEvent = midi.into();
 
@@ -45,6 +51,7 @@ fn test_pad_released_conversion() {
         InputEvent::PadReleased {
             pad,
             time: event_time,
+            ..
         } => {
             assert_eq!(pad, 48, "Pad number should match MIDI note");
             assert_eq!(event_time, time, "Timestamp should be preserved");
@@ -57,6 +64,7 @@ fn test_pad_released_conversion() {
 fn test_encoder_turned_conversion() {
     let time = Instant::now();
     let midi = MidiEvent::ControlChange {
+        channel: 0,
         cc: 1,
         value: 64,
         time,
@@ -69,6 +77,7 @@ fn test_encoder_turned_conversion() {
             encoder,
             value,
             time: event_time,
+            ..
         } => {
             assert_eq!(encoder, 1, "Encoder ID should match CC number");
             assert_eq!(value, 64, "Encoder value should be preserved");
@@ -82,6 +91,7 @@ fn test_encoder_turned_conversion() {
 fn test_poly_pressure_conversion() {
     let time = Instant::now();
     let midi = MidiEvent::PolyPressure {
+        channel: 0,
         note: 60,
         pressure: 90,
         time,
@@ -94,6 +104,7 @@ fn test_poly_pressure_conversion() {
             pad,
             pressure,
             time: event_time,
+            ..
         } => {
             assert_eq!(pad, 60, "Pad number should match MIDI note");
             assert_eq!(pressure, 90, "Pressure should be preserved");
@@ -106,7 +117,11 @@ fn test_poly_pressure_conversion() {
 #[test]
 fn test_aftertouch_conversion() {
     let time = Instant::now();
-    let midi = MidiEvent::Aftertouch { pressure: 80, time };
+    let midi = MidiEvent::Aftertouch {
+        channel: 0,
+        pressure: 80,
+        time,
+    };
 
     let input: InputEvent = midi.into();
 
@@ -114,6 +129,7 @@ fn test_aftertouch_conversion() {
         InputEvent::Aftertouch {
             pressure,
             time: event_time,
+            ..
         } => {
             assert_eq!(pressure, 80, "Pressure should be preserved");
             assert_eq!(event_time, time, "Timestamp should be preserved");
@@ -125,7 +141,11 @@ fn test_aftertouch_conversion() {
 #[test]
 fn test_pitch_bend_conversion() {
     let time = Instant::now();
-    let midi = MidiEvent::PitchBend { value: 12000, time };
+    let midi = MidiEvent::PitchBend {
+        channel: 0,
+        value: 12000,
+        time,
+    };
 
     let input: InputEvent = midi.into();
 
@@ -133,6 +153,7 @@ fn test_pitch_bend_conversion() {
         InputEvent::PitchBend {
             value,
             time: event_time,
+            ..
         } => {
             assert_eq!(value, 12000, "Pitch bend value should be preserved");
             assert_eq!(event_time, time, "Timestamp should be preserved");
@@ -144,7 +165,11 @@ fn test_pitch_bend_conversion() {
 #[test]
 fn test_program_change_conversion() {
     let time = Instant::now();
-    let midi = MidiEvent::ProgramChange { program: 5, time };
+    let midi = MidiEvent::ProgramChange {
+        channel: 0,
+        program: 5,
+        time,
+    };
 
     let input: InputEvent = midi.into();
 
@@ -152,6 +177,7 @@ fn test_program_change_conversion() {
         InputEvent::ProgramChange {
             program,
             time: event_time,
+            ..
         } => {
             assert_eq!(program, 5, "Program number should be preserved");
             assert_eq!(event_time, time, "Timestamp should be preserved");
@@ -166,6 +192,7 @@ fn test_velocity_range_preservation() {
 
     // Test minimum velocity (0)
     let midi_min = MidiEvent::NoteOn {
+        channel: 0,
         note: 1,
         velocity: 0,
         time,
@@ -179,6 +206,7 @@ fn test_velocity_range_preservation() {
 
     // Test medium velocity (64)
     let midi_med = MidiEvent::NoteOn {
+        channel: 0,
         note: 1,
         velocity: 64,
         time,
@@ -192,6 +220,7 @@ fn test_velocity_range_preservation() {
 
     // Test maximum velocity (127)
     let midi_max = MidiEvent::NoteOn {
+        channel: 0,
         note: 1,
         velocity: 127,
         time,
@@ -209,7 +238,11 @@ fn test_pitch_bend_range_preservation() {
     let time = Instant::now();
 
     // Test minimum pitch bend (0 = full down)
-    let midi_min = MidiEvent::PitchBend { value: 0, time };
+    let midi_min = MidiEvent::PitchBend {
+        channel: 0,
+        value: 0,
+        time,
+    };
     let input_min: InputEvent = midi_min.into();
     if let InputEvent::PitchBend { value, .. } = input_min {
         assert_eq!(value, 0, "Minimum pitch bend should be preserved");
@@ -218,7 +251,11 @@ fn test_pitch_bend_range_preservation() {
     }
 
     // Test center pitch bend (8192 = neutral)
-    let midi_center = MidiEvent::PitchBend { value: 8192, time };
+    let midi_center = MidiEvent::PitchBend {
+        channel: 0,
+        value: 8192,
+        time,
+    };
     let input_center: InputEvent = midi_center.into();
     if let InputEvent::PitchBend { value, .. } = input_center {
         assert_eq!(value, 8192, "Center pitch bend should be preserved");
@@ -227,7 +264,11 @@ fn test_pitch_bend_range_preservation() {
     }
 
     // Test maximum pitch bend (16383 = full up)
-    let midi_max = MidiEvent::PitchBend { value: 16383, time };
+    let midi_max = MidiEvent::PitchBend {
+        channel: 0,
+        value: 16383,
+        time,
+    };
     let input_max: InputEvent = midi_max.into();
     if let InputEvent::PitchBend { value, .. } = input_max {
         assert_eq!(value, 16383, "Maximum pitch bend should be preserved");
@@ -236,31 +277,71 @@ fn test_pitch_bend_range_preservation() {
     }
 }
 
+#[test]
+fn test_channel_preservation() {
+    // Note On on MIDI channel 10 (zero-based 9)
+    let midi = MidiEvent::from_midi_msg(&[0x99, 36, 100]).expect("valid Note On");
+    assert_eq!(
+        midi.channel(),
+        9,
+        "Channel should be parsed from status byte"
+    );
+
+    let input: InputEvent = midi.into();
+    assert_eq!(input.channel(), 9, "Channel should survive conversion");
+    assert!(matches!(input, InputEvent::PadPressed { pad: 36, .. }));
+
+    // Pitch bend on channel 16 (zero-based 15)
+    let midi = MidiEvent::from_midi_msg(&[0xEF, 0x00, 0x40]).expect("valid Pitch Bend");
+    let input: InputEvent = midi.into();
+    assert_eq!(input.channel(), 15);
+}
+
 #[test]
 fn test_timestamp_accessor() {
     let time = Instant::now();
 
     let events = vec![
         InputEvent::PadPressed {
+            channel: 0,
             pad: 1,
             velocity: 100,
             time,
         },
-        InputEvent::PadReleased { pad: 1, time },
+        InputEvent::PadReleased {
+            channel: 0,
+            pad: 1,
+            time,
+        },
         InputEvent::EncoderTurned {
+            channel: 0,
             encoder: 1,
             value: 64,
             time,
         },
         InputEvent::PolyPressure {
+            channel: 0,
             pad: 60,
             pressure: 90,
             time,
         },
-        InputEvent::Aftertouch { pressure: 50, time },
-        InputEvent::PitchBend { value: 8192, time },
-        InputEvent::ProgramChange { program: 1, time },
+        InputEvent::Aftertouch {
+            channel: 0,
+            pressure: 50,
+            time,
+        },
+        InputEvent::PitchBend {
+            channel: 0,
+            value: 8192,
+            time,
+        },
+        InputEvent::ProgramChange {
+            channel: 0,
+            program: 1,
+            time,
+        },
         InputEvent::ControlChange {
+            channel: 0,
             control: 7,
             value: 100,
             time,
@@ -284,15 +365,24 @@ fn test_event_type_strings() {
     let test_cases = vec![
         (
             InputEvent::PadPressed {
+                channel: 0,
                 pad: 1,
                 velocity: 100,
                 time,
             },
             "PadPressed",
         ),
-        (InputEvent::PadReleased { pad: 1, time }, "PadReleased"),
+        (
+            InputEvent::PadReleased {
+                channel: 0,
+                pad: 1,
+                time,
+            },
+            "PadReleased",
+        ),
         (
             InputEvent::EncoderTurned {
+                channel: 0,
                 encoder: 1,
                 value: 64,
                 time,
@@ -301,20 +391,40 @@ fn test_event_type_strings() {
         ),
         (
             InputEvent::PolyPressure {
+                channel: 0,
                 pad: 60,
                 pressure: 90,
                 time,
             },
             "PolyPressure",
         ),
-        (InputEvent::Aftertouch { pressure: 50, time }, "Aftertouch"),
-        (InputEvent::PitchBend { value: 8192, time }, "PitchBend"),
         (
-            InputEvent::ProgramChange { program: 1, time },
+            InputEvent::Aftertouch {
+                channel: 0,
+                pressure: 50,
+                time,
+            },
+            "Aftertouch",
+        ),
+        (
+            InputEvent::PitchBend {
+                channel: 0,
+                value: 8192,
+                time,
+            },
+            "PitchBend",
+        ),
+        (
+            InputEvent::ProgramChange {
+                channel: 0,
+                program: 1,
+                time,
+            },
             "ProgramChange",
         ),
         (
             InputEvent::ControlChange {
+                channel: 0,
                 control: 7,
                 value: 100,
                 time,
@@ -340,25 +450,30 @@ fn test_batch_conversion() {
 
     let midi_events = vec![
         MidiEvent::NoteOn {
+            channel: 0,
             note: 36,
             velocity: 100,
             time: base_time,
         },
         MidiEvent::NoteOn {
+            channel: 0,
             note: 38,
             velocity: 80,
             time: base_time,
         },
         MidiEvent::ControlChange {
+            channel: 0,
             cc: 1,
             value: 64,
             time: base_time,
         },
         MidiEvent::NoteOff {
+            channel: 0,
             note: 36,
             time: base_time,
         },
         MidiEvent::NoteOff {
+            channel: 0,
             note: 38,
             time: base_time,
         },
@@ -420,6 +535,7 @@ fn test_protocol_agnostic_field_names() {
 
     // Create a pad press (not "note on")
     let _pad_event = InputEvent::PadPressed {
+        channel: 0,
         pad: 36, // Not "note"
         velocity: 100,
         time,
@@ -427,6 +543,7 @@ fn test_protocol_agnostic_field_names() {
 
     // Create an encoder turn (not "control change")
     let _encoder_event = InputEvent::EncoderTurned {
+        channel: 0,
         encoder: 1, // Not "cc"
         value: 64,
         time,
@@ -434,6 +551,7 @@ fn test_protocol_agnostic_field_names() {
 
     // Create pressure input (not "channel pressure")
     let _pressure_event = InputEvent::Aftertouch {
+        channel: 0,
         pressure: 80, // Domain terminology
         time,
     };
@@ -446,6 +564,7 @@ fn test_control_change_variant_exists() {
     // Verify that we have a generic ControlChange variant for unmapped controls
     let time = Instant::now();
     let event = InputEvent::ControlChange {
+        channel: 0,
         control: 7,
         value: 100,
         time,
@@ -118,6 +118,7 @@ fn test_send_midi_config_to_action_conversion_note_on() {
         program: None,
         pitch: None,
         pressure: None,
+        relative: false,
     };
 
     let action: Action = action_config.into();
@@ -128,6 +129,7 @@ fn test_send_midi_config_to_action_conversion_note_on() {
             message_type,
             channel,
             params,
+            ..
         } => {
             assert_eq!(port, "IAC Driver Bus 1");
             assert_eq!(message_type, MidiMessageType::NoteOn);
@@ -157,6 +159,7 @@ fn test_send_midi_config_to_action_conversion_cc() {
         program: None,
         pitch: None,
         pressure: None,
+        relative: false,
     };
 
     let action: Action = action_config.into();
@@ -167,6 +170,7 @@ fn test_send_midi_config_to_action_conversion_cc() {
             message_type,
             channel,
             params,
+            ..
         } => {
             assert_eq!(port, "Virtual MIDI Port");
             assert_eq!(message_type, MidiMessageType::ControlChange);
@@ -196,6 +200,7 @@ fn test_send_midi_config_to_action_conversion_program_change() {
         program: Some(42),
         pitch: None,
         pressure: None,
+        relative: false,
     };
 
     let action: Action = action_config.into();
@@ -206,6 +211,7 @@ fn test_send_midi_config_to_action_conversion_program_change() {
             message_type,
             channel,
             params,
+            ..
         } => {
             assert_eq!(port, "Virtual Synth");
             assert_eq!(message_type, MidiMessageType::ProgramChange);
@@ -229,6 +235,7 @@ fn test_send_midi_config_to_action_conversion_pitch_bend() {
         program: None,
         pitch: Some(4096),
         pressure: None,
+        relative: false,
     };
 
     let action: Action = action_config.into();
@@ -239,6 +246,7 @@ fn test_send_midi_config_to_action_conversion_pitch_bend() {
             message_type,
             channel,
             params,
+            ..
         } => {
             assert_eq!(port, "Virtual Synth");
             assert_eq!(message_type, MidiMessageType::PitchBend);
@@ -262,6 +270,7 @@ fn test_send_midi_config_to_action_conversion_aftertouch() {
         program: None,
         pitch: None,
         pressure: Some(80),
+        relative: false,
     };
 
     let action: Action = action_config.into();
@@ -272,6 +281,7 @@ fn test_send_midi_config_to_action_conversion_aftertouch() {
             message_type,
             channel,
             params,
+            ..
         } => {
             assert_eq!(port, "Virtual Synth");
             assert_eq!(message_type, MidiMessageType::Aftertouch);
@@ -320,6 +330,7 @@ fn test_send_midi_message_type_parsing_variants() {
             program: None,
             pitch: None,
             pressure: None,
+            relative: false,
         };
 
         let action: Action = action_config.into();
@@ -351,6 +362,7 @@ fn test_send_midi_with_defaults() {
         program: None,
         pitch: None,
         pressure: None,
+        relative: false,
     };
 
     let action: Action = action_config.into();
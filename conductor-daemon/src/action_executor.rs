@@ -11,15 +11,21 @@
 //! - Daemon: System interaction (keyboard, mouse, shell, etc.)
 
 use crate::conditions::{ConditionContext, evaluate_condition};
+use crate::daemon::types::DaemonCommand;
 use crate::plugin_manager::PluginManager;
-use enigo::{Button, Coordinate, Direction, Enigo, Key, Keyboard, Mouse, Settings};
+use conductor_core::event_processor::ClockState;
+use enigo::{Axis, Button, Coordinate, Direction, Enigo, Key, Keyboard, Mouse, Settings};
 use conductor_core::{
-    Action, KeyCode, MidiMessageParams, MidiMessageType, MidiOutputManager, ModifierKey,
-    MouseButton, VolumeOperation,
+    Action, Condition, CyclePositions, CycleReset, KeyCode, MidiMessageParams, MidiMessageType, MidiOutputManager, ModifierKey,
+    MouseButton, VariableStore, VariableValue, VolumeOperation,
 };
+use std::collections::HashMap;
 use std::process::Command;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
 use std::thread;
 use std::time::Duration;
+use thiserror::Error;
+use tokio::sync::mpsc;
 
 /// Context about the triggering event passed to action execution
 ///
@@ -40,6 +46,18 @@ pub struct TriggerContext {
     /// Used by Conditional actions with ModeIs conditions to check if a condition
     /// should execute based on the current mode.
     pub current_mode: Option<String>,
+
+    /// Note or CC number of the triggering event
+    pub note: Option<u8>,
+
+    /// Offset of `note` within a NoteRange/CCRange trigger (0 for other triggers)
+    pub offset: Option<u8>,
+
+    /// Bytes captured by the `(??)` wildcards of a SysEx trigger
+    pub captures: Vec<u8>,
+
+    /// Transport and tempo derived from incoming MIDI clock
+    pub clock: Option<ClockState>,
 }
 
 impl TriggerContext {
@@ -48,6 +66,7 @@ impl TriggerContext {
         Self {
             velocity: Some(velocity),
             current_mode: None,
+            ..Default::default()
         }
     }
 
@@ -56,6 +75,7 @@ impl TriggerContext {
         Self {
             velocity: Some(velocity),
             current_mode: Some(mode),
+            ..Default::default()
         }
     }
 
@@ -64,6 +84,7 @@ impl TriggerContext {
         Self {
             velocity: None,
             current_mode: Some(mode),
+            ..Default::default()
         }
     }
 
@@ -71,6 +92,97 @@ impl TriggerContext {
     pub fn velocity_or_default(&self) -> u8 {
         self.velocity.unwrap_or(100)
     }
+
+    /// Placeholders that actions can reference, with their values
+    ///
+    /// For CC triggers `{note}` is the CC number and `{velocity}` the CC value;
+    /// `{cc}` and `{value}` are aliases for them. SysEx captures are
+    /// `{capture0}`, `{capture1}`, ...
+    fn placeholders(&self) -> Vec<(String, Option<u8>)> {
+        let named = [
+            ("{note}", self.note),
+            ("{cc}", self.note),
+            ("{offset}", self.offset),
+            ("{velocity}", self.velocity),
+            ("{value}", self.velocity),
+        ]
+        .map(|(name, value)| (name.to_string(), value));
+        let captures = self
+            .captures
+            .iter()
+            .enumerate()
+            .map(|(i, byte)| (format!("{{capture{}}}", i), Some(*byte)));
+
+        named.into_iter().chain(captures).collect()
+    }
+}
+
+/// Replace `{note}`, `{offset}`, `{velocity}` (and the `{cc}` and `{value}`
+/// aliases) and SysEx `{captureN}` in `text` with values from the triggering
+/// event
+///
+/// Placeholders the context has no value for are left as they are.
+///
+/// # Examples
+/// ```
+/// # use conductor_daemon::{expand_placeholders, TriggerContext};
+/// let context = TriggerContext {
+///     note: Some(40),
+///     offset: Some(4),
+///     ..TriggerContext::with_velocity(90)
+/// };
+/// assert_eq!(
+///     expand_placeholders("pad {offset} ({note}) at {velocity}", Some(&context)),
+///     "pad 4 (40) at 90"
+/// );
+/// ```
+pub fn expand_placeholders(text: &str, context: Option<&TriggerContext>) -> String {
+    let Some(context) = context else {
+        return text.to_string();
+    };
+
+    context
+        .placeholders()
+        .into_iter()
+        .fold(text.to_string(), |text, (name, value)| match value {
+            Some(value) if text.contains(&name) => text.replace(&name, &value.to_string()),
+            _ => text,
+        })
+}
+
+/// Expand placeholders in the string values of plugin params
+///
+/// A string that is exactly one placeholder becomes a number.
+fn expand_params(params: serde_json::Value, context: Option<&TriggerContext>) -> serde_json::Value {
+    use serde_json::Value;
+
+    match params {
+        Value::String(text) => {
+            let number = context.and_then(|context| {
+                context
+                    .placeholders()
+                    .into_iter()
+                    .find(|(name, _)| *name == text)
+                    .and_then(|(_, value)| value)
+            });
+            match number {
+                Some(number) => Value::from(number),
+                None => Value::String(expand_placeholders(&text, context)),
+            }
+        }
+        Value::Array(values) => Value::Array(
+            values
+                .into_iter()
+                .map(|value| expand_params(value, context))
+                .collect(),
+        ),
+        Value::Object(map) => Value::Object(
+            map.into_iter()
+                .map(|(key, value)| (key, expand_params(value, context)))
+                .collect(),
+        ),
+        other => other,
+    }
 }
 
 /// Convert domain KeyCode to enigo Key for execution
@@ -180,6 +292,50 @@ fn to_enigo_button(mouse_button: MouseButton) -> Button {
     }
 }
 
+/// An action that failed to run
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum ActionError {
+    #[error("Failed to execute command '{command}': {reason}")]
+    Shell { command: String, reason: String },
+
+    #[error("Failed to send MIDI message to '{port}': {reason}")]
+    SendMidi { port: String, reason: String },
+
+    #[error("Plugin execution error for '{plugin}': {reason}")]
+    Plugin { plugin: String, reason: String },
+
+    #[error("Failed to change mode to '{mode}': {reason}")]
+    ModeChange { mode: String, reason: String },
+
+    /// Some of the actions run by a Sequence, Parallel or Repeat failed
+    #[error("{} of {total} actions failed: {}", .errors.len(), join_errors(.errors))]
+    Several {
+        errors: Vec<ActionError>,
+        total: usize,
+    },
+}
+
+impl ActionError {
+    /// Combine the failures of `total` actions run together into one result
+    ///
+    /// A single action's failure is passed on as it is.
+    pub fn collect(errors: Vec<ActionError>, total: usize) -> Result<(), ActionError> {
+        match errors.len() {
+            0 => Ok(()),
+            1 if total == 1 => Err(errors.into_iter().next().unwrap()),
+            _ => Err(ActionError::Several { errors, total }),
+        }
+    }
+}
+
+fn join_errors(errors: &[ActionError]) -> String {
+    errors
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
 /// ActionExecutor handles the execution of actions on the host system.
 ///
 /// This includes:
@@ -194,9 +350,15 @@ fn to_enigo_button(mouse_button: MouseButton) -> Button {
 /// This executor lives in the daemon layer (not core) because it interacts
 /// with the operating system through UI libraries (enigo) and system commands.
 pub struct ActionExecutor {
-    enigo: Enigo,
-    midi_output: MidiOutputManager,
-    plugin_manager: PluginManager,
+    devices: ActionDevices,
+    /// Where ModeChange actions send the mode they request
+    commands: Option<mpsc::Sender<DaemonCommand>>,
+    /// Keys pressed by momentary mappings and not yet released, in press order
+    held_keys: Vec<Key>,
+    /// Runtime variables set by variable actions
+    variables: VariableStore,
+    /// Positions of Cycle and Toggle actions
+    cycles: CyclePositions,
 }
 
 impl Default for ActionExecutor {
@@ -209,24 +371,55 @@ impl ActionExecutor {
     /// Create a new ActionExecutor with default settings
     pub fn new() -> Self {
         Self {
-            enigo: Enigo::new(&Settings::default()).unwrap(),
-            midi_output: MidiOutputManager::new(),
-            plugin_manager: PluginManager::default(),
+            devices: ActionDevices::new(),
+            commands: None,
+            held_keys: Vec::new(),
+            variables: VariableStore::default(),
+            cycles: CyclePositions::default(),
         }
     }
 
-    /// Get a reference to the plugin manager
+    /// Lock the plugin manager
     ///
     /// Allows external code to manage plugins (discover, load, configure permissions)
-    pub fn plugin_manager(&self) -> &PluginManager {
-        &self.plugin_manager
+    pub fn plugin_manager(&self) -> MutexGuard<'_, PluginManager> {
+        self.devices.plugin_manager()
+    }
+
+    /// Get the devices actions drive, to run actions on them without the
+    /// executor
+    pub fn devices(&self) -> &ActionDevices {
+        &self.devices
+    }
+
+    /// Get the runtime variables set by variable actions
+    pub fn variables(&self) -> &VariableStore {
+        &self.variables
+    }
+
+    /// Get the runtime variables mutably, e.g. to restore persisted values
+    pub fn variables_mut(&mut self) -> &mut VariableStore {
+        &mut self.variables
     }
 
-    /// Get a mutable reference to the plugin manager
+    /// Get the positions of the Cycle and Toggle actions that have fired
+    pub fn cycles(&self) -> &CyclePositions {
+        &self.cycles
+    }
+
+    /// Send the Cycle and Toggle actions that reset on `event` (a reload or
+    /// mode change) back to their first action
+    pub fn reset_cycles(&mut self, event: CycleReset) {
+        self.cycles.reset(event);
+    }
+
+    /// Send the mode requested by ModeChange actions to `commands`
     ///
-    /// Allows external code to manage plugins (discover, load, configure permissions)
-    pub fn plugin_manager_mut(&mut self) -> &mut PluginManager {
-        &mut self.plugin_manager
+    /// ModeChange actions cannot switch modes themselves because the active mode
+    /// is owned by the engine manager, which receives them as
+    /// [`DaemonCommand::ModeChange`]. Without a sender they fail.
+    pub fn set_command_sender(&mut self, commands: mpsc::Sender<DaemonCommand>) {
+        self.commands = Some(commands);
     }
 
     /// Execute an action
@@ -251,42 +444,69 @@ impl ActionExecutor {
     /// executor.execute(action, Some(context));
     /// ```
     pub fn execute(&mut self, action: Action, context: Option<TriggerContext>) {
+        if let Err(e) = self.try_execute(action, context) {
+            eprintln!("{}", e);
+        }
+    }
+
+    /// Execute an action, returning what failed
+    ///
+    /// A failed step doesn't stop a Sequence, Parallel or Repeat: the
+    /// remaining steps still run and the failures are reported together.
+    /// Keyboard and mouse actions panic if the input simulation fails, as
+    /// in [`execute`](Self::execute).
+    ///
+    /// The actions of a Parallel run one after another here; a mapping's
+    /// action runs them alongside each other as a job (see
+    /// [`ActionJobs`](crate::action_jobs::ActionJobs)).
+    pub fn try_execute(
+        &mut self,
+        action: Action,
+        context: Option<TriggerContext>,
+    ) -> Result<(), ActionError> {
         match action {
-            Action::Keystroke { keys, modifiers } => {
-                self.execute_keystroke(keys, modifiers);
-            }
-            Action::Text(text) => {
-                self.enigo.text(&text).unwrap();
-            }
-            Action::Launch(app) => {
-                self.launch_app(&app);
-            }
-            Action::Shell(cmd) => {
-                self.execute_shell(&cmd);
+            action @ (Action::Keystroke { .. }
+            | Action::Text(_)
+            | Action::Launch(_)
+            | Action::Shell(_)
+            | Action::MouseClick { .. }
+            | Action::MouseMove { .. }
+            | Action::MouseScroll { .. }
+            | Action::VolumeControl { .. }
+            | Action::SendMidi { .. }
+            | Action::Plugin { .. }) => {
+                self.devices
+                    .execute(action, context, self.variables.values())?;
             }
             Action::Sequence(actions) => {
+                let total = actions.len();
+                let mut errors = Vec::new();
                 for act in actions {
-                    self.execute(act, context.clone());
+                    errors.extend(self.try_execute(act, context.clone()).err());
                     thread::sleep(Duration::from_millis(50));
                 }
+                ActionError::collect(errors, total)?;
+            }
+            // One after another: only jobs run the branches alongside each other
+            Action::Parallel { actions, .. } => {
+                let total = actions.len();
+                let mut errors = Vec::new();
+                for act in actions {
+                    errors.extend(self.try_execute(act, context.clone()).err());
+                }
+                ActionError::collect(errors, total)?;
             }
             Action::Delay(ms) => {
                 thread::sleep(Duration::from_millis(ms));
             }
-            Action::MouseClick { button, x, y } => {
-                if let (Some(x), Some(y)) = (x, y) {
-                    self.enigo.move_mouse(x, y, Coordinate::Abs).unwrap();
-                }
-                let enigo_button = to_enigo_button(button);
-                self.enigo.button(enigo_button, Direction::Click).unwrap();
-            }
             Action::Repeat {
                 action,
                 count,
                 delay_ms,
             } => {
+                let mut errors = Vec::new();
                 for i in 0..count {
-                    self.execute((*action).clone(), context.clone());
+                    errors.extend(self.try_execute((*action).clone(), context.clone()).err());
 
                     // Add delay between iterations (but not after the last one)
                     if i < count - 1
@@ -295,39 +515,270 @@ impl ActionExecutor {
                         thread::sleep(Duration::from_millis(delay));
                     }
                 }
+                ActionError::collect(errors, count)?;
             }
             Action::Conditional {
                 condition,
                 then_action,
                 else_action,
             } => {
-                // Create condition context from trigger context (includes current mode)
-                let cond_ctx = context
-                    .as_ref()
-                    .and_then(|ctx| ctx.current_mode.as_ref())
-                    .map(|mode| ConditionContext::with_mode(mode.clone()));
-
-                if evaluate_condition(&condition, cond_ctx.as_ref()) {
-                    self.execute((*then_action).clone(), context.clone());
+                if self.check_condition(&condition, context.as_ref()) {
+                    self.try_execute((*then_action).clone(), context.clone())?;
                 } else if let Some(else_act) = else_action {
-                    self.execute((*else_act).clone(), context);
+                    self.try_execute((*else_act).clone(), context)?;
+                }
+            }
+            Action::ModeChange { mode } => {
+                self.request_mode_change(mode)?;
+            }
+            Action::SetVariable { name, value } => {
+                self.variables.set(&name, value);
+            }
+            Action::ToggleVariable { name } => {
+                self.variables.toggle(&name);
+            }
+            Action::IncrementVariable {
+                name,
+                amount,
+                min,
+                max,
+                wrap,
+            } => {
+                self.variables.increment(&name, amount, min, max, wrap);
+            }
+            Action::Cycle {
+                id,
+                mut actions,
+                reset,
+            } => {
+                if !actions.is_empty() {
+                    let index = self.cycles.advance(&id, actions.len(), reset);
+                    self.try_execute(actions.swap_remove(index), context)?;
+                }
+            }
+            Action::CancelAction { .. } => {
+                // Running actions are background jobs, cancelled by the
+                // ActionJobs that runs them; a directly executed action has
+                // nothing to cancel
+            }
+        }
+        Ok(())
+    }
+
+    /// Evaluate a Conditional's condition against the trigger context
+    /// (current mode and clock) and the variables
+    pub fn check_condition(&self, condition: &Condition, context: Option<&TriggerContext>) -> bool {
+        let cond_ctx = ConditionContext {
+            current_mode: context.and_then(|ctx| ctx.current_mode.clone()),
+            clock: context.and_then(|ctx| ctx.clock),
+            variables: self.variables.clone(),
+        };
+        evaluate_condition(condition, Some(&cond_ctx))
+    }
+
+    /// Step Cycle `id`, returning the index of the action to run now
+    pub fn advance_cycle(&mut self, id: &str, length: usize, reset: CycleReset) -> usize {
+        self.cycles.advance(id, length, reset)
+    }
+
+    /// Press and hold a keystroke's modifiers and keys (momentary mappings)
+    ///
+    /// The keys stay down until [`ActionExecutor::key_up`] or
+    /// [`ActionExecutor::release_held_keys`] is called.
+    pub fn key_down(&mut self, keys: &[KeyCode], modifiers: &[ModifierKey]) {
+        let enigo_keys = modifiers
+            .iter()
+            .map(|&m| to_enigo_modifier(m))
+            .chain(keys.iter().map(|&k| to_enigo_key(k)));
+
+        for key in enigo_keys {
+            if let Err(e) = self.devices.enigo().key(key, Direction::Press) {
+                eprintln!("Failed to press key {:?}: {}", key, e);
+                continue;
+            }
+            self.held_keys.push(key);
+        }
+    }
+
+    /// Release keys pressed by [`ActionExecutor::key_down`], in reverse order
+    ///
+    /// A key that another momentary mapping still holds stays down.
+    pub fn key_up(&mut self, keys: &[KeyCode], modifiers: &[ModifierKey]) {
+        let enigo_keys: Vec<Key> = modifiers
+            .iter()
+            .map(|&m| to_enigo_modifier(m))
+            .chain(keys.iter().map(|&k| to_enigo_key(k)))
+            .collect();
+
+        for key in enigo_keys.into_iter().rev() {
+            if let Some(pos) = self.held_keys.iter().rposition(|held| *held == key) {
+                self.held_keys.remove(pos);
+                self.release_key_if_unheld(key);
+            }
+        }
+    }
+
+    /// Release every key still held by momentary mappings
+    ///
+    /// Used on device disconnect and shutdown so no key is left stuck down.
+    pub fn release_held_keys(&mut self) {
+        while let Some(key) = self.held_keys.pop() {
+            self.release_key_if_unheld(key);
+        }
+    }
+
+    /// Send a key release unless another momentary mapping still holds the key
+    fn release_key_if_unheld(&mut self, key: Key) {
+        if !self.held_keys.contains(&key)
+            && let Err(e) = self.devices.enigo().key(key, Direction::Release)
+        {
+            eprintln!("Failed to release key {:?}: {}", key, e);
+        }
+    }
+
+    /// Ask the engine manager, which owns the active mode, to switch to `mode`
+    fn request_mode_change(&self, mode: String) -> Result<(), ActionError> {
+        let Some(commands) = &self.commands else {
+            return Err(ActionError::ModeChange {
+                mode,
+                reason: "no engine to apply it".to_string(),
+            });
+        };
+        commands
+            .try_send(DaemonCommand::ModeChange(mode.clone()))
+            .map_err(|e| ActionError::ModeChange {
+                mode,
+                reason: e.to_string(),
+            })
+    }
+}
+
+/// The devices actions drive: keyboard and mouse, MIDI output and plugins
+///
+/// Each device has its own lock, and cloning gives another handle to the same
+/// devices, so actions on different devices can run at the same time without
+/// holding the [`ActionExecutor`] (see
+/// [`ActionJobs`](crate::action_jobs::ActionJobs)).
+#[derive(Clone)]
+pub struct ActionDevices {
+    enigo: Arc<Mutex<Enigo>>,
+    midi_output: Arc<Mutex<MidiOutputManager>>,
+    plugin_manager: Arc<Mutex<PluginManager>>,
+}
+
+impl ActionDevices {
+    fn new() -> Self {
+        Self {
+            enigo: Arc::new(Mutex::new(Enigo::new(&Settings::default()).unwrap())),
+            midi_output: Arc::new(Mutex::new(MidiOutputManager::new())),
+            plugin_manager: Arc::new(Mutex::new(PluginManager::default())),
+        }
+    }
+
+    /// Whether `action` only drives the devices, so it can run with
+    /// [`execute`](Self::execute)
+    pub fn drives(action: &Action) -> bool {
+        matches!(
+            action,
+            Action::Keystroke { .. }
+                | Action::Text(_)
+                | Action::Launch(_)
+                | Action::Shell(_)
+                | Action::MouseClick { .. }
+                | Action::MouseMove { .. }
+                | Action::MouseScroll { .. }
+                | Action::VolumeControl { .. }
+                | Action::SendMidi { .. }
+                | Action::Plugin { .. }
+        )
+    }
+
+    /// Run an action that [`drives`](Self::drives) the devices, blocking
+    /// until it's done
+    ///
+    /// Plugins get `variables` in their trigger context. Other actions are
+    /// ignored. Keyboard and mouse actions panic if the input simulation
+    /// fails, as in [`ActionExecutor::execute`].
+    pub fn execute(
+        &self,
+        action: Action,
+        context: Option<TriggerContext>,
+        variables: &HashMap<String, VariableValue>,
+    ) -> Result<(), ActionError> {
+        match action {
+            Action::Keystroke { keys, modifiers } => {
+                self.execute_keystroke(keys, modifiers);
+            }
+            Action::Text(text) => {
+                let text = expand_placeholders(&text, context.as_ref());
+                self.enigo().text(&text).unwrap();
+            }
+            Action::Launch(app) => {
+                self.launch_app(&app);
+            }
+            Action::Shell(cmd) => {
+                self.execute_shell(&expand_placeholders(&cmd, context.as_ref()))?;
+            }
+            Action::MouseClick { button, x, y } => {
+                let mut enigo = self.enigo();
+                if let (Some(x), Some(y)) = (x, y) {
+                    enigo.move_mouse(x, y, Coordinate::Abs).unwrap();
+                }
+                let enigo_button = to_enigo_button(button);
+                enigo.button(enigo_button, Direction::Click).unwrap();
+            }
+            Action::MouseMove { x, y, relative } => {
+                if relative {
+                    self.enigo()
+                        .move_mouse(x.unwrap_or(0), y.unwrap_or(0), Coordinate::Rel)
+                        .unwrap();
+                } else {
+                    // A missing coordinate stays where the pointer is
+                    let mut enigo = self.enigo();
+                    let (current_x, current_y) = enigo.location().unwrap_or((0, 0));
+                    enigo
+                        .move_mouse(
+                            x.unwrap_or(current_x),
+                            y.unwrap_or(current_y),
+                            Coordinate::Abs,
+                        )
+                        .unwrap();
+                }
+            }
+            Action::MouseScroll { amount, horizontal } => {
+                let axis = if horizontal {
+                    Axis::Horizontal
+                } else {
+                    Axis::Vertical
+                };
+                if amount != 0 {
+                    self.enigo().scroll(amount, axis).unwrap();
                 }
             }
             Action::VolumeControl { operation, value } => {
                 execute_volume_control(&operation, &value);
             }
-            Action::ModeChange { mode } => {
-                // Mode changes are handled by the mapping engine
-                // This action just serves as a marker in the config
-                eprintln!("ModeChange to '{}' (handled by mapping engine)", mode);
-            }
             Action::SendMidi {
                 port,
                 message_type,
                 channel,
                 params,
+                relative,
             } => {
-                self.execute_send_midi(&port, &message_type, channel, &params, context.as_ref());
+                // Relative messages shift the note/controller by the range offset
+                let offset = context
+                    .as_ref()
+                    .and_then(|ctx| ctx.offset)
+                    .filter(|_| relative)
+                    .unwrap_or(0);
+                self.execute_send_midi(
+                    &port,
+                    &message_type,
+                    channel,
+                    &params,
+                    offset,
+                    context.as_ref(),
+                )?;
             }
             Action::Plugin { plugin, params } => {
                 // Convert TriggerContext from daemon to plugin TriggerContext
@@ -339,44 +790,67 @@ impl ActionExecutor {
                             .duration_since(std::time::UNIX_EPOCH)
                             .unwrap()
                             .as_millis() as u64,
+                        variables: variables.clone(),
                     }
                 });
 
                 // Execute plugin
-                match self
-                    .plugin_manager
+                let params = expand_params(params, context.as_ref());
+                self.plugin_manager()
                     .execute_plugin(&plugin, params, plugin_context)
-                {
-                    Ok(()) => {
-                        // Plugin executed successfully
-                    }
-                    Err(e) => {
-                        eprintln!("Plugin execution error for '{}': {}", plugin, e);
-                    }
-                }
+                    .map_err(|e| ActionError::Plugin {
+                        plugin: plugin.clone(),
+                        reason: e.to_string(),
+                    })?;
             }
+            _ => {}
         }
+        Ok(())
+    }
+
+    /// Lock the plugin manager
+    pub fn plugin_manager(&self) -> MutexGuard<'_, PluginManager> {
+        self.plugin_manager
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Lock the MIDI output ports
+    pub fn midi_output(&self) -> MutexGuard<'_, MidiOutputManager> {
+        self.midi_output
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Lock the keyboard and mouse
+    ///
+    /// A keyboard or mouse action that panicked leaves the lock poisoned,
+    /// which doesn't stop later actions.
+    fn enigo(&self) -> MutexGuard<'_, Enigo> {
+        self.enigo.lock().unwrap_or_else(PoisonError::into_inner)
     }
 
     /// Execute a keystroke with modifiers
     ///
     /// Converts domain types (KeyCode, ModifierKey) to platform-specific enigo types.
-    fn execute_keystroke(&mut self, keys: Vec<KeyCode>, modifiers: Vec<ModifierKey>) {
+    fn execute_keystroke(&self, keys: Vec<KeyCode>, modifiers: Vec<ModifierKey>) {
+        let mut enigo = self.enigo();
+
         // Convert and press modifiers
         let enigo_modifiers: Vec<Key> = modifiers.iter().map(|&m| to_enigo_modifier(m)).collect();
         for modifier in &enigo_modifiers {
-            self.enigo.key(*modifier, Direction::Press).unwrap();
+            enigo.key(*modifier, Direction::Press).unwrap();
         }
 
         // Convert and press keys
         for key_code in &keys {
             let enigo_key = to_enigo_key(*key_code);
-            self.enigo.key(enigo_key, Direction::Click).unwrap();
+            enigo.key(enigo_key, Direction::Click).unwrap();
         }
 
         // Release modifiers
         for modifier in enigo_modifiers.iter().rev() {
-            self.enigo.key(*modifier, Direction::Release).unwrap();
+            enigo.key(*modifier, Direction::Release).unwrap();
         }
     }
 
@@ -434,13 +908,16 @@ impl ActionExecutor {
     /// - "git add . && git commit" → Contains &&
     /// - "ls | grep txt" → Contains |
     /// - "cat file.txt > output.txt" → Contains >
-    fn execute_shell(&self, cmd: &str) {
+    fn execute_shell(&self, cmd: &str) -> Result<(), ActionError> {
         let cmd = cmd.trim();
+        let failed = |reason: &str| ActionError::Shell {
+            command: cmd.to_string(),
+            reason: reason.to_string(),
+        };
 
         // Handle empty command
         if cmd.is_empty() {
-            eprintln!("Warning: Attempted to execute empty shell command");
-            return;
+            return Err(failed("empty command"));
         }
 
         // Parse command into program + arguments
@@ -448,8 +925,7 @@ impl ActionExecutor {
         let parts = parse_command_line(cmd);
 
         if parts.is_empty() {
-            eprintln!("Warning: Failed to parse shell command: {}", cmd);
-            return;
+            return Err(failed("could not parse command"));
         }
 
         let program = &parts[0];
@@ -457,14 +933,12 @@ impl ActionExecutor {
 
         // Execute command WITHOUT shell interpreter
         // This is the critical security improvement: no sh -c, no cmd /C
-        match Command::new(program).args(args).spawn() {
-            Ok(_) => {
-                // Command spawned successfully (runs in background)
-            }
-            Err(e) => {
-                eprintln!("Failed to execute command '{}': {}", cmd, e);
-            }
-        }
+        // The command runs in the background once spawned
+        Command::new(program)
+            .args(args)
+            .spawn()
+            .map(|_| ())
+            .map_err(|e| failed(&e.to_string()))
     }
 
     /// Execute SendMIDI action
@@ -476,6 +950,7 @@ impl ActionExecutor {
     /// * `message_type` - Type of MIDI message to send
     /// * `channel` - MIDI channel (0-15)
     /// * `params` - Message-specific parameters
+    /// * `offset` - Added to the note (Note messages) or controller (CC messages)
     /// * `context` - Optional trigger context (contains velocity from triggering event)
     ///
     /// # MIDI Message Format
@@ -483,13 +958,14 @@ impl ActionExecutor {
     /// - Status byte: 0x80-0xE0 | channel (0-15)
     /// - Data bytes: 0-127 (7-bit values)
     fn execute_send_midi(
-        &mut self,
+        &self,
         port: &str,
         message_type: &MidiMessageType,
         channel: u8,
         params: &MidiMessageParams,
+        offset: u8,
         context: Option<&TriggerContext>,
-    ) {
+    ) -> Result<(), ActionError> {
         // Build MIDI message bytes based on message type
         let message_bytes = match (message_type, params) {
             (
@@ -506,7 +982,7 @@ impl ActionExecutor {
                     conductor_core::velocity::calculate_velocity(trigger_velocity, velocity_mapping);
                 vec![
                     0x90 | (channel & 0x0F),
-                    *note & 0x7F,
+                    note.saturating_add(offset).min(0x7F),
                     calculated_velocity & 0x7F,
                 ]
             }
@@ -524,12 +1000,16 @@ impl ActionExecutor {
                     conductor_core::velocity::calculate_velocity(trigger_velocity, velocity_mapping);
                 vec![
                     0x80 | (channel & 0x0F),
-                    *note & 0x7F,
+                    note.saturating_add(offset).min(0x7F),
                     calculated_velocity & 0x7F,
                 ]
             }
             (MidiMessageType::ControlChange, MidiMessageParams::CC { controller, value }) => {
-                vec![0xB0 | (channel & 0x0F), *controller & 0x7F, *value & 0x7F]
+                vec![
+                    0xB0 | (channel & 0x0F),
+                    controller.saturating_add(offset).min(0x7F),
+                    *value & 0x7F,
+                ]
             }
             (MidiMessageType::ProgramChange, MidiMessageParams::ProgramChange { program }) => {
                 vec![0xC0 | (channel & 0x0F), *program & 0x7F]
@@ -545,23 +1025,23 @@ impl ActionExecutor {
                 vec![0xD0 | (channel & 0x0F), *pressure & 0x7F]
             }
             _ => {
-                eprintln!(
-                    "Warning: Mismatched MIDI message type {:?} and params {:?}",
-                    message_type, params
-                );
-                return;
+                return Err(ActionError::SendMidi {
+                    port: port.to_string(),
+                    reason: format!(
+                        "mismatched message type {:?} and params {:?}",
+                        message_type, params
+                    ),
+                });
             }
         };
 
         // Send message via MidiOutputManager
-        match self.midi_output.send_message(port, &message_bytes) {
-            Ok(_) => {
-                // Success - message sent
-            }
-            Err(e) => {
-                eprintln!("Failed to send MIDI message to '{}': {}", port, e);
-            }
-        }
+        self.midi_output()
+            .send_message(port, &message_bytes)
+            .map_err(|e| ActionError::SendMidi {
+                port: port.to_string(),
+                reason: e.to_string(),
+            })
     }
 }
 
@@ -840,6 +1320,95 @@ mod tests {
         assert_eq!(parse_command_line("ls *.txt"), vec!["ls", "*.txt"]);
     }
 
+    // ========== Placeholder Tests ==========
+
+    fn range_context() -> TriggerContext {
+        TriggerContext {
+            note: Some(40),
+            offset: Some(4),
+            ..TriggerContext::with_velocity(90)
+        }
+    }
+
+    #[test]
+    fn test_expand_placeholders() {
+        let context = range_context();
+        assert_eq!(
+            expand_placeholders("echo {note} {offset} {velocity}", Some(&context)),
+            "echo 40 4 90"
+        );
+        assert_eq!(
+            expand_placeholders("cc {cc}={value}", Some(&context)),
+            "cc 40=90"
+        );
+    }
+
+    #[test]
+    fn test_expand_sysex_captures() {
+        let context = TriggerContext {
+            captures: vec![0x02, 0x7F],
+            ..Default::default()
+        };
+        assert_eq!(
+            expand_placeholders("mmc {capture0} {capture1} {capture2}", Some(&context)),
+            "mmc 2 127 {capture2}"
+        );
+    }
+
+    #[test]
+    fn test_expand_placeholders_without_values() {
+        assert_eq!(expand_placeholders("pad {note}", None), "pad {note}");
+
+        let context = TriggerContext::with_velocity(90);
+        assert_eq!(
+            expand_placeholders("pad {note} at {velocity}", Some(&context)),
+            "pad {note} at 90"
+        );
+    }
+
+    #[test]
+    fn test_action_error_collect() {
+        let shell = |command: &str| ActionError::Shell {
+            command: command.to_string(),
+            reason: "not found".to_string(),
+        };
+
+        assert_eq!(ActionError::collect(Vec::new(), 3), Ok(()));
+        // A single action's failure is passed on unchanged
+        assert_eq!(
+            ActionError::collect(vec![shell("obs")], 1),
+            Err(shell("obs"))
+        );
+
+        let error = ActionError::collect(vec![shell("obs"), shell("vlc")], 3).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "2 of 3 actions failed: Failed to execute command 'obs': not found; \
+             Failed to execute command 'vlc': not found"
+        );
+    }
+
+    #[test]
+    fn test_expand_params() {
+        let context = range_context();
+        let params = serde_json::json!({
+            "track": "{offset}",
+            "label": "Pad {note}",
+            "levels": ["{velocity}", 1],
+            "enabled": true,
+        });
+
+        assert_eq!(
+            expand_params(params, Some(&context)),
+            serde_json::json!({
+                "track": 4,
+                "label": "Pad 40",
+                "levels": [90, 1],
+                "enabled": true,
+            })
+        );
+    }
+
     // ========== SendMidi Action Tests ==========
 
     #[test]
@@ -859,6 +1428,7 @@ mod tests {
                 note: 60,
                 velocity_mapping: conductor_core::VelocityMapping::Fixed { velocity: 100 },
             },
+            relative: false,
         };
 
         // Execute shouldn't panic (though send will fail without a port)
@@ -879,6 +1449,7 @@ mod tests {
                 note: 64,
                 velocity_mapping: conductor_core::VelocityMapping::Fixed { velocity: 0 },
             },
+            relative: false,
         };
 
         executor.execute(action, None);
@@ -898,6 +1469,7 @@ mod tests {
                 controller: 7,
                 value: 127,
             },
+            relative: false,
         };
 
         executor.execute(action, None);
@@ -914,6 +1486,7 @@ mod tests {
             message_type: MidiMessageType::ProgramChange,
             channel: 3,
             params: MidiMessageParams::ProgramChange { program: 42 },
+            relative: false,
         };
 
         executor.execute(action, None);
@@ -931,6 +1504,7 @@ mod tests {
             message_type: MidiMessageType::PitchBend,
             channel: 4,
             params: MidiMessageParams::PitchBend { value: 0 },
+            relative: false,
         };
 
         executor.execute(action, None);
@@ -948,6 +1522,7 @@ mod tests {
             message_type: MidiMessageType::PitchBend,
             channel: 5,
             params: MidiMessageParams::PitchBend { value: -8192 },
+            relative: false,
         };
         executor.execute(action_min, None);
 
@@ -957,6 +1532,7 @@ mod tests {
             message_type: MidiMessageType::PitchBend,
             channel: 5,
             params: MidiMessageParams::PitchBend { value: 8191 },
+            relative: false,
         };
         executor.execute(action_max, None);
     }
@@ -972,6 +1548,7 @@ mod tests {
             message_type: MidiMessageType::Aftertouch,
             channel: 6,
             params: MidiMessageParams::Aftertouch { pressure: 80 },
+            relative: false,
         };
 
         executor.execute(action, None);
@@ -993,6 +1570,7 @@ mod tests {
                     note: 60,
                     velocity_mapping: conductor_core::VelocityMapping::Fixed { velocity: 100 },
                 },
+                relative: false,
             };
 
             executor.execute(action, None);
@@ -1018,6 +1596,7 @@ mod tests {
                         note,
                         velocity_mapping: conductor_core::VelocityMapping::Fixed { velocity },
                     },
+                    relative: false,
                 };
 
                 executor.execute(action, None);
@@ -1047,6 +1626,7 @@ mod tests {
                 note: 60,
                 velocity_mapping: conductor_core::VelocityMapping::Fixed { velocity: 100 },
             },
+            relative: false,
         };
 
         executor.execute(action, None);
@@ -1068,6 +1648,7 @@ mod tests {
                     note: 60,
                     velocity_mapping: VelocityMapping::Fixed { velocity: 100 },
                 },
+                relative: false,
             },
             conductor_core::Action::Delay(10),
             conductor_core::Action::SendMidi {
@@ -1078,6 +1659,7 @@ mod tests {
                     note: 60,
                     velocity_mapping: VelocityMapping::Fixed { velocity: 0 },
                 },
+                relative: false,
             },
         ]);
 
@@ -1100,6 +1682,7 @@ mod tests {
                     controller: 1,
                     value: 64,
                 },
+                relative: false,
             }),
             count: 3,
             delay_ms: Some(50),
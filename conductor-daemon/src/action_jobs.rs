@@ -0,0 +1,715 @@
+// Copyright 2025 Amiable
+// SPDX-License-Identifier: MIT
+
+//! Background jobs running mapping actions
+//!
+//! Each fired mapping runs its action as a job on its own task, so a long
+//! `Sequence`, `Repeat` or `Delay` doesn't hold up input processing. A job
+//! can be cancelled by a `CancelAction` action, over IPC, or by its mapping's
+//! concurrency policy; it stops before its next step or during a delay.
+//! Single actions (keystrokes, shell commands, plugins) run to completion on
+//! a blocking thread, holding only the device they drive, so the branches of
+//! a `Parallel` run at the same time unless they use the same device.
+
+use crate::action_executor::{ActionDevices, ActionError, ActionExecutor, TriggerContext};
+use crate::daemon::types::DaemonCommand;
+use conductor_core::{Action, ConcurrencyPolicy};
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, PoisonError};
+use std::time::{Duration, Instant};
+use tokio::sync::{Mutex, mpsc, watch};
+use tokio::task::JoinSet;
+use tracing::{debug, warn};
+
+/// Pause after each step of a Sequence, as in [`ActionExecutor::execute`]
+const SEQUENCE_STEP_DELAY: Duration = Duration::from_millis(50);
+
+/// A running or queued job, as listed over IPC
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct JobInfo {
+    pub id: u64,
+    /// Id of the mapping that started the job, e.g. `Default/3`
+    pub mapping: String,
+    pub description: Option<String>,
+    /// False while the job waits for the mapping's previous job to finish
+    pub running: bool,
+    /// Time since the mapping fired, in milliseconds
+    pub elapsed_ms: u64,
+}
+
+struct Job {
+    mapping: String,
+    description: Option<String>,
+    running: bool,
+    started: Instant,
+    cancel: watch::Sender<bool>,
+}
+
+impl Job {
+    fn is_cancelled(&self) -> bool {
+        *self.cancel.borrow()
+    }
+
+    /// Whether `name` is the id or description of the job's mapping
+    fn matches(&self, name: &str) -> bool {
+        self.mapping == name || self.description.as_deref() == Some(name)
+    }
+}
+
+/// A job accepted by [`JobTable::admit`]
+pub struct AdmittedJob {
+    pub id: u64,
+    /// Changes to true when the job is cancelled
+    pub cancelled: watch::Receiver<bool>,
+    /// Held while the job runs, so a mapping's jobs run one at a time
+    pub turn: Arc<Mutex<()>>,
+}
+
+/// Ids, concurrency policies and cancellation of jobs
+///
+/// A job stays in the table until its task finishes, even once cancelled, but
+/// cancelled jobs are neither listed nor count as running.
+#[derive(Default)]
+pub struct JobTable {
+    jobs: BTreeMap<u64, Job>,
+    /// Lock per mapping id, see [`AdmittedJob::turn`]
+    turns: HashMap<String, Arc<Mutex<()>>>,
+    next_id: u64,
+}
+
+impl JobTable {
+    /// Register a job for `mapping`, applying `policy` to the mapping's
+    /// running jobs
+    ///
+    /// Returns None when the policy drops the trigger.
+    pub fn admit(
+        &mut self,
+        mapping: &str,
+        description: Option<String>,
+        policy: ConcurrencyPolicy,
+    ) -> Option<AdmittedJob> {
+        let busy = self
+            .jobs
+            .values()
+            .any(|job| job.mapping == mapping && !job.is_cancelled());
+        if busy {
+            match policy {
+                ConcurrencyPolicy::Queue => {}
+                ConcurrencyPolicy::Restart => {
+                    self.cancel_mapping(mapping);
+                }
+                ConcurrencyPolicy::IgnoreWhileRunning => return None,
+                ConcurrencyPolicy::CancelOnRetrigger => {
+                    self.cancel_mapping(mapping);
+                    return None;
+                }
+            }
+        }
+
+        self.next_id += 1;
+        let (cancel, cancelled) = watch::channel(false);
+        self.jobs.insert(
+            self.next_id,
+            Job {
+                mapping: mapping.to_string(),
+                description,
+                running: false,
+                started: Instant::now(),
+                cancel,
+            },
+        );
+        Some(AdmittedJob {
+            id: self.next_id,
+            cancelled,
+            turn: self.turns.entry(mapping.to_string()).or_default().clone(),
+        })
+    }
+
+    /// Mark job `id` as running once it has its mapping's turn
+    pub fn start(&mut self, id: u64) {
+        if let Some(job) = self.jobs.get_mut(&id) {
+            job.running = true;
+        }
+    }
+
+    /// Remove job `id` once its task has finished
+    pub fn finish(&mut self, id: u64) {
+        self.jobs.remove(&id);
+    }
+
+    /// Cancel job `id`, returning false if there's no such job
+    pub fn cancel(&mut self, id: u64) -> bool {
+        match self.jobs.get(&id) {
+            Some(job) if !job.is_cancelled() => {
+                job.cancel.send_replace(true);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Cancel the jobs of the mapping with id or description `mapping`, or
+    /// every job with None, except job `except`
+    ///
+    /// Returns the ids of the jobs cancelled.
+    pub fn cancel_matching(&mut self, mapping: Option<&str>, except: Option<u64>) -> Vec<u64> {
+        let ids: Vec<u64> = self
+            .jobs
+            .iter()
+            .filter(|(id, job)| {
+                Some(**id) != except
+                    && !job.is_cancelled()
+                    && mapping.is_none_or(|mapping| job.matches(mapping))
+            })
+            .map(|(id, _)| *id)
+            .collect();
+        for id in &ids {
+            self.cancel(*id);
+        }
+        ids
+    }
+
+    fn cancel_mapping(&mut self, mapping: &str) {
+        for job in self.jobs.values().filter(|job| job.mapping == mapping) {
+            job.cancel.send_replace(true);
+        }
+    }
+
+    /// The jobs that are running or queued, oldest first
+    pub fn list(&self) -> Vec<JobInfo> {
+        self.jobs
+            .iter()
+            .filter(|(_, job)| !job.is_cancelled())
+            .map(|(id, job)| JobInfo {
+                id: *id,
+                mapping: job.mapping.clone(),
+                description: job.description.clone(),
+                running: job.running,
+                elapsed_ms: job.started.elapsed().as_millis() as u64,
+            })
+            .collect()
+    }
+}
+
+/// Runs mapping actions as background jobs on the shared [`ActionExecutor`]
+///
+/// Cloning gives another handle to the same jobs.
+#[derive(Clone)]
+pub struct ActionJobs {
+    executor: Arc<Mutex<ActionExecutor>>,
+    table: Arc<std::sync::Mutex<JobTable>>,
+    /// Receives the mode changes requested by ModeChange actions, which the
+    /// engine manager applies
+    command_tx: mpsc::Sender<DaemonCommand>,
+}
+
+impl ActionJobs {
+    /// Create the job runner for `executor`
+    pub fn new(
+        executor: Arc<Mutex<ActionExecutor>>,
+        command_tx: mpsc::Sender<DaemonCommand>,
+    ) -> Self {
+        Self {
+            executor,
+            table: Arc::new(std::sync::Mutex::new(JobTable::default())),
+            command_tx,
+        }
+    }
+
+    /// Start a job running `action` for the mapping with id `mapping`
+    ///
+    /// Returns the job's id, or None when the mapping's concurrency policy
+    /// drops the trigger. Must be called within a Tokio runtime.
+    pub fn spawn(
+        &self,
+        mapping: &str,
+        description: Option<String>,
+        policy: ConcurrencyPolicy,
+        action: Action,
+        context: Option<TriggerContext>,
+    ) -> Option<u64> {
+        let Some(job) = self.table().admit(mapping, description, policy) else {
+            debug!(
+                "Trigger of mapping {} dropped by its {:?} policy",
+                mapping, policy
+            );
+            return None;
+        };
+        let id = job.id;
+        let jobs = self.clone();
+        let mapping = mapping.to_string();
+        tokio::spawn(async move { jobs.run_job(job, mapping, action, context).await });
+        Some(id)
+    }
+
+    /// The jobs that are running or queued, oldest first
+    pub fn list(&self) -> Vec<JobInfo> {
+        self.table().list()
+    }
+
+    /// Cancel job `id`, returning false if there's no such job
+    pub fn cancel(&self, id: u64) -> bool {
+        self.table().cancel(id)
+    }
+
+    /// Cancel every job, returning the ids of the jobs cancelled
+    pub fn cancel_all(&self) -> Vec<u64> {
+        self.table().cancel_matching(None, None)
+    }
+
+    fn table(&self) -> std::sync::MutexGuard<'_, JobTable> {
+        self.table.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    async fn run_job(
+        self,
+        job: AdmittedJob,
+        mapping: String,
+        action: Action,
+        context: Option<TriggerContext>,
+    ) {
+        // Removes the job from the table however its task ends, including a
+        // panicking action
+        let finish = FinishJob {
+            table: self.table.clone(),
+            id: job.id,
+        };
+        let (detached_tx, mut detached) = mpsc::unbounded_channel();
+        let mut run = JobRun {
+            id: job.id,
+            cancelled: job.cancelled,
+            detached: detached_tx,
+        };
+
+        // Wait for the mapping's previous job, unless cancelled while queued
+        let turn = tokio::select! {
+            turn = job.turn.lock_owned() => Some(turn),
+            _ = run.cancelled.wait_for(|cancelled| *cancelled) => None,
+        };
+
+        let mut errors = Vec::new();
+        let mut total = 0;
+        if turn.is_some() {
+            self.table().start(job.id);
+            total += 1;
+            errors.extend(self.run(action, context, &mut run).await.err());
+        }
+
+        // Actions a Parallel started without waiting belong to the job until
+        // they finish
+        drop(run);
+        while let Some(result) = detached.recv().await {
+            total += 1;
+            errors.extend(result.err());
+        }
+        drop(finish);
+
+        if let Err(e) = ActionError::collect(errors, total) {
+            warn!("Action of mapping {} failed: {}", mapping, e);
+            let _ = self
+                .command_tx
+                .send(DaemonCommand::ActionFailed {
+                    mapping,
+                    error: e.to_string(),
+                })
+                .await;
+        }
+    }
+
+    /// Run an action, stepping through nested actions so a cancellation
+    /// takes effect between steps
+    ///
+    /// A cancelled action isn't a failure; failures of the steps that ran
+    /// before are still reported.
+    fn run<'a>(
+        &'a self,
+        action: Action,
+        context: Option<TriggerContext>,
+        run: &'a mut JobRun,
+    ) -> Pin<Box<dyn Future<Output = Result<(), ActionError>> + Send + 'a>> {
+        Box::pin(async move {
+            if *run.cancelled.borrow() {
+                return Ok(());
+            }
+
+            match action {
+                Action::Sequence(actions) => {
+                    let total = actions.len();
+                    let mut errors = Vec::new();
+                    for action in actions {
+                        errors.extend(self.run(action, context.clone(), run).await.err());
+                        if !pause(&mut run.cancelled, SEQUENCE_STEP_DELAY).await {
+                            break;
+                        }
+                    }
+                    ActionError::collect(errors, total)
+                }
+                Action::Parallel { actions, wait } => {
+                    let total = actions.len();
+                    let mut branches = JoinSet::new();
+                    for action in actions {
+                        let jobs = self.clone();
+                        let context = context.clone();
+                        let mut branch = run.clone();
+                        if wait {
+                            branches
+                                .spawn(async move { jobs.run(action, context, &mut branch).await });
+                        } else {
+                            tokio::spawn(async move {
+                                let result = jobs.run(action, context, &mut branch).await;
+                                let _ = branch.detached.send(result);
+                            });
+                        }
+                    }
+
+                    let mut errors = Vec::new();
+                    while let Some(result) = branches.join_next().await {
+                        match result {
+                            Ok(result) => errors.extend(result.err()),
+                            // Keyboard and mouse failures panic, as they do
+                            // outside a Parallel
+                            Err(e) if e.is_panic() => std::panic::resume_unwind(e.into_panic()),
+                            Err(_) => {}
+                        }
+                    }
+                    if wait {
+                        ActionError::collect(errors, total)
+                    } else {
+                        Ok(())
+                    }
+                }
+                Action::Delay(ms) => {
+                    pause(&mut run.cancelled, Duration::from_millis(ms)).await;
+                    Ok(())
+                }
+                Action::Repeat {
+                    action,
+                    count,
+                    delay_ms,
+                } => {
+                    let mut errors = Vec::new();
+                    for i in 0..count {
+                        let result = self.run((*action).clone(), context.clone(), run).await;
+                        errors.extend(result.err());
+
+                        // Delay between iterations (but not after the last one)
+                        if i < count - 1
+                            && let Some(delay) = delay_ms
+                            && !pause(&mut run.cancelled, Duration::from_millis(delay)).await
+                        {
+                            break;
+                        }
+                    }
+                    ActionError::collect(errors, count)
+                }
+                Action::Conditional {
+                    condition,
+                    then_action,
+                    else_action,
+                } => {
+                    let matched = self
+                        .executor
+                        .lock()
+                        .await
+                        .check_condition(&condition, context.as_ref());
+                    if matched {
+                        self.run(*then_action, context, run).await
+                    } else if let Some(else_action) = else_action {
+                        self.run(*else_action, context, run).await
+                    } else {
+                        Ok(())
+                    }
+                }
+                Action::Cycle {
+                    id,
+                    mut actions,
+                    reset,
+                } => {
+                    if actions.is_empty() {
+                        return Ok(());
+                    }
+                    let index = self
+                        .executor
+                        .lock()
+                        .await
+                        .advance_cycle(&id, actions.len(), reset);
+                    self.run(actions.swap_remove(index), context, run).await
+                }
+                Action::CancelAction { mapping } => {
+                    let ids = self
+                        .table()
+                        .cancel_matching(mapping.as_deref(), Some(run.id));
+                    debug!("Job {} cancelled jobs {:?}", run.id, ids);
+                    Ok(())
+                }
+                // Mode changes are applied by the engine manager, which owns
+                // the active mode
+                Action::ModeChange { mode } => self
+                    .command_tx
+                    .send(DaemonCommand::ModeChange(mode.clone()))
+                    .await
+                    .map_err(|e| ActionError::ModeChange {
+                        mode,
+                        reason: e.to_string(),
+                    }),
+                action if ActionDevices::drives(&action) => {
+                    self.run_on_devices(action, context).await
+                }
+                action => self.executor.lock().await.try_execute(action, context),
+            }
+        })
+    }
+
+    /// Run an action that drives the devices on a blocking thread, holding
+    /// only the lock of the device it uses, so actions of a Parallel's
+    /// branches run at the same time
+    async fn run_on_devices(
+        &self,
+        action: Action,
+        context: Option<TriggerContext>,
+    ) -> Result<(), ActionError> {
+        let (devices, variables) = {
+            let executor = self.executor.lock().await;
+            let variables = match action {
+                Action::Plugin { .. } => executor.variables().values().clone(),
+                _ => HashMap::new(),
+            };
+            (executor.devices().clone(), variables)
+        };
+
+        let result =
+            tokio::task::spawn_blocking(move || devices.execute(action, context, &variables)).await;
+        match result {
+            Ok(result) => result,
+            // Keyboard and mouse failures panic, as they do on the executor
+            Err(e) if e.is_panic() => std::panic::resume_unwind(e.into_panic()),
+            Err(_) => Ok(()),
+        }
+    }
+}
+
+/// A job's state shared by the steps it runs, including those of a Parallel
+#[derive(Clone)]
+struct JobRun {
+    id: u64,
+    cancelled: watch::Receiver<bool>,
+    /// Reports the results of actions started by a Parallel without `wait`
+    detached: mpsc::UnboundedSender<Result<(), ActionError>>,
+}
+
+/// Finishes a job in its [`JobTable`] when dropped
+struct FinishJob {
+    table: Arc<std::sync::Mutex<JobTable>>,
+    id: u64,
+}
+
+impl Drop for FinishJob {
+    fn drop(&mut self) {
+        self.table
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .finish(self.id);
+    }
+}
+
+/// Wait for `duration`, returning false if the job is cancelled first
+async fn pause(cancelled: &mut watch::Receiver<bool>, duration: Duration) -> bool {
+    tokio::select! {
+        _ = tokio::time::sleep(duration) => true,
+        _ = cancelled.wait_for(|cancelled| *cancelled) => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn admit(
+        table: &mut JobTable,
+        mapping: &str,
+        policy: ConcurrencyPolicy,
+    ) -> Option<AdmittedJob> {
+        table.admit(mapping, None, policy)
+    }
+
+    #[test]
+    fn test_queue_waits_for_turn() {
+        let mut table = JobTable::default();
+        let first = admit(&mut table, "Default/0", ConcurrencyPolicy::Queue).unwrap();
+        let second = admit(&mut table, "Default/0", ConcurrencyPolicy::Queue).unwrap();
+        let other = admit(&mut table, "Default/1", ConcurrencyPolicy::Queue).unwrap();
+
+        assert!(Arc::ptr_eq(&first.turn, &second.turn));
+        assert!(!Arc::ptr_eq(&first.turn, &other.turn));
+
+        table.start(first.id);
+        let running: Vec<(u64, bool)> = table
+            .list()
+            .iter()
+            .map(|job| (job.id, job.running))
+            .collect();
+        assert_eq!(running, vec![(1, true), (2, false), (3, false)]);
+    }
+
+    #[test]
+    fn test_restart_cancels_running_job() {
+        let mut table = JobTable::default();
+        let first = admit(&mut table, "Default/0", ConcurrencyPolicy::Restart).unwrap();
+        let second = admit(&mut table, "Default/0", ConcurrencyPolicy::Restart).unwrap();
+
+        assert!(*first.cancelled.borrow());
+        assert!(!*second.cancelled.borrow());
+        assert_eq!(table.list().len(), 1);
+    }
+
+    #[test]
+    fn test_ignore_and_cancel_on_retrigger() {
+        let mut table = JobTable::default();
+        let ignore = ConcurrencyPolicy::IgnoreWhileRunning;
+        let first = admit(&mut table, "Default/0", ignore).unwrap();
+        assert!(admit(&mut table, "Default/0", ignore).is_none());
+        table.finish(first.id);
+        assert!(admit(&mut table, "Default/0", ignore).is_some());
+
+        // The second press stops the first, the third starts again
+        let toggle = ConcurrencyPolicy::CancelOnRetrigger;
+        let first = admit(&mut table, "Default/1", toggle).unwrap();
+        assert!(admit(&mut table, "Default/1", toggle).is_none());
+        assert!(*first.cancelled.borrow());
+        assert!(admit(&mut table, "Default/1", toggle).is_some());
+    }
+
+    #[test]
+    fn test_cancel_matching() {
+        let mut table = JobTable::default();
+        let intro = table
+            .admit(
+                "Default/0",
+                Some("Intro".to_string()),
+                ConcurrencyPolicy::Queue,
+            )
+            .unwrap();
+        let outro = admit(&mut table, "Default/1", ConcurrencyPolicy::Queue).unwrap();
+        let stop = admit(&mut table, "Default/2", ConcurrencyPolicy::Queue).unwrap();
+
+        assert_eq!(table.cancel_matching(Some("Intro"), None), vec![intro.id]);
+        assert_eq!(
+            table.cancel_matching(Some("Default/1"), None),
+            vec![outro.id]
+        );
+        assert!(table.cancel_matching(Some("Missing"), None).is_empty());
+
+        // Cancelling everything spares the job that asked
+        let again = admit(&mut table, "Default/1", ConcurrencyPolicy::Queue).unwrap();
+        assert_eq!(table.cancel_matching(None, Some(stop.id)), vec![again.id]);
+        assert!(!table.cancel(intro.id));
+        assert!(table.cancel(stop.id));
+    }
+
+    #[tokio::test]
+    async fn test_panicking_job_is_finished() {
+        let table = Arc::new(std::sync::Mutex::new(JobTable::default()));
+        let ignore = ConcurrencyPolicy::IgnoreWhileRunning;
+        let job = admit(&mut table.lock().unwrap(), "Default/0", ignore).unwrap();
+        let finish = FinishJob {
+            table: table.clone(),
+            id: job.id,
+        };
+
+        let task = tokio::spawn(async move {
+            let _finish = finish;
+            panic!("Keystroke failed");
+        });
+        assert!(task.await.unwrap_err().is_panic());
+
+        // The job is gone, so the mapping can fire again
+        let mut table = table.lock().unwrap();
+        assert!(table.list().is_empty());
+        assert!(admit(&mut table, "Default/0", ignore).is_some());
+    }
+
+    #[tokio::test]
+    async fn test_parallel_branches_overlap() {
+        use conductor_core::{MidiMessageParams, MidiMessageType};
+
+        let executor = Arc::new(Mutex::new(ActionExecutor::new()));
+        let (command_tx, mut commands) = mpsc::channel(10);
+        let jobs = ActionJobs::new(executor.clone(), command_tx);
+
+        // Hold the MIDI output, so sending a note blocks until it's released
+        let devices = executor.lock().await.devices().clone();
+        let (locked_tx, locked_rx) = std::sync::mpsc::channel();
+        let (release_tx, release_rx) = std::sync::mpsc::channel::<()>();
+        let holder = std::thread::spawn(move || {
+            let _midi = devices.midi_output();
+            locked_tx.send(()).unwrap();
+            let _ = release_rx.recv();
+        });
+        locked_rx.recv().unwrap();
+
+        let note = Action::SendMidi {
+            port: "Missing".to_string(),
+            message_type: MidiMessageType::ControlChange,
+            channel: 0,
+            params: MidiMessageParams::CC {
+                controller: 1,
+                value: 127,
+            },
+            relative: false,
+        };
+        let plugin_then_mode = Action::Sequence(vec![
+            Action::Plugin {
+                plugin: "missing".to_string(),
+                params: serde_json::Value::Null,
+            },
+            Action::ModeChange {
+                mode: "Done".to_string(),
+            },
+        ]);
+        jobs.spawn(
+            "Default/0",
+            None,
+            ConcurrencyPolicy::Queue,
+            Action::Parallel {
+                actions: vec![note, plugin_then_mode],
+                wait: true,
+            },
+            None,
+        );
+
+        // The plugin branch runs to the end while the note is still waiting
+        let command = tokio::time::timeout(Duration::from_secs(5), commands.recv())
+            .await
+            .expect("Branches should overlap");
+        assert!(matches!(command, Some(DaemonCommand::ModeChange(mode)) if mode == "Done"));
+        assert_eq!(jobs.list().len(), 1);
+
+        // Once the note is sent, the job reports both branches' failures
+        release_tx.send(()).unwrap();
+        holder.join().unwrap();
+        let command = tokio::time::timeout(Duration::from_secs(5), commands.recv())
+            .await
+            .unwrap();
+        assert!(matches!(
+            command,
+            Some(DaemonCommand::ActionFailed { error, .. }) if error.starts_with("2 of 2")
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_pause_stops_on_cancel() {
+        let (cancel, mut cancelled) = watch::channel(false);
+        assert!(pause(&mut cancelled, Duration::from_millis(1)).await);
+
+        let started = Instant::now();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            cancel.send_replace(true);
+        });
+        assert!(!pause(&mut cancelled, Duration::from_secs(5)).await);
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+}
@@ -66,6 +66,23 @@ enum Commands {
     #[command(name = "get-device")]
     GetDevice,
 
+    /// Show the positions of Cycle and Toggle actions
+    Cycles,
+
+    /// List running and queued action jobs
+    Jobs,
+
+    /// Cancel a running or queued action job
+    Kill {
+        /// Id of the job to cancel, as listed by `jobs`
+        #[arg(required_unless_present = "all")]
+        id: Option<u64>,
+
+        /// Cancel every job
+        #[arg(long, conflicts_with = "id")]
+        all: bool,
+    },
+
     // ============================================================================
     // Service Management Commands
     // ============================================================================
@@ -206,6 +223,9 @@ async fn execute_command(cli: &Cli) -> Result<()> {
         Commands::ListDevices => handle_list_devices(&mut client, cli.json).await?,
         Commands::SetDevice { port } => handle_set_device(&mut client, *port, cli.json).await?,
         Commands::GetDevice => handle_get_device(&mut client, cli.json).await?,
+        Commands::Cycles => handle_cycles(&mut client, cli.json).await?,
+        Commands::Jobs => handle_jobs(&mut client, cli.json).await?,
+        Commands::Kill { id, all } => handle_kill(&mut client, *id, *all, cli.json).await?,
         _ => unreachable!("Service commands handled above"),
     }
 
@@ -541,6 +561,113 @@ async fn handle_get_device(client: &mut IpcClient, json: bool) -> Result<()> {
     Ok(())
 }
 
+async fn handle_cycles(client: &mut IpcClient, json: bool) -> Result<()> {
+    let response = client
+        .send_command(IpcCommand::GetCycles, Value::Null)
+        .await
+        .context("Failed to get cycle positions")?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&response)?);
+    } else if let Some(data) = response.data {
+        if let Some(cycles) = data.get("cycles").and_then(|v| v.as_array()) {
+            println!("{}", "Cycle Positions".bold().cyan());
+            println!("{}", "─".repeat(50));
+
+            if cycles.is_empty() {
+                println!("No Cycle or Toggle actions have fired since the last reset");
+            } else {
+                for cycle in cycles {
+                    let id = cycle.get("id").and_then(|v| v.as_str()).unwrap_or("?");
+                    let position = cycle.get("position").and_then(|v| v.as_u64()).unwrap_or(0);
+                    let length = cycle.get("length").and_then(|v| v.as_u64()).unwrap_or(0);
+
+                    // Show the next action 1-based
+                    println!("  {}: next {} of {}", id, position + 1, length);
+                }
+            }
+            println!();
+        }
+    }
+
+    Ok(())
+}
+
+async fn handle_jobs(client: &mut IpcClient, json: bool) -> Result<()> {
+    let response = client
+        .send_command(IpcCommand::ListJobs, Value::Null)
+        .await
+        .context("Failed to list action jobs")?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&response)?);
+    } else if let Some(data) = response.data
+        && let Some(jobs) = data.get("jobs").and_then(|v| v.as_array())
+    {
+        println!("{}", "Action Jobs".bold().cyan());
+        println!("{}", "─".repeat(50));
+
+        if jobs.is_empty() {
+            println!("No actions running");
+        } else {
+            for job in jobs {
+                let id = job.get("id").and_then(|v| v.as_u64()).unwrap_or(0);
+                let mapping = job.get("mapping").and_then(|v| v.as_str()).unwrap_or("?");
+                let description = job.get("description").and_then(|v| v.as_str());
+                let running = job.get("running").and_then(|v| v.as_bool()) == Some(true);
+                let elapsed_ms = job.get("elapsed_ms").and_then(|v| v.as_u64()).unwrap_or(0);
+
+                let state = if running {
+                    "running".green()
+                } else {
+                    "queued".yellow()
+                };
+                let name = match description {
+                    Some(description) => format!("{} ({})", mapping, description),
+                    None => mapping.to_string(),
+                };
+                println!(
+                    "  [{}] {} - {} for {:.1}s",
+                    id,
+                    name,
+                    state,
+                    elapsed_ms as f64 / 1000.0
+                );
+            }
+        }
+        println!();
+    }
+
+    Ok(())
+}
+
+async fn handle_kill(client: &mut IpcClient, id: Option<u64>, all: bool, json: bool) -> Result<()> {
+    let args = if all {
+        serde_json::json!({ "all": true })
+    } else {
+        serde_json::json!({ "id": id })
+    };
+
+    let response = client
+        .send_command(IpcCommand::CancelJob, args)
+        .await
+        .context("Failed to cancel action job")?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&response)?);
+    } else if let Some(data) = response.data {
+        let cancelled = data
+            .get("cancelled")
+            .and_then(|v| v.as_array())
+            .map_or(0, |ids| ids.len());
+        println!("{}", format!("✓ Cancelled {} job(s)", cancelled).green());
+    } else if let Some(error) = response.error {
+        println!("{}", format!("✗ {}", error.message).red());
+    }
+
+    Ok(())
+}
+
 /// Format duration in seconds to human-readable string
 fn format_duration(secs: u64) -> String {
     let days = secs / 86400;
@@ -7,7 +7,8 @@
 //! Supports time-based, app-based, mode-based, and logical operators.
 
 use chrono::{Datelike, Local, Timelike, Weekday};
-use conductor_core::Condition;
+use conductor_core::event_processor::ClockState;
+use conductor_core::{Condition, VariableStore};
 
 #[cfg(any(target_os = "macos", target_os = "linux"))]
 use std::process::Command;
@@ -19,6 +20,12 @@ use std::process::Command;
 pub struct ConditionContext {
     /// Current mode name (e.g., "Default", "Development")
     pub current_mode: Option<String>,
+
+    /// Transport and tempo derived from incoming MIDI clock
+    pub clock: Option<ClockState>,
+
+    /// Runtime variables set by actions
+    pub variables: VariableStore,
 }
 
 impl ConditionContext {
@@ -26,6 +33,7 @@ impl ConditionContext {
     pub fn with_mode(mode: String) -> Self {
         Self {
             current_mode: Some(mode),
+            ..Default::default()
         }
     }
 }
@@ -57,6 +65,19 @@ pub fn evaluate_condition(condition: &Condition, context: Option<&ConditionConte
                 false
             }
         }
+        Condition::TransportPlaying => context
+            .and_then(|ctx| ctx.clock)
+            .is_some_and(|clock| clock.playing),
+        Condition::TempoRange { min, max } => context
+            .and_then(|ctx| ctx.clock)
+            .and_then(|clock| clock.bpm)
+            .is_some_and(|bpm| (*min..=*max).contains(&bpm)),
+        Condition::VariableEquals { name, value } => {
+            context.is_some_and(|ctx| ctx.variables.equals(name, value))
+        }
+        Condition::VariableInRange { name, min, max } => {
+            context.is_some_and(|ctx| ctx.variables.in_range(name, *min, *max))
+        }
         Condition::And { conditions } => conditions.iter().all(|c| evaluate_condition(c, context)),
         Condition::Or { conditions } => conditions.iter().any(|c| evaluate_condition(c, context)),
         Condition::Not { condition } => !evaluate_condition(condition, context),
@@ -199,6 +220,7 @@ fn evaluate_app_frontmost(app_name: &str) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use conductor_core::VariableValue;
 
     #[test]
     fn test_always_condition() {
@@ -286,6 +308,73 @@ mod tests {
         assert!(!evaluate_condition(&condition, None));
     }
 
+    #[test]
+    fn test_transport_playing() {
+        let context = |playing| ConditionContext {
+            clock: Some(ClockState {
+                playing,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        assert!(evaluate_condition(
+            &Condition::TransportPlaying,
+            Some(&context(true))
+        ));
+        assert!(!evaluate_condition(
+            &Condition::TransportPlaying,
+            Some(&context(false))
+        ));
+        assert!(!evaluate_condition(&Condition::TransportPlaying, None));
+    }
+
+    #[test]
+    fn test_tempo_range() {
+        let condition = Condition::TempoRange {
+            min: 100.0,
+            max: 130.0,
+        };
+        let context = |bpm| ConditionContext {
+            clock: Some(ClockState {
+                bpm,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        assert!(evaluate_condition(&condition, Some(&context(Some(120.0)))));
+        assert!(!evaluate_condition(&condition, Some(&context(Some(140.0)))));
+        // No clock received yet
+        assert!(!evaluate_condition(&condition, Some(&context(None))));
+    }
+
+    #[test]
+    fn test_variable_conditions() {
+        let mut context = ConditionContext::default();
+        context.variables.set("scene", VariableValue::Number(2.0));
+
+        let equals = |value| Condition::VariableEquals {
+            name: "scene".to_string(),
+            value,
+        };
+        assert!(evaluate_condition(
+            &equals(VariableValue::Number(2.0)),
+            Some(&context)
+        ));
+        assert!(!evaluate_condition(
+            &equals(VariableValue::Text("2".to_string())),
+            Some(&context)
+        ));
+
+        let in_range = Condition::VariableInRange {
+            name: "scene".to_string(),
+            min: 1.0,
+            max: 3.0,
+        };
+        assert!(evaluate_condition(&in_range, Some(&context)));
+        // Unset variables match nothing
+        assert!(!evaluate_condition(&in_range, None));
+    }
+
     #[test]
     fn test_and_operator_all_true() {
         let condition = Condition::And {
@@ -4,6 +4,7 @@
 //! Engine manager with atomic config reloading and device reconnection
 
 use crate::action_executor::{ActionExecutor, TriggerContext};
+use crate::action_jobs::ActionJobs;
 use crate::daemon::error::{DaemonError, IpcErrorCode, Result};
 use crate::daemon::ipc::create_success_response;
 use crate::daemon::state::{ConfigInfo, EngineInfo, calculate_checksum};
@@ -12,15 +13,41 @@ use crate::daemon::types::{
     IpcResponse, LifecycleState, ReloadMetrics, ResponseStatus,
 };
 use crate::input_manager::{InputManager, InputMode};
+use conductor_core::event_processor::ProcessedEvent;
 use conductor_core::events::InputEvent;
-use conductor_core::{Config, EventProcessor, MappingEngine};
+use conductor_core::mapping::MappedAction;
+use conductor_core::mikro_leds::RGB;
+use conductor_core::{
+    Action, ConcurrencyPolicy, Config, ConfigError, CycleReset, EventProcessor, FeedbackManager,
+    KeyCode, MappingEngine, ModifierKey,
+};
 use serde_json::json;
+use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::Arc;
-use std::time::{Instant, SystemTime, UNIX_EPOCH};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 use tokio::sync::{Mutex, RwLock, broadcast, mpsc};
 use tracing::{debug, error, info, trace, warn};
 
+/// How often held pads are checked for long presses
+const HOLD_TICK_INTERVAL: Duration = Duration::from_millis(20);
+
+/// Release-phase work registered when a pad fires a mapping
+struct PendingRelease {
+    /// Pads as (channel, note); releasing any of them completes the mapping
+    pads: Vec<(u8, u8)>,
+    on_release: Option<Action>,
+    /// Id, description and concurrency policy of the mapping, for the
+    /// `on_release` action's job
+    mapping: String,
+    description: Option<String>,
+    concurrency: ConcurrencyPolicy,
+    /// Keys held down by a momentary Keystroke
+    held_keys: Option<(Vec<KeyCode>, Vec<ModifierKey>)>,
+    /// Mode layered by a momentary ModeChange
+    layer: Option<u8>,
+}
+
 /// Engine manager coordinating MIDIMon engine with daemon lifecycle
 pub struct EngineManager {
     /// Current config (atomic swap support)
@@ -32,6 +59,18 @@ pub struct EngineManager {
     mapping_engine: Arc<RwLock<MappingEngine>>,
     action_executor: Arc<Mutex<ActionExecutor>>,
 
+    /// Background jobs running the actions of fired mappings
+    action_jobs: ActionJobs,
+
+    /// Index of the active mode in `config.modes`
+    current_mode: Arc<RwLock<usize>>,
+
+    /// Optional LED feedback (hold progress)
+    feedback: Arc<Mutex<Option<FeedbackManager>>>,
+
+    /// `on_release` actions and momentary keys waiting for their pad's release
+    pending_releases: Vec<PendingRelease>,
+
     /// Unified input device manager (MIDI + Gamepad) (v3.0)
     input_manager: Arc<Mutex<Option<InputManager>>>,
 
@@ -71,10 +110,16 @@ impl EngineManager {
         command_tx: mpsc::Sender<DaemonCommand>,
         shutdown_tx: broadcast::Sender<()>,
     ) -> Result<Self> {
-        let event_processor = EventProcessor::new();
+        let event_processor = EventProcessor::from_config(&config);
         let mut mapping_engine = MappingEngine::new();
         mapping_engine.load_from_config(&config);
-        let action_executor = ActionExecutor::new();
+        let mut action_executor = ActionExecutor::new();
+        action_executor
+            .variables_mut()
+            .set_defaults(&config.variables.initial);
+        action_executor.set_command_sender(command_tx.clone());
+        let action_executor = Arc::new(Mutex::new(action_executor));
+        let action_jobs = ActionJobs::new(action_executor.clone(), command_tx.clone());
 
         // Create input event channel (buffer: 100 events) (v3.0)
         let (input_event_tx, input_event_rx) = mpsc::channel::<InputEvent>(100);
@@ -84,7 +129,11 @@ impl EngineManager {
             config_path,
             event_processor: Arc::new(RwLock::new(event_processor)),
             mapping_engine: Arc::new(RwLock::new(mapping_engine)),
-            action_executor: Arc::new(Mutex::new(action_executor)),
+            action_executor,
+            action_jobs,
+            current_mode: Arc::new(RwLock::new(0)),
+            feedback: Arc::new(Mutex::new(None)),
+            pending_releases: Vec::new(),
             input_manager: Arc::new(Mutex::new(None)),
             input_event_tx,
             input_event_rx,
@@ -103,6 +152,9 @@ impl EngineManager {
     pub async fn run(&mut self) -> Result<()> {
         info!("Engine manager starting");
 
+        // A config using plugins that can't run is rejected, as on reload
+        self.start_plugins().await?;
+
         // Transition to Starting state
         self.transition_state(LifecycleState::Starting).await?;
 
@@ -117,6 +169,9 @@ impl EngineManager {
 
         info!("Engine manager running");
 
+        let mut hold_timer = tokio::time::interval(HOLD_TICK_INTERVAL);
+        hold_timer.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+
         // Main event loop: process input events and commands concurrently (v3.0)
         loop {
             tokio::select! {
@@ -128,6 +183,14 @@ impl EngineManager {
                     }
                 }
 
+                // Hold timer: long presses fire while the pad is still held
+                _ = hold_timer.tick() => {
+                    if let Err(e) = self.process_hold_tick(Instant::now()).await {
+                        error!("Failed to process hold tick: {}", e);
+                        self.log_error("InputEventProcessingFailed", e.to_string()).await;
+                    }
+                }
+
                 // Commands from IPC, config watcher, or reconnection thread
                 Some(command) = self.command_rx.recv() => {
                     match command {
@@ -167,6 +230,15 @@ impl EngineManager {
                             }
                         }
 
+                        DaemonCommand::ModeChange(mode) => {
+                            self.set_mode(&mode).await;
+                        }
+
+                        DaemonCommand::ActionFailed { mapping, error } => {
+                            self.log_error("ActionFailed", format!("{}: {}", mapping, error))
+                                .await;
+                        }
+
                         DaemonCommand::ReconnectGamepad => {
                             info!("Gamepad reconnection requested (v3.0 - not yet implemented)");
                             // TODO: Implement gamepad reconnection in Week 3-4
@@ -210,7 +282,8 @@ impl EngineManager {
             }
         }
 
-        // Disconnect input devices before shutdown (v3.0)
+        // Stop running actions and disconnect input devices before shutdown (v3.0)
+        self.action_jobs.cancel_all();
         self.disconnect_input_devices().await;
 
         // Final state transition
@@ -245,7 +318,7 @@ impl EngineManager {
 
             IpcCommand::Status => {
                 let state = *self.state.read().await;
-                let config = self.config.read().await;
+                let (current_mode_index, current_mode) = self.current_mode().await;
                 let device_status = self.device_status.read().await.clone();
                 let stats = self.statistics.read().await.clone();
                 let uptime_secs = self.start_time.elapsed().as_secs();
@@ -286,7 +359,8 @@ impl EngineManager {
                         "device": device_status,
                         // Legacy fields for backward compatibility
                         "state": format!("{}", state),
-                        "current_mode": config.modes.first().map(|m| &m.name).unwrap_or(&"None".to_string()),
+                        "current_mode": current_mode,
+                        "current_mode_index": current_mode_index,
                         "config_path": self.config_path,
                         "config_loaded_at": stats.uptime_secs,
                         "device_status": device_status,
@@ -354,7 +428,16 @@ impl EngineManager {
                     .map(PathBuf::from)
                     .unwrap_or_else(|| self.config_path.clone());
 
-                match Config::load(path.to_str().unwrap_or("")) {
+                let loaded = Config::load(path.to_str().unwrap_or("")).map_err(|e| e.to_string());
+                let loaded = match loaded {
+                    Ok(config) => self
+                        .check_plugins(&config)
+                        .await
+                        .map(|()| config)
+                        .map_err(|e| e.to_string()),
+                    Err(e) => Err(e),
+                };
+                match loaded {
                     Ok(config) => {
                         let total_mappings: usize =
                             config.modes.iter().map(|m| m.mappings.len()).sum::<usize>()
@@ -376,7 +459,7 @@ impl EngineManager {
                         data: None,
                         error: Some(ErrorDetails {
                             code: IpcErrorCode::ConfigValidationFailed.as_u16(),
-                            message: e.to_string(),
+                            message: e,
                             details: None,
                         }),
                     },
@@ -453,13 +536,74 @@ impl EngineManager {
                     })),
                 )
             }
+
+            IpcCommand::GetCycles => {
+                let executor = self.action_executor.lock().await;
+                let mut positions: Vec<_> = executor.cycles().positions().iter().collect();
+                positions.sort_by_key(|(cycle_id, _)| *cycle_id);
+
+                // Cycles that haven't fired since their last reset aren't listed
+                let cycles: Vec<_> = positions
+                    .into_iter()
+                    .map(|(cycle_id, cycle)| {
+                        json!({
+                            "id": cycle_id,
+                            "position": cycle.position,
+                            "length": cycle.length,
+                            "reset": cycle.reset,
+                        })
+                    })
+                    .collect();
+
+                create_success_response(&id, Some(json!({ "cycles": cycles })))
+            }
+
+            IpcCommand::ListJobs => {
+                create_success_response(&id, Some(json!({ "jobs": self.action_jobs.list() })))
+            }
+
+            IpcCommand::CancelJob => {
+                // Cancel one job by id, or every job with "all"
+                let all = request.args.get("all").and_then(|v| v.as_bool()) == Some(true);
+                let result = match request.args.get("id").and_then(|v| v.as_u64()) {
+                    _ if all => Ok(self.action_jobs.cancel_all()),
+                    Some(job_id) if self.action_jobs.cancel(job_id) => Ok(vec![job_id]),
+                    Some(job_id) => Err((format!("No running job with id {}", job_id), None)),
+                    None => Err((
+                        "Missing 'id' or 'all' parameter".to_string(),
+                        Some(json!({"example": {"id": 1}})),
+                    )),
+                };
+
+                match result {
+                    Ok(cancelled) => {
+                        info!("Cancelled action jobs {:?}", cancelled);
+                        create_success_response(&id, Some(json!({ "cancelled": cancelled })))
+                    }
+                    Err((message, details)) => IpcResponse {
+                        id,
+                        status: ResponseStatus::Error,
+                        data: None,
+                        error: Some(ErrorDetails {
+                            code: IpcErrorCode::InvalidRequest.as_u16(),
+                            message,
+                            details,
+                        }),
+                    },
+                }
+            }
         }
     }
 
     /// Reload configuration with atomic swap
+    ///
+    /// The new config is loaded, validated and compiled before anything is
+    /// swapped. If any of those phases fail, the previous config, mapping
+    /// engine and timings stay in place and the daemon returns to Running.
+    /// Input devices are only reconnected when `[device]` changed; a failed
+    /// reconnect keeps the new config but leaves the daemon Degraded, matching
+    /// startup behavior.
     async fn reload_config(&mut self) -> Result<ReloadMetrics> {
-        let start = Instant::now();
-
         // Check current state
         let current_state = *self.state.read().await;
 
@@ -486,10 +630,115 @@ impl EngineManager {
         }
         self.transition_state(LifecycleState::Reloading).await?;
 
-        // Phase 1: Load and validate new config
+        let (metrics, device_changed) = match self.swap_config().await {
+            Ok(result) => result,
+            Err(e) => {
+                // Rollback: nothing was swapped, keep running the old config
+                warn!("Config reload failed, keeping previous config: {}", e);
+                self.transition_state(LifecycleState::Running).await?;
+                return Err(e);
+            }
+        };
+
+        // Update statistics
+        {
+            let mut stats = self.statistics.write().await;
+            stats.update_reload_metrics(&metrics);
+        }
+
+        info!(
+            "Config reloaded in {}ms (grade: {}): {} modes, {} mappings [load: {}ms, compile: {}ms, swap: {}ms]",
+            metrics.duration_ms,
+            metrics.performance_grade(),
+            metrics.modes_loaded,
+            metrics.mappings_loaded,
+            metrics.config_load_ms,
+            metrics.mapping_compile_ms,
+            metrics.swap_ms
+        );
+
+        // Reconnect input devices only if the device section changed
+        if device_changed {
+            info!("Device config changed, reconnecting input devices");
+            self.disconnect_input_devices().await;
+            if let Err(e) = self.connect_input_devices().await {
+                warn!("Failed to connect to input device after reload: {}", e);
+                self.log_error("InputConnectionFailed", e.to_string()).await;
+                self.transition_state(LifecycleState::Degraded).await?;
+                return Ok(metrics);
+            }
+        }
+
+        // Transition back to Running
+        self.transition_state(LifecycleState::Running).await?;
+
+        Ok(metrics)
+    }
+
+    /// Discover the installed plugins, then check and load the ones the
+    /// startup config uses
+    async fn start_plugins(&self) -> Result<()> {
+        self.discover_plugins().await;
+        let config = self.config.read().await.clone();
+        self.check_plugins(&config).await?;
+        self.load_plugins(&config).await;
+        Ok(())
+    }
+
+    /// Rescan the plugins directory for installed plugins
+    ///
+    /// Without a successful scan, `Plugin` actions can't be checked.
+    async fn discover_plugins(&self) {
+        let executor = self.action_executor.lock().await;
+        match executor.plugin_manager().discover_plugins() {
+            Ok(count) => debug!("Discovered {} plugins", count),
+            Err(e) => warn!("Failed to discover plugins: {}", e),
+        }
+    }
+
+    /// Load the plugins used by a config's `Plugin` actions, logging the ones
+    /// that fail to load
+    async fn load_plugins(&self, config: &Config) {
+        let failed = self
+            .action_executor
+            .lock()
+            .await
+            .plugin_manager()
+            .load_config_plugins(config);
+        for (plugin, e) in failed {
+            warn!("Failed to load plugin {}: {}", plugin, e);
+            self.log_error("PluginLoadFailed", format!("{}: {}", plugin, e))
+                .await;
+        }
+    }
+
+    /// Check the plugins used by a config's `Plugin` actions against the
+    /// plugin manager, once it has discovered the installed plugins
+    async fn check_plugins(&self, config: &Config) -> std::result::Result<(), ConfigError> {
+        let executor = self.action_executor.lock().await;
+        let plugins = executor.plugin_manager();
+        if plugins.is_discovered() {
+            config.validate_with_plugins(&*plugins)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Load, compile and atomically swap in the config from disk
+    ///
+    /// Returns the reload metrics and whether the device config changed.
+    /// Nothing is modified unless every phase before the swap succeeds.
+    async fn swap_config(&self) -> Result<(ReloadMetrics, bool)> {
+        let start = Instant::now();
+
+        // Phase 1: Load and validate new config, against the plugins installed now
         let config_load_start = Instant::now();
         let new_config = Config::load(self.config_path.to_str().unwrap_or(""))
             .map_err(|e| DaemonError::Ipc(format!("Config load failed: {}", e)))?;
+        self.discover_plugins().await;
+        self.check_plugins(&new_config)
+            .await
+            .map_err(|e| DaemonError::Ipc(format!("Config load failed: {}", e)))?;
         let config_load_ms = config_load_start.elapsed().as_millis() as u64;
 
         // Phase 2: Create new mapping engine
@@ -498,53 +747,58 @@ impl EngineManager {
         new_mapping_engine.load_from_config(&new_config);
         let mapping_compile_ms = mapping_compile_start.elapsed().as_millis() as u64;
 
-        // Phase 3: Atomic swap
-        let swap_start = Instant::now();
-        {
-            *self.config.write().await = new_config.clone();
-            *self.mapping_engine.write().await = new_mapping_engine;
-        }
-        let swap_ms = swap_start.elapsed().as_millis() as u64;
-
-        // Calculate metrics
-        let duration_ms = start.elapsed().as_millis() as u64;
         let total_mappings: usize = new_config
             .modes
             .iter()
             .map(|m| m.mappings.len())
             .sum::<usize>()
             + new_config.global_mappings.len();
+        let modes_loaded = new_config.modes.len();
+        let initial_variables = new_config.variables.initial.clone();
 
-        let metrics = ReloadMetrics {
-            duration_ms,
-            modes_loaded: new_config.modes.len(),
-            mappings_loaded: total_mappings,
-            config_load_ms,
-            mapping_compile_ms,
-            swap_ms,
+        // Plugins the new config adds are loaded ahead of the swap, which can't fail
+        self.load_plugins(&new_config).await;
+
+        // Phase 3: Atomic swap (keep the active mode if it still exists by name)
+        let swap_start = Instant::now();
+        let device_changed = {
+            let mut config = self.config.write().await;
+            let mut mapping_engine = self.mapping_engine.write().await;
+            let mut event_processor = self.event_processor.write().await;
+            let mut current_mode = self.current_mode.write().await;
+
+            let active_name = config.modes.get(*current_mode).map(|m| m.name.clone());
+            *current_mode = active_name
+                .and_then(|name| find_mode_index(&new_config, &name))
+                .unwrap_or(0);
+
+            // Timings and per-trigger windows take effect without resetting held notes
+            event_processor.apply_config(&new_config);
+
+            let device_changed = config.device != new_config.device;
+            *config = new_config;
+            *mapping_engine = new_mapping_engine;
+            device_changed
         };
 
-        // Update statistics
         {
-            let mut stats = self.statistics.write().await;
-            stats.update_reload_metrics(&metrics);
+            // Variables keep their values; new ones start at their initial value
+            let mut executor = self.action_executor.lock().await;
+            executor.variables_mut().set_defaults(&initial_variables);
+            executor.reset_cycles(CycleReset::Reload);
         }
+        let swap_ms = swap_start.elapsed().as_millis() as u64;
 
-        info!(
-            "Config reloaded in {}ms (grade: {}): {} modes, {} mappings [load: {}ms, compile: {}ms, swap: {}ms]",
-            duration_ms,
-            metrics.performance_grade(),
-            metrics.modes_loaded,
-            metrics.mappings_loaded,
+        let metrics = ReloadMetrics {
+            duration_ms: start.elapsed().as_millis() as u64,
+            modes_loaded,
+            mappings_loaded: total_mappings,
             config_load_ms,
             mapping_compile_ms,
-            swap_ms
-        );
-
-        // Transition back to Running
-        self.transition_state(LifecycleState::Running).await?;
+            swap_ms,
+        };
 
-        Ok(metrics)
+        Ok((metrics, device_changed))
     }
 
     /// Transition to a new lifecycle state
@@ -621,18 +875,161 @@ impl EngineManager {
 
     /// Get current engine info for state persistence
     pub async fn get_engine_info(&self) -> EngineInfo {
-        let config = self.config.read().await;
+        let (current_mode_index, current_mode) = self.current_mode().await;
         let device_status = self.device_status.read().await.clone();
+        let variables = if self.config.read().await.variables.persist {
+            let executor = self.action_executor.lock().await;
+            executor.variables().values().clone()
+        } else {
+            HashMap::new()
+        };
 
         EngineInfo {
-            current_mode: config
-                .modes
-                .first()
-                .map(|m| m.name.clone())
-                .unwrap_or_else(|| "None".to_string()),
-            current_mode_index: 0,
+            current_mode,
+            current_mode_index,
             device_status,
+            variables,
+        }
+    }
+
+    /// Get the active mode as (index, name)
+    ///
+    /// The name is "None" when the config defines no modes.
+    pub async fn current_mode(&self) -> (usize, String) {
+        let config = self.config.read().await;
+        let index = *self.current_mode.read().await;
+        let name = config
+            .modes
+            .get(index)
+            .map(|m| m.name.clone())
+            .unwrap_or_else(|| "None".to_string());
+
+        (index, name)
+    }
+
+    /// Get the mode on top of the layer stack as (index, name)
+    ///
+    /// This is the active mode unless a momentary layer is held.
+    pub async fn effective_mode(&self) -> (usize, String) {
+        let (index, name) = self.current_mode().await;
+        let top = self.mapping_engine.read().await.effective_mode(index as u8) as usize;
+        if top == index {
+            return (index, name);
         }
+
+        let config = self.config.read().await;
+        let name = config
+            .modes
+            .get(top)
+            .map(|m| m.name.clone())
+            .unwrap_or(name);
+
+        (top, name)
+    }
+
+    /// Layer a mode by name on top of the active mode
+    ///
+    /// Returns the layered mode index, or None if the config has no mode with
+    /// that name.
+    async fn push_layer(&self, name: &str) -> Option<u8> {
+        let Some(index) = find_mode_index(&*self.config.read().await, name) else {
+            warn!("Momentary ModeChange to unknown mode '{}' ignored", name);
+            return None;
+        };
+
+        let index = index as u8;
+        self.mapping_engine.write().await.push_layer(index);
+        debug!("Layer pushed: {}", name);
+        self.show_mode_feedback().await;
+
+        Some(index)
+    }
+
+    /// Show the mode on top of the layer stack on the feedback device
+    async fn show_mode_feedback(&self) {
+        let (index, _) = self.effective_mode().await;
+        if let Some(feedback) = self.feedback.lock().await.as_mut() {
+            // Devices derive their colors from the mode index
+            if let Err(e) = feedback.on_mode_change(index as u8, RGB::OFF) {
+                debug!("Failed to show mode feedback: {}", e);
+            }
+        }
+    }
+
+    /// Switch the active mode by name
+    ///
+    /// Returns the new mode index, or None (leaving the mode unchanged) if the
+    /// config has no mode with that name.
+    pub async fn set_mode(&self, name: &str) -> Option<usize> {
+        let (index, changed) = {
+            let config = self.config.read().await;
+            let Some(index) = find_mode_index(&config, name) else {
+                warn!("ModeChange to unknown mode '{}' ignored", name);
+                return None;
+            };
+
+            let mut current_mode = self.current_mode.write().await;
+            let changed = *current_mode != index;
+            if changed {
+                info!(
+                    "Mode changed: {} → {}",
+                    config.modes[*current_mode].name, name
+                );
+                *current_mode = index;
+            }
+            (index, changed)
+        };
+
+        if changed {
+            self.action_executor
+                .lock()
+                .await
+                .reset_cycles(CycleReset::ModeChange);
+            self.show_mode_feedback().await;
+        }
+
+        Some(index)
+    }
+
+    /// Restore the active mode from persisted engine info
+    ///
+    /// Ignored if the persisted index is out of range for the current config.
+    pub async fn restore_mode(&self, engine_info: &EngineInfo) {
+        let config = self.config.read().await;
+        if engine_info.current_mode_index < config.modes.len() {
+            *self.current_mode.write().await = engine_info.current_mode_index;
+            info!(
+                "Restored mode {} ({})",
+                engine_info.current_mode_index, config.modes[engine_info.current_mode_index].name
+            );
+        } else {
+            warn!(
+                "Persisted mode index {} out of range ({} modes), using mode 0",
+                engine_info.current_mode_index,
+                config.modes.len()
+            );
+        }
+    }
+
+    /// Restore runtime variables from persisted engine info
+    ///
+    /// Ignored unless the config sets `variables.persist`. Persisted values
+    /// replace the initial values from the config.
+    pub async fn restore_variables(&self, engine_info: &EngineInfo) {
+        if !self.config.read().await.variables.persist || engine_info.variables.is_empty() {
+            return;
+        }
+
+        let mut executor = self.action_executor.lock().await;
+        for (name, value) in &engine_info.variables {
+            executor.variables_mut().set(name, value.clone());
+        }
+        info!("Restored {} variables", engine_info.variables.len());
+    }
+
+    /// Attach an LED feedback manager that shows hold progress on held pads
+    pub async fn set_feedback(&self, feedback: FeedbackManager) {
+        *self.feedback.lock().await = Some(feedback);
     }
 
     /// Get current statistics
@@ -699,6 +1096,15 @@ impl EngineManager {
         // Update device status
         self.update_device_status(false, None, None).await;
 
+        // No release will arrive for pads held now, so don't leave keys stuck down
+        // or layers active
+        self.pending_releases.clear();
+        self.action_executor.lock().await.release_held_keys();
+        if !self.mapping_engine.read().await.layers().is_empty() {
+            self.mapping_engine.write().await.clear_layers();
+            self.show_mode_feedback().await;
+        }
+
         info!("Input devices disconnected");
     }
 
@@ -729,46 +1135,75 @@ impl EngineManager {
         debug!("Processing input event: {:?}", input_event);
 
         // Phase 1: Process InputEvent → ProcessedEvent (with timing, gestures) (v3.0)
-        let processed_events = {
+        // Timers are advanced to the event's time first, so a deferred single
+        // press that has just resolved is executed on its own
+        let (resolved_events, processed_events) = {
             let mut processor = self.event_processor.write().await;
-            processor.process_input(input_event)
+            let resolved = processor.tick(input_event.timestamp());
+            (resolved, processor.process_input(input_event))
         };
 
-        // Phase 2: Map ProcessedEvents → Action (v3.0)
-        let current_mode = 0; // TODO: Track current mode
-        let mut action = None;
+        self.execute_processed_events(&resolved_events).await?;
+        self.execute_processed_events(&processed_events).await
+    }
 
-        {
-            let engine = self.mapping_engine.read().await;
+    /// Advance hold timers to `now`, firing long presses and updating hold feedback
+    async fn process_hold_tick(&mut self, now: Instant) -> Result<()> {
+        let (processed_events, held_pads) = {
+            let mut processor = self.event_processor.write().await;
+            (processor.tick(now), processor.held_pads(now))
+        };
 
-            // Try matching processed events (chords, long press, etc.)
-            for processed_event in &processed_events {
-                if let Some(found_action) = engine.get_action_for_processed(processed_event, current_mode) {
-                    action = Some(found_action);
-                    break;
+        if !held_pads.is_empty()
+            && let Some(feedback) = self.feedback.lock().await.as_mut()
+        {
+            for (_, pad, elapsed_ms) in held_pads {
+                if let Err(e) = feedback.show_long_press_feedback(pad, elapsed_ms) {
+                    debug!("Failed to show hold feedback for pad {}: {}", pad, e);
                 }
             }
         }
 
-        // Phase 3: Execute action if found
-        if let Some(action) = action {
-            debug!("Executing action for input event");
+        self.execute_processed_events(&processed_events).await
+    }
 
-            // Create trigger context with velocity from processed event (v3.0)
-            let context = TriggerContext {
-                velocity: processed_events.iter().find_map(|e| match e {
-                    conductor_core::event_processor::ProcessedEvent::PadPressed { velocity, .. } => Some(*velocity),
-                    _ => None,
-                }),
-                current_mode: None, // TODO: Track current mode
-            };
+    /// Map ProcessedEvents to an action and execute it
+    async fn execute_processed_events(
+        &mut self,
+        processed_events: &[ProcessedEvent],
+    ) -> Result<()> {
+        // Phase 2: Map ProcessedEvents → Action (v3.0)
+        let (current_mode_index, _) = self.current_mode().await;
+        let (_, current_mode) = self.effective_mode().await;
 
-            let mut executor = self.action_executor.lock().await;
-            executor.execute(action, Some(context));
+        let matched = {
+            let engine = self.mapping_engine.read().await;
+            let held: Vec<(u8, u8)> = self
+                .event_processor
+                .read()
+                .await
+                .held_pads(Instant::now())
+                .into_iter()
+                .map(|(channel, note, _)| (channel, note))
+                .collect();
+
+            // Every event in the batch that has a mapping fires (chords,
+            // long press, a sequence and its last step, etc.)
+            engine.get_mapped_actions_for_batch(processed_events, current_mode_index as u8, &held)
+        };
 
-            // Update statistics
-            let mut stats = self.statistics.write().await;
-            stats.events_processed += 1;
+        // Phase 3: Execute the actions found
+        for (processed_event, mapped) in matched {
+            self.dispatch_mapped(processed_event, mapped, processed_events, &current_mode)
+                .await;
+        }
+
+        // Phase 4: Complete mappings whose pad was released (after any press
+        // in the same batch, e.g. a deferred tap resolved after its release)
+        for processed_event in processed_events {
+            if let ProcessedEvent::PadReleased { channel, note, .. } = processed_event {
+                self.release_pad(*channel, *note).await;
+            }
         }
 
         // ProcessedEvents are available for future use (UI feedback, etc.)
@@ -782,6 +1217,136 @@ impl EngineManager {
         Ok(())
     }
 
+    /// Run the action mapped for one event of a batch
+    async fn dispatch_mapped(
+        &mut self,
+        processed_event: &ProcessedEvent,
+        mapped: MappedAction,
+        processed_events: &[ProcessedEvent],
+        current_mode: &str,
+    ) {
+        debug!("Executing action for input event");
+
+        // The modifier pads' own press and hold don't fire as well
+        if !mapped.modifiers.is_empty() {
+            self.event_processor
+                .write()
+                .await
+                .mark_modifiers_used(&mapped.modifiers);
+        }
+
+        // Create trigger context with velocity from processed event (v3.0)
+        let control = mapped.control;
+        let context = TriggerContext {
+            velocity: control.map(|c| c.value).or_else(|| {
+                processed_events.iter().find_map(|e| match e {
+                    ProcessedEvent::PadPressed { velocity, .. } => Some(*velocity),
+                    _ => None,
+                })
+            }),
+            current_mode: Some(current_mode.to_string()),
+            note: control.map(|c| c.number),
+            offset: control.map(|c| c.offset),
+            captures: mapped.captures,
+            clock: Some(self.event_processor.read().await.clock()),
+        };
+
+        let pads = event_pads(processed_event);
+        let mut layer_mode = None;
+        let held_keys = match mapped.action {
+            // Momentary keystrokes stay down until the pad is released
+            Action::Keystroke { keys, modifiers } if mapped.momentary && !pads.is_empty() => {
+                self.action_executor
+                    .lock()
+                    .await
+                    .key_down(&keys, &modifiers);
+                Some((keys, modifiers))
+            }
+            // Momentary mode changes layer the mode until the pad is released
+            Action::ModeChange { mode } if mapped.momentary && !pads.is_empty() => {
+                layer_mode = Some(mode);
+                None
+            }
+            // Everything else runs in the background, so a long action
+            // doesn't hold up input; ModeChange actions (top-level or
+            // nested) come back as DaemonCommand::ModeChange
+            action => {
+                self.action_jobs.spawn(
+                    &mapped.id,
+                    mapped.description.clone(),
+                    mapped.concurrency,
+                    action,
+                    Some(context),
+                );
+                None
+            }
+        };
+
+        let layer = match layer_mode {
+            Some(mode) => self.push_layer(&mode).await,
+            None => None,
+        };
+
+        if !pads.is_empty()
+            && (mapped.on_release.is_some() || held_keys.is_some() || layer.is_some())
+        {
+            self.pending_releases.push(PendingRelease {
+                pads,
+                on_release: mapped.on_release,
+                mapping: mapped.id,
+                description: mapped.description,
+                concurrency: mapped.concurrency,
+                held_keys,
+                layer,
+            });
+        }
+
+        // Update statistics
+        let mut stats = self.statistics.write().await;
+        stats.events_processed += 1;
+    }
+
+    /// Release momentary keys and layers, and run `on_release` actions
+    /// registered for a pad
+    async fn release_pad(&mut self, channel: u8, note: u8) {
+        let (released, pending): (Vec<_>, Vec<_>) = std::mem::take(&mut self.pending_releases)
+            .into_iter()
+            .partition(|release| release.pads.contains(&(channel, note)));
+        self.pending_releases = pending;
+
+        if released.is_empty() {
+            return;
+        }
+
+        let mut popped = false;
+        {
+            let mut engine = self.mapping_engine.write().await;
+            for layer in released.iter().filter_map(|release| release.layer) {
+                popped |= engine.pop_layer(layer);
+            }
+        }
+        if popped {
+            self.show_mode_feedback().await;
+        }
+
+        let (_, current_mode) = self.effective_mode().await;
+        for release in released {
+            if let Some((keys, modifiers)) = release.held_keys {
+                self.action_executor.lock().await.key_up(&keys, &modifiers);
+            }
+            if let Some(action) = release.on_release {
+                debug!("Executing on_release action for note {}", note);
+                self.action_jobs.spawn(
+                    &format!("{}/release", release.mapping),
+                    release.description,
+                    release.concurrency,
+                    action,
+                    Some(TriggerContext::with_mode(current_mode.clone())),
+                );
+            }
+        }
+    }
+
     /// Enumerate available MIDI devices
     fn enumerate_midi_devices() -> Result<Vec<crate::daemon::types::MidiDeviceInfo>> {
         use midir::MidiInput;
@@ -812,9 +1377,49 @@ impl EngineManager {
     }
 }
 
+/// Pads (channel, note) that an event was triggered by
+///
+/// Releasing any of them completes a mapping fired by the event.
+fn event_pads(event: &ProcessedEvent) -> Vec<(u8, u8)> {
+    match event {
+        ProcessedEvent::PadPressed { channel, note, .. }
+        | ProcessedEvent::PadReleased { channel, note, .. }
+        | ProcessedEvent::ShortPress { channel, note }
+        | ProcessedEvent::MediumPress { channel, note, .. }
+        | ProcessedEvent::LongPress { channel, note, .. }
+        | ProcessedEvent::HoldDetected { channel, note, .. }
+        | ProcessedEvent::DoubleTap { channel, note, .. }
+        | ProcessedEvent::PolyPressureChanged { channel, note, .. } => vec![(*channel, *note)],
+        ProcessedEvent::ChordDetected { channel, notes, .. } => {
+            notes.iter().map(|note| (*channel, *note)).collect()
+        }
+        ProcessedEvent::EncoderTurned { .. }
+        | ProcessedEvent::AftertouchChanged { .. }
+        | ProcessedEvent::PitchBendMoved { .. }
+        | ProcessedEvent::HighResCCChanged { .. }
+        | ProcessedEvent::NrpnChanged { .. }
+        | ProcessedEvent::RpnChanged { .. }
+        | ProcessedEvent::SysExReceived { .. }
+        | ProcessedEvent::TransportChanged { .. }
+        | ProcessedEvent::SongPositionChanged { .. }
+        | ProcessedEvent::TempoChanged { .. }
+        | ProcessedEvent::ProgramChanged { .. }
+        | ProcessedEvent::CCZoneEntered { .. }
+        | ProcessedEvent::CCThresholdCrossed { .. }
+        | ProcessedEvent::GestureRecognized { .. }
+        | ProcessedEvent::SequenceMatched { .. } => Vec::new(),
+    }
+}
+
+/// Find the index of a mode by name
+fn find_mode_index(config: &Config, name: &str) -> Option<usize> {
+    config.modes.iter().position(|m| m.name == name)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::plugin_manager::PluginManager;
     use std::time::Duration;
     use tokio::sync::mpsc;
 
@@ -930,4 +1535,232 @@ mod tests {
         let stats = manager.get_statistics().await;
         assert!(stats.uptime_secs == 0); // Less than 1 second
     }
+
+    const RELOAD_CONFIG: &str = r#"
+        [device]
+        name = "Test"
+        auto_connect = false
+
+        [[modes]]
+        name = "Default"
+
+        [[modes]]
+        name = "Media"
+    "#;
+
+    /// Create a running engine manager backed by a config file on disk
+    async fn create_running_manager(config_path: &std::path::Path) -> EngineManager {
+        std::fs::write(config_path, RELOAD_CONFIG).unwrap();
+        let config = Config::load(config_path.to_str().unwrap()).unwrap();
+        let (cmd_tx, cmd_rx) = mpsc::channel(10);
+        let (shutdown_tx, _shutdown_rx) = broadcast::channel(1);
+
+        let manager = EngineManager::new(
+            config,
+            config_path.to_path_buf(),
+            cmd_rx,
+            cmd_tx,
+            shutdown_tx,
+        )
+        .unwrap();
+        manager
+            .transition_state(LifecycleState::Starting)
+            .await
+            .unwrap();
+        manager
+            .transition_state(LifecycleState::Running)
+            .await
+            .unwrap();
+        manager
+    }
+
+    #[tokio::test]
+    async fn test_reload_swaps_config_and_keeps_mode() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let config_path = temp_dir.path().join("config.toml");
+        let mut manager = create_running_manager(&config_path).await;
+        manager.set_mode("Media").await;
+
+        // Reorder modes and add one; the active mode follows its name
+        std::fs::write(
+            &config_path,
+            r#"
+            [device]
+            name = "Test"
+            auto_connect = false
+
+            [advanced_settings]
+            double_tap_timeout_ms = 500
+
+            [[modes]]
+            name = "Media"
+
+            [[modes]]
+            name = "Default"
+
+            [[modes]]
+            name = "Gaming"
+        "#,
+        )
+        .unwrap();
+
+        let metrics = manager.reload_config().await.unwrap();
+        assert_eq!(metrics.modes_loaded, 3);
+        assert_eq!(metrics.mappings_loaded, 0);
+        assert_eq!(manager.get_state().await, LifecycleState::Running);
+        assert_eq!(manager.current_mode().await, (0, "Media".to_string()));
+        let settings = manager.config.read().await.advanced_settings.clone();
+        assert_eq!(settings.double_tap_timeout_ms, 500);
+        assert_eq!(manager.get_statistics().await.config_reloads, 1);
+    }
+
+    #[tokio::test]
+    async fn test_reload_failure_keeps_previous_config() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let config_path = temp_dir.path().join("config.toml");
+        let mut manager = create_running_manager(&config_path).await;
+
+        std::fs::write(&config_path, "this is not [valid toml").unwrap();
+
+        assert!(manager.reload_config().await.is_err());
+        assert_eq!(manager.get_state().await, LifecycleState::Running);
+        assert_eq!(manager.config.read().await.modes.len(), 2);
+
+        // A later valid reload still works
+        std::fs::write(&config_path, RELOAD_CONFIG).unwrap();
+        assert!(manager.reload_config().await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_plugins_checked_on_startup_and_reload() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let plugins_dir = temp_dir.path().join("plugins");
+        std::fs::create_dir_all(plugins_dir.join("notify")).unwrap();
+        std::fs::write(
+            plugins_dir.join("notify").join("plugin.toml"),
+            "[plugin]\nname = \"notify\"\nversion = \"1.0.0\"\ndescription = \"Test\"\n\
+             author = \"Test\"\nlicense = \"MIT\"\ntype = \"action\"\nbinary = \"notify.so\"\n",
+        )
+        .unwrap();
+
+        let config_path = temp_dir.path().join("config.toml");
+        let write_config = |plugin: &str| {
+            let mapping = format!(
+                "\n[[modes.mappings]]\ntrigger = {{ type = \"Note\", note = 36 }}\n\
+                 action = {{ type = \"Plugin\", plugin = \"{plugin}\" }}\n"
+            );
+            std::fs::write(&config_path, format!("{}{}", RELOAD_CONFIG, mapping)).unwrap();
+        };
+        let start = |config_path: PathBuf| {
+            let plugins_dir = plugins_dir.clone();
+            async move {
+                let config = Config::load(config_path.to_str().unwrap()).unwrap();
+                let (cmd_tx, cmd_rx) = mpsc::channel(10);
+                let (shutdown_tx, _shutdown_rx) = broadcast::channel(1);
+                let manager =
+                    EngineManager::new(config, config_path, cmd_rx, cmd_tx, shutdown_tx).unwrap();
+                *manager.action_executor.lock().await.plugin_manager() =
+                    PluginManager::new(plugins_dir);
+                let started = manager.start_plugins().await;
+                (manager, started)
+            }
+        };
+
+        // A plugin that isn't installed keeps the daemon from starting
+        write_config("obs");
+        let (_, started) = start(config_path.clone()).await;
+        assert!(started.is_err());
+
+        // An installed plugin passes; without its binary, it fails to load
+        write_config("notify");
+        let (mut manager, started) = start(config_path.clone()).await;
+        started.unwrap();
+        assert!(
+            manager
+                .action_executor
+                .lock()
+                .await
+                .plugin_manager()
+                .is_discovered()
+        );
+        let errors = manager.get_recent_errors().await;
+        assert!(errors.iter().any(|e| e.kind == "PluginLoadFailed"));
+
+        // A reload using a plugin that isn't installed keeps the previous config
+        manager
+            .transition_state(LifecycleState::Starting)
+            .await
+            .unwrap();
+        manager
+            .transition_state(LifecycleState::Running)
+            .await
+            .unwrap();
+        write_config("obs");
+        assert!(manager.reload_config().await.is_err());
+        assert_eq!(manager.config.read().await.plugins(), vec!["notify"]);
+    }
+
+    #[test]
+    fn test_find_mode_index() {
+        let config = create_test_config();
+
+        assert_eq!(find_mode_index(&config, "Default"), Some(0));
+        assert_eq!(find_mode_index(&config, "Development"), Some(1));
+        assert_eq!(find_mode_index(&config, "Missing"), None);
+    }
+
+    #[tokio::test]
+    async fn test_set_mode_by_name() {
+        let config = create_test_config();
+        let (cmd_tx, cmd_rx) = mpsc::channel(10);
+        let (shutdown_tx, _shutdown_rx) = broadcast::channel(1);
+
+        let manager = EngineManager::new(
+            config,
+            PathBuf::from("/tmp/test.toml"),
+            cmd_rx,
+            cmd_tx,
+            shutdown_tx,
+        )
+        .unwrap();
+
+        assert_eq!(manager.current_mode().await, (0, "Default".to_string()));
+
+        assert_eq!(manager.set_mode("Development").await, Some(1));
+        assert_eq!(manager.current_mode().await, (1, "Development".to_string()));
+
+        // Unknown modes leave the active mode unchanged
+        assert_eq!(manager.set_mode("Missing").await, None);
+        assert_eq!(manager.current_mode().await, (1, "Development".to_string()));
+
+        let engine_info = manager.get_engine_info().await;
+        assert_eq!(engine_info.current_mode, "Development");
+        assert_eq!(engine_info.current_mode_index, 1);
+    }
+
+    #[tokio::test]
+    async fn test_restore_mode() {
+        let config = create_test_config();
+        let (cmd_tx, cmd_rx) = mpsc::channel(10);
+        let (shutdown_tx, _shutdown_rx) = broadcast::channel(1);
+
+        let manager = EngineManager::new(
+            config,
+            PathBuf::from("/tmp/test.toml"),
+            cmd_rx,
+            cmd_tx,
+            shutdown_tx,
+        )
+        .unwrap();
+
+        let mut engine_info = manager.get_engine_info().await;
+        engine_info.current_mode_index = 1;
+        manager.restore_mode(&engine_info).await;
+        assert_eq!(manager.current_mode().await.0, 1);
+
+        // Out-of-range indices are ignored
+        engine_info.current_mode_index = 99;
+        manager.restore_mode(&engine_info).await;
+        assert_eq!(manager.current_mode().await.0, 1);
+    }
 }
@@ -14,7 +14,7 @@ use std::path::PathBuf;
 use std::process;
 use std::time::{SystemTime, UNIX_EPOCH};
 use tokio::sync::{broadcast, mpsc};
-use tracing::{error, info};
+use tracing::{error, info, warn};
 
 /// Main daemon service coordinating all components
 pub struct DaemonService {
@@ -76,6 +76,16 @@ impl DaemonService {
             self.shutdown_tx.clone(),
         )?;
 
+        // Restore the active mode and variables from the previous run
+        match self.state_manager.load().await {
+            Ok(Some(state)) => {
+                engine_manager.restore_mode(&state.engine).await;
+                engine_manager.restore_variables(&state.engine).await;
+            }
+            Ok(None) => {}
+            Err(e) => warn!("Failed to load persisted state: {}", e),
+        }
+
         // Create IPC server
         let shutdown_rx_ipc = self.shutdown_tx.subscribe();
         let mut ipc_server = IpcServer::new(self.command_tx.clone(), shutdown_rx_ipc)?;
@@ -5,7 +5,9 @@
 
 use crate::daemon::error::{DaemonError, Result};
 use crate::daemon::types::{DaemonStatistics, DeviceStatus, ErrorEntry, LifecycleState};
+use conductor_core::VariableValue;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::{SystemTime, UNIX_EPOCH};
@@ -43,6 +45,9 @@ pub struct EngineInfo {
     pub current_mode: String,
     pub current_mode_index: usize,
     pub device_status: DeviceStatus,
+    /// Runtime variables, when the config persists them
+    #[serde(default)]
+    pub variables: HashMap<String, VariableValue>,
 }
 
 impl PersistedState {
@@ -452,6 +457,7 @@ mod tests {
                 current_mode: "Default".to_string(),
                 current_mode_index: 0,
                 device_status: DeviceStatus::default(),
+                variables: HashMap::from([("muted".to_string(), VariableValue::Bool(true))]),
             },
             DaemonStatistics::default(),
             vec![],
@@ -469,6 +475,10 @@ mod tests {
         assert_eq!(loaded.daemon.pid, 12345);
         assert_eq!(loaded.config.path, PathBuf::from("/tmp/config.toml"));
         assert_eq!(loaded.engine.current_mode, "Default");
+        assert_eq!(
+            loaded.engine.variables.get("muted"),
+            Some(&VariableValue::Bool(true))
+        );
     }
 
     #[tokio::test]
@@ -96,6 +96,12 @@ pub enum DaemonCommand {
     /// Gamepad reconnected (v3.0)
     ReconnectGamepad,
 
+    /// ModeChange action run by a background action job
+    ModeChange(String),
+
+    /// Background action job finished with failed actions
+    ActionFailed { mapping: String, error: String },
+
     /// Device reconnection failed after max attempts
     DeviceReconnectionFailed,
 
@@ -129,6 +135,11 @@ pub enum IpcCommand {
     ListDevices,
     SetDevice,
     GetDevice,
+
+    // Action state
+    GetCycles,
+    ListJobs,
+    CancelJob,
 }
 
 /// IPC response to client
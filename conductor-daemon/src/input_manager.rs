@@ -352,50 +352,89 @@ impl InputManager {
 /// - `MidiEvent::ControlChange` → `InputEvent::EncoderTurned` (value, timestamp)
 /// - `MidiEvent::Aftertouch` → `InputEvent::Aftertouch` (pressure, timestamp)
 /// - `MidiEvent::PitchBend` → `InputEvent::PitchBend` (value, timestamp)
+/// - `MidiEvent::HighResCC` → `InputEvent::HighResControlChange` (14-bit value, timestamp)
+/// - `MidiEvent::Nrpn`/`Rpn` → `InputEvent::Nrpn`/`Rpn` (parameter, value, timestamp)
+/// - `MidiEvent::SysEx` → `InputEvent::SysEx` (message bytes, timestamp)
+/// - `MidiEvent::Clock`/`Transport`/`SongPosition` → the same `InputEvent` (timestamp)
+///
+/// The MIDI channel is carried over unchanged so channel filters keep working.
 ///
 /// Note: MIDI uses note numbers 0-127, gamepad uses IDs 128-255.
 /// This conversion preserves the MIDI range, allowing both to coexist.
 fn convert_midi_to_input(midi_event: MidiEvent) -> InputEvent {
     let now = Instant::now();
+    let channel = midi_event.channel();
 
     match midi_event {
         MidiEvent::NoteOn {
             note, velocity, ..
         } => InputEvent::PadPressed {
+            channel,
             pad: note,
             velocity,
             time: now,
         },
         MidiEvent::NoteOff { note, .. } => InputEvent::PadReleased {
+            channel,
             pad: note,
             time: now,
         },
         MidiEvent::ControlChange { cc, value, .. } => InputEvent::EncoderTurned {
+            channel,
             encoder: cc,
             value,
             time: now,
         },
         MidiEvent::Aftertouch { pressure, .. } => InputEvent::Aftertouch {
+            channel,
             pressure,
             time: now,
         },
         MidiEvent::PitchBend { value, .. } => InputEvent::PitchBend {
+            channel,
             value,
             time: now,
         },
-        MidiEvent::PolyPressure { pressure, .. } => InputEvent::Aftertouch {
+        MidiEvent::PolyPressure { note, pressure, .. } => InputEvent::PolyPressure {
+            channel,
+            pad: note,
             pressure,
             time: now,
         },
-        MidiEvent::ProgramChange { .. } => {
-            // Program Change doesn't have a direct InputEvent mapping
-            // For now, ignore it or create a generic InputEvent variant in the future
-            InputEvent::PadPressed {
-                pad: 0,
-                velocity: 0,
-                time: now,
-            }
-        }
+        MidiEvent::HighResCC { cc, value, .. } => InputEvent::HighResControlChange {
+            channel,
+            control: cc,
+            value,
+            time: now,
+        },
+        MidiEvent::Nrpn {
+            parameter, value, ..
+        } => InputEvent::Nrpn {
+            channel,
+            parameter,
+            value,
+            time: now,
+        },
+        MidiEvent::Rpn {
+            parameter, value, ..
+        } => InputEvent::Rpn {
+            channel,
+            parameter,
+            value,
+            time: now,
+        },
+        MidiEvent::SysEx { data, .. } => InputEvent::SysEx { data, time: now },
+        MidiEvent::Clock { .. } => InputEvent::Clock { time: now },
+        MidiEvent::Transport { command, .. } => InputEvent::Transport { command, time: now },
+        MidiEvent::SongPosition { position, .. } => InputEvent::SongPosition {
+            position,
+            time: now,
+        },
+        MidiEvent::ProgramChange { program, .. } => InputEvent::ProgramChange {
+            channel,
+            program,
+            time: now,
+        },
     }
 }
 
@@ -438,6 +477,7 @@ mod tests {
     #[test]
     fn test_convert_midi_note_on() {
         let midi_event = MidiEvent::NoteOn {
+            channel: 0,
             note: 60,
             velocity: 100,
             time: Instant::now(),
@@ -456,6 +496,7 @@ mod tests {
     #[test]
     fn test_convert_midi_note_off() {
         let midi_event = MidiEvent::NoteOff {
+            channel: 0,
             note: 60,
             time: Instant::now(),
         };
@@ -472,6 +513,7 @@ mod tests {
     #[test]
     fn test_convert_midi_cc() {
         let midi_event = MidiEvent::ControlChange {
+            channel: 0,
             cc: 7,
             value: 64,
             time: Instant::now(),
@@ -486,4 +528,46 @@ mod tests {
             _ => panic!("Expected EncoderTurned"),
         }
     }
+
+    #[test]
+    fn test_convert_midi_poly_pressure() {
+        let midi_event = MidiEvent::PolyPressure {
+            channel: 2,
+            note: 36,
+            pressure: 90,
+            time: Instant::now(),
+        };
+        let input_event = convert_midi_to_input(midi_event);
+
+        match input_event {
+            InputEvent::PolyPressure {
+                channel,
+                pad,
+                pressure,
+                ..
+            } => {
+                assert_eq!(channel, 2);
+                assert_eq!(pad, 36);
+                assert_eq!(pressure, 90);
+            }
+            _ => panic!("Expected PolyPressure"),
+        }
+    }
+
+    #[test]
+    fn test_convert_midi_program_change() {
+        let midi_event = MidiEvent::ProgramChange {
+            channel: 0,
+            program: 5,
+            time: Instant::now(),
+        };
+        let input_event = convert_midi_to_input(midi_event);
+
+        match input_event {
+            InputEvent::ProgramChange { program, .. } => {
+                assert_eq!(program, 5);
+            }
+            _ => panic!("Expected ProgramChange"),
+        }
+    }
 }
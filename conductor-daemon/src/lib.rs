@@ -165,6 +165,7 @@
 //! - [`daemon::error`] - Error types and handling
 
 pub mod action_executor;
+pub mod action_jobs;
 pub mod conditions;
 pub mod daemon;
 pub mod gamepad_device; // HID device management - Game Controllers (v3.0)
@@ -183,7 +184,12 @@ pub use daemon::{
 };
 
 // Re-export ActionExecutor, TriggerContext, and helpers for daemon use
-pub use action_executor::{ActionExecutor, TriggerContext, parse_command_line};
+pub use action_executor::{
+    ActionError, ActionExecutor, TriggerContext, expand_placeholders, parse_command_line,
+};
+
+// Re-export background action jobs for daemon use
+pub use action_jobs::{ActionJobs, JobInfo};
 
 // Re-export condition evaluation for daemon use
 pub use conditions::{ConditionContext, evaluate_condition};
@@ -73,7 +73,7 @@
 //! # }
 //! ```
 
-use conductor_core::event_processor::MidiEvent;
+use conductor_core::event_processor::{HighResDecoder, MidiEvent};
 use midir::{MidiInput, MidiInputConnection, MidiInputPort};
 use std::sync::{Arc, Mutex};
 use std::thread;
@@ -247,6 +247,9 @@ impl MidiDeviceManager {
 
         debug!("Opening MIDI port {} (index {})", port_name, port_index);
 
+        // Pairs up 14-bit CC and NRPN/RPN messages across callbacks
+        let mut decoder = HighResDecoder::default();
+
         // Create callback that parses and sends events
         let callback = move |_timestamp: u64, message: &[u8], _: &mut ()| {
             trace!(
@@ -258,18 +261,20 @@ impl MidiDeviceManager {
             // Parse MIDI message using midi-msg library
             match MidiEvent::from_midi_msg(message) {
                 Ok(event) => {
-                    // Non-blocking send to prevent callback blocking
-                    if let Err(e) = event_tx.try_send(event.clone()) {
-                        match e {
-                            mpsc::error::TrySendError::Full(_) => {
-                                warn!("Event channel full, dropping event: {:?}", event);
-                            }
-                            mpsc::error::TrySendError::Closed(_) => {
-                                error!("Event channel closed, cannot send event");
+                    for event in decoder.decode(event) {
+                        // Non-blocking send to prevent callback blocking
+                        if let Err(e) = event_tx.try_send(event.clone()) {
+                            match e {
+                                mpsc::error::TrySendError::Full(_) => {
+                                    warn!("Event channel full, dropping event: {:?}", event);
+                                }
+                                mpsc::error::TrySendError::Closed(_) => {
+                                    error!("Event channel closed, cannot send event");
+                                }
                             }
+                        } else {
+                            trace!("Successfully sent event: {:?}", event);
                         }
-                    } else {
-                        trace!("Successfully sent event: {:?}", event);
                     }
                 }
                 Err(e) => {
@@ -375,6 +380,9 @@ impl MidiDeviceManager {
 
         debug!("Opening MIDI port {} (index {})", port_name, port_index);
 
+        // Pairs up 14-bit CC and NRPN/RPN messages across callbacks
+        let mut decoder = HighResDecoder::default();
+
         // Create callback that parses and sends events
         let callback = move |_timestamp: u64, message: &[u8], _: &mut ()| {
             trace!(
@@ -386,18 +394,20 @@ impl MidiDeviceManager {
             // Parse MIDI message using midi-msg library
             match MidiEvent::from_midi_msg(message) {
                 Ok(event) => {
-                    // Non-blocking send to prevent callback blocking
-                    if let Err(e) = event_tx.try_send(event.clone()) {
-                        match e {
-                            mpsc::error::TrySendError::Full(_) => {
-                                warn!("Event channel full, dropping event: {:?}", event);
-                            }
-                            mpsc::error::TrySendError::Closed(_) => {
-                                error!("Event channel closed, cannot send event");
+                    for event in decoder.decode(event) {
+                        // Non-blocking send to prevent callback blocking
+                        if let Err(e) = event_tx.try_send(event.clone()) {
+                            match e {
+                                mpsc::error::TrySendError::Full(_) => {
+                                    warn!("Event channel full, dropping event: {:?}", event);
+                                }
+                                mpsc::error::TrySendError::Closed(_) => {
+                                    error!("Event channel closed, cannot send event");
+                                }
                             }
+                        } else {
+                            trace!("Successfully sent event: {:?}", event);
                         }
-                    } else {
-                        trace!("Successfully sent event: {:?}", event);
                     }
                 }
                 Err(e) => {
@@ -704,6 +714,9 @@ impl MidiDeviceManager {
             .port_name(&port)
             .unwrap_or_else(|_| format!("Port {}", port_index));
 
+        // Pairs up 14-bit CC and NRPN/RPN messages across callbacks
+        let mut decoder = HighResDecoder::default();
+
         // Create callback
         let callback = move |_timestamp: u64, message: &[u8], _: &mut ()| {
             trace!(
@@ -714,17 +727,19 @@ impl MidiDeviceManager {
 
             match MidiEvent::from_midi_msg(message) {
                 Ok(event) => {
-                    if let Err(e) = event_tx.try_send(event.clone()) {
-                        match e {
-                            mpsc::error::TrySendError::Full(_) => {
-                                warn!("Event channel full, dropping event: {:?}", event);
-                            }
-                            mpsc::error::TrySendError::Closed(_) => {
-                                error!("Event channel closed, cannot send event");
+                    for event in decoder.decode(event) {
+                        if let Err(e) = event_tx.try_send(event.clone()) {
+                            match e {
+                                mpsc::error::TrySendError::Full(_) => {
+                                    warn!("Event channel full, dropping event: {:?}", event);
+                                }
+                                mpsc::error::TrySendError::Closed(_) => {
+                                    error!("Event channel closed, cannot send event");
+                                }
                             }
+                        } else {
+                            trace!("Successfully sent event: {:?}", event);
                         }
-                    } else {
-                        trace!("Successfully sent event: {:?}", event);
                     }
                 }
                 Err(e) => {
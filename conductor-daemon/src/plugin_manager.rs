@@ -20,6 +20,7 @@ use conductor_core::plugin::{
     Capability, LoadedPlugin, PluginDiscovery, PluginLoader, PluginLoaderError, PluginMetadata,
     PluginRegistry, TriggerContext,
 };
+use conductor_core::{Config, PluginCatalog};
 use serde_json::Value;
 
 #[cfg(feature = "plugin-wasm")]
@@ -414,16 +415,7 @@ impl PluginManager {
         };
 
         // Determine granted capabilities
-        let granted_capabilities = if self.auto_grant_safe {
-            metadata
-                .capabilities
-                .iter()
-                .filter(|cap| cap.risk_level().is_safe())
-                .cloned()
-                .collect()
-        } else {
-            Vec::new() // No capabilities granted by default
-        };
+        let granted_capabilities = self.auto_granted(&metadata.capabilities);
 
         // Create managed plugin
         let managed = ManagedPlugin {
@@ -579,14 +571,11 @@ impl PluginManager {
         }
 
         // Check capabilities
-        for required_cap in &managed.metadata.capabilities {
-            if !managed.granted_capabilities.contains(required_cap) {
-                return Err(PluginManagerError::PermissionDenied {
-                    plugin: plugin_name.to_string(),
-                    capability: required_cap.name().to_string(),
-                });
-            }
-        }
+        Self::check_granted(
+            plugin_name,
+            &managed.metadata.capabilities,
+            &managed.granted_capabilities,
+        )?;
 
         // Execute plugin (synchronous wrapper for both native and WASM)
         let result = managed
@@ -622,6 +611,91 @@ impl PluginManager {
         })
     }
 
+    /// Check that a plugin can be executed
+    ///
+    /// The plugin must be installed and, if loaded, enabled. Its capabilities
+    /// must be granted; a plugin not loaded yet is checked against the
+    /// capabilities `load_plugin()` would grant it.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error `execute_plugin()` would fail with.
+    pub fn check_runnable(&self, plugin_name: &str) -> PluginManagerResult<()> {
+        let plugins = self
+            .plugins
+            .read()
+            .map_err(|e| PluginManagerError::LockError(e.to_string()))?;
+
+        if let Some(managed) = plugins.get(plugin_name) {
+            if !managed.enabled {
+                return Err(PluginManagerError::PluginDisabled(plugin_name.to_string()));
+            }
+            return Self::check_granted(
+                plugin_name,
+                &managed.metadata.capabilities,
+                &managed.granted_capabilities,
+            );
+        }
+        drop(plugins);
+
+        let metadata = self.get_metadata(plugin_name)?;
+        let granted = self.auto_granted(&metadata.capabilities);
+        Self::check_granted(plugin_name, &metadata.capabilities, &granted)
+    }
+
+    /// Whether plugins have been discovered, so plugin actions can be checked
+    pub fn is_discovered(&self) -> bool {
+        self.registry
+            .read()
+            .is_ok_and(|registry| registry.is_some())
+    }
+
+    /// Load the plugins used by a config's `Plugin` actions that aren't loaded
+    /// yet
+    ///
+    /// Returns the plugins that failed to load, with their errors.
+    pub fn load_config_plugins(&mut self, config: &Config) -> Vec<(String, PluginManagerError)> {
+        let loaded = self.list_loaded().unwrap_or_default();
+        let mut failed = Vec::new();
+        for plugin in config.plugins() {
+            if loaded.iter().any(|name| name == plugin) {
+                continue;
+            }
+            if let Err(e) = self.load_plugin(plugin) {
+                failed.push((plugin.to_string(), e));
+            }
+        }
+        failed
+    }
+
+    /// Capabilities granted when a plugin requesting `requested` is loaded
+    fn auto_granted(&self, requested: &[Capability]) -> Vec<Capability> {
+        if self.auto_grant_safe {
+            requested
+                .iter()
+                .filter(|cap| cap.risk_level().is_safe())
+                .cloned()
+                .collect()
+        } else {
+            Vec::new() // No capabilities granted by default
+        }
+    }
+
+    /// Fail with `PermissionDenied` for the first required capability not granted
+    fn check_granted(
+        plugin_name: &str,
+        required: &[Capability],
+        granted: &[Capability],
+    ) -> PluginManagerResult<()> {
+        match required.iter().find(|cap| !granted.contains(cap)) {
+            Some(capability) => Err(PluginManagerError::PermissionDenied {
+                plugin: plugin_name.to_string(),
+                capability: capability.name().to_string(),
+            }),
+            None => Ok(()),
+        }
+    }
+
     /// Get plugin statistics
     pub fn get_stats(&self, plugin_name: &str) -> PluginManagerResult<PluginStats> {
         let plugins = self
@@ -676,6 +750,12 @@ impl PluginManager {
     }
 }
 
+impl PluginCatalog for PluginManager {
+    fn check_plugin(&self, plugin: &str) -> Result<(), String> {
+        self.check_runnable(plugin).map_err(|e| e.to_string())
+    }
+}
+
 impl Default for PluginManager {
     fn default() -> Self {
         let plugins_dir = dirs::home_dir()
@@ -769,4 +849,110 @@ mod tests {
         manager.set_auto_grant_safe(false);
         assert!(!manager.auto_grant_safe);
     }
+
+    #[test]
+    fn test_validate_config_plugins() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        for (name, capability) in [("notify", "midi"), ("runner", "subprocess")] {
+            let plugin_dir = temp_dir.path().join(name);
+            fs::create_dir(&plugin_dir).unwrap();
+            let manifest = format!(
+                "[plugin]\nname = \"{name}\"\nversion = \"1.0.0\"\ndescription = \"Test\"\n\
+                 author = \"Test\"\nlicense = \"MIT\"\ntype = \"action\"\n\
+                 binary = \"{name}.so\"\n\n[plugin.capabilities]\n{capability} = true\n"
+            );
+            fs::write(plugin_dir.join("plugin.toml"), manifest).unwrap();
+        }
+        let mut manager = PluginManager::new(temp_dir.path().to_path_buf());
+        assert!(!manager.is_discovered());
+        manager.discover_plugins().unwrap();
+        assert!(manager.is_discovered());
+
+        let config_with_plugin = |plugin: &str| -> conductor_core::Config {
+            toml::from_str(&format!(
+                r#"
+                [device]
+                name = "Test"
+                auto_connect = false
+
+                [[modes]]
+                name = "Default"
+
+                [[modes.mappings]]
+                trigger = {{ type = "Note", note = 36 }}
+                action = {{ type = "Plugin", plugin = "{plugin}", params = {{ scene = "Live" }} }}
+            "#
+            ))
+            .unwrap()
+        };
+
+        // MIDI is granted automatically, subprocess isn't
+        assert!(
+            config_with_plugin("notify")
+                .validate_with_plugins(&manager)
+                .is_ok()
+        );
+        let denied = config_with_plugin("runner").validate_with_plugins(&manager);
+        let message = denied.unwrap_err().to_string();
+        assert!(message.contains("Process Execution"), "{}", message);
+        let missing = config_with_plugin("obs").validate_with_plugins(&manager);
+        assert!(missing.unwrap_err().to_string().contains("not found"));
+
+        // Plugins nested in a Parallel are checked too
+        let mut nested = config_with_plugin("runner");
+        let mapping = &mut nested.modes[0].mappings[0];
+        mapping.action = conductor_core::ActionConfig::Parallel {
+            actions: vec![
+                conductor_core::ActionConfig::Text {
+                    text: "go".to_string(),
+                },
+                mapping.action.clone(),
+            ],
+            wait: true,
+        };
+        assert!(nested.validate_with_plugins(&manager).is_err());
+    }
+
+    #[test]
+    fn test_load_config_plugins() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        for name in ["notify", "unused"] {
+            let plugin_dir = temp_dir.path().join(name);
+            fs::create_dir(&plugin_dir).unwrap();
+            let manifest = format!(
+                "[plugin]\nname = \"{name}\"\nversion = \"1.0.0\"\ndescription = \"Test\"\n\
+                 author = \"Test\"\nlicense = \"MIT\"\ntype = \"action\"\n\
+                 binary = \"{name}.so\"\n"
+            );
+            fs::write(plugin_dir.join("plugin.toml"), manifest).unwrap();
+        }
+        let mut manager = PluginManager::new(temp_dir.path().to_path_buf());
+        manager.discover_plugins().unwrap();
+
+        let config: conductor_core::Config = toml::from_str(
+            r#"
+            [device]
+            name = "Test"
+            auto_connect = false
+
+            [[modes]]
+            name = "Default"
+
+            [[modes.mappings]]
+            trigger = { type = "Note", note = 36 }
+            action = { type = "Plugin", plugin = "notify" }
+
+            [[modes.mappings]]
+            trigger = { type = "Note", note = 37 }
+            action = { type = "Plugin", plugin = "notify", params = { level = 2 } }
+        "#,
+        )
+        .unwrap();
+
+        // Only the plugin the config uses is loaded, once; its binary is missing
+        let failed = manager.load_config_plugins(&config);
+        assert_eq!(failed.len(), 1);
+        assert_eq!(failed[0].0, "notify");
+        assert!(manager.list_loaded().unwrap().is_empty());
+    }
 }
@@ -22,6 +22,7 @@ async fn test_event_processor_midi_to_processed() {
 
     // Create a Note On event
     let midi_event = MidiEvent::NoteOn {
+        channel: 0,
         note: 60,
         velocity: 100,
         time: Instant::now(),
@@ -61,17 +62,25 @@ async fn test_mapping_engine_event_to_action() {
                 trigger: conductor_core::config::types::Trigger::Note {
                     note: 60,
                     velocity_min: None,
+                    channel: None,
                 },
                 action: conductor_core::config::types::ActionConfig::Keystroke {
                     keys: "Space".to_string(),
                     modifiers: vec![],
                 },
                 description: None,
+                immediate: false,
+                on_release: None,
+                momentary: false,
+                continuous: None,
+                while_held: vec![],
+                concurrency: Default::default(),
             }],
         }],
         global_mappings: vec![],
         logging: None,
         advanced_settings: Default::default(),
+        variables: Default::default(),
     };
 
     // Create mapping engine and load config
@@ -80,6 +89,7 @@ async fn test_mapping_engine_event_to_action() {
 
     // Create a Note On event that matches the mapping
     let midi_event = MidiEvent::NoteOn {
+        channel: 0,
         note: 60,
         velocity: 100,
         time: Instant::now(),
@@ -106,6 +116,7 @@ async fn test_mapping_engine_event_to_action() {
 async fn test_velocity_context_propagation() {
     // Create a MIDI event with specific velocity
     let midi_event = MidiEvent::NoteOn {
+        channel: 0,
         note: 60,
         velocity: 85,
         time: Instant::now(),
@@ -118,6 +129,10 @@ async fn test_velocity_context_propagation() {
             _ => None,
         },
         current_mode: None,
+        note: None,
+        offset: None,
+        captures: Vec::new(),
+        clock: None,
     };
 
     // Verify context has correct velocity
@@ -147,6 +162,7 @@ async fn test_midi_event_channel() {
 
     // Send a MIDI event
     let midi_event = MidiEvent::NoteOn {
+        channel: 0,
         note: 60,
         velocity: 100,
         time: Instant::now(),
@@ -211,14 +227,22 @@ async fn test_end_to_end_pipeline() {
                 trigger: conductor_core::config::types::Trigger::Note {
                     note: 36,
                     velocity_min: None,
+                    channel: None,
                 },
                 action: conductor_core::config::types::ActionConfig::Delay { ms: 1 },
                 description: None,
+                immediate: false,
+                on_release: None,
+                momentary: false,
+                continuous: None,
+                while_held: vec![],
+                concurrency: Default::default(),
             }],
         }],
         global_mappings: vec![],
         logging: None,
         advanced_settings: Default::default(),
+        variables: Default::default(),
     };
 
     // Create components
@@ -229,6 +253,7 @@ async fn test_end_to_end_pipeline() {
 
     // Step 1: Create MIDI event
     let midi_event = MidiEvent::NoteOn {
+        channel: 0,
         note: 36,
         velocity: 120,
         time: Instant::now(),
@@ -246,6 +271,10 @@ async fn test_end_to_end_pipeline() {
     let context = TriggerContext {
         velocity: Some(120),
         current_mode: None,
+        note: None,
+        offset: None,
+        captures: Vec::new(),
+        clock: None,
     };
 
     action_executor.execute(action.unwrap(), Some(context));
@@ -260,6 +289,7 @@ async fn test_multiple_midi_event_types() {
 
     // Test Note On
     let note_on = MidiEvent::NoteOn {
+        channel: 0,
         note: 60,
         velocity: 100,
         time: Instant::now(),
@@ -269,6 +299,7 @@ async fn test_multiple_midi_event_types() {
 
     // Test Note Off
     let note_off = MidiEvent::NoteOff {
+        channel: 0,
         note: 60,
         time: Instant::now(),
     };
@@ -277,6 +308,7 @@ async fn test_multiple_midi_event_types() {
 
     // Test Control Change (requires two events to detect direction change)
     let cc1 = MidiEvent::ControlChange {
+        channel: 0,
         cc: 1,
         value: 64,
         time: Instant::now(),
@@ -286,6 +318,7 @@ async fn test_multiple_midi_event_types() {
     assert!(result1.is_empty(), "First CC event should store baseline");
 
     let cc2 = MidiEvent::ControlChange {
+        channel: 0,
         cc: 1,
         value: 80,
         time: Instant::now(),
@@ -303,6 +336,7 @@ async fn test_multiple_midi_event_types() {
 async fn test_velocity_extraction() {
     // Note On with velocity
     let note_on = MidiEvent::NoteOn {
+        channel: 0,
         note: 60,
         velocity: 95,
         time: Instant::now(),
@@ -314,6 +348,10 @@ async fn test_velocity_extraction() {
             _ => None,
         },
         current_mode: None,
+        note: None,
+        offset: None,
+        captures: Vec::new(),
+        clock: None,
     };
 
     assert_eq!(
@@ -324,6 +362,7 @@ async fn test_velocity_extraction() {
 
     // Note Off (no velocity in our implementation)
     let note_off = MidiEvent::NoteOff {
+        channel: 0,
         note: 60,
         time: Instant::now(),
     };
@@ -334,6 +373,10 @@ async fn test_velocity_extraction() {
             _ => None,
         },
         current_mode: None,
+        note: None,
+        offset: None,
+        captures: Vec::new(),
+        clock: None,
     };
 
     assert_eq!(
@@ -350,11 +393,13 @@ async fn test_event_channel_backpressure() {
 
     // Fill the channel
     let event1 = MidiEvent::NoteOn {
+        channel: 0,
         note: 60,
         velocity: 100,
         time: Instant::now(),
     };
     let event2 = MidiEvent::NoteOn {
+        channel: 0,
         note: 61,
         velocity: 101,
         time: Instant::now(),
@@ -365,6 +410,7 @@ async fn test_event_channel_backpressure() {
 
     // Channel is now full, try_send should fail
     let event3 = MidiEvent::NoteOn {
+        channel: 0,
         note: 62,
         velocity: 102,
         time: Instant::now(),
@@ -378,6 +424,7 @@ async fn test_event_channel_backpressure() {
 
     // Now try_send should succeed
     let event4 = MidiEvent::NoteOn {
+        channel: 0,
         note: 63,
         velocity: 103,
         time: Instant::now(),
@@ -413,6 +460,7 @@ async fn test_device_disconnect_reconnect() {
 
     // Verify event channel still works after disconnect
     let test_event = MidiEvent::NoteOn {
+        channel: 0,
         note: 60,
         velocity: 100,
         time: Instant::now(),
@@ -14,38 +14,47 @@ pub enum TriggerConfig {
     Note {
         note: u8,
         velocity_min: Option<u8>,
+        channel: Option<u8>,
     },
     VelocityRange {
         note: u8,
         soft_max: Option<u8>,
         medium_max: Option<u8>,
+        channel: Option<u8>,
     },
     LongPress {
         note: u8,
         duration_ms: Option<u64>,
+        channel: Option<u8>,
     },
     DoubleTap {
         note: u8,
         timeout_ms: Option<u64>,
+        channel: Option<u8>,
     },
     NoteChord {
         notes: Vec<u8>,
         timeout_ms: Option<u64>,
+        channel: Option<u8>,
     },
     EncoderTurn {
         cc: u8,
         direction: Option<String>,
+        channel: Option<u8>,
     },
     CC {
         cc: u8,
         value_min: Option<u8>,
+        channel: Option<u8>,
     },
     Aftertouch {
         pressure_min: Option<u8>,
+        channel: Option<u8>,
     },
     PitchBend {
         value_min: Option<u16>,
         value_max: Option<u16>,
+        channel: Option<u8>,
     },
     // Gamepad trigger types (v3.0)
     GamepadButton {
@@ -72,15 +81,18 @@ pub fn suggestion_to_config(suggestion: &TriggerSuggestion) -> TriggerConfig {
         TriggerSuggestion::Note {
             note,
             velocity_range,
+            channel,
         } => TriggerConfig::Note {
             note: *note,
             velocity_min: velocity_range.map(|(min, _)| min),
+            channel: *channel,
         },
         TriggerSuggestion::VelocityRange {
             note,
             velocity_min: _,
             velocity_max,
             level,
+            channel,
         } => {
             // Convert back to soft_max/medium_max format
             let (soft_max, medium_max) = match level.as_str() {
@@ -94,39 +106,70 @@ pub fn suggestion_to_config(suggestion: &TriggerSuggestion) -> TriggerConfig {
                 note: *note,
                 soft_max,
                 medium_max,
+                channel: *channel,
             }
         }
-        TriggerSuggestion::LongPress { note, duration_ms } => TriggerConfig::LongPress {
+        TriggerSuggestion::LongPress {
+            note,
+            duration_ms,
+            channel,
+        } => TriggerConfig::LongPress {
             note: *note,
             duration_ms: Some(*duration_ms),
+            channel: *channel,
         },
-        TriggerSuggestion::DoubleTap { note, timeout_ms } => TriggerConfig::DoubleTap {
+        TriggerSuggestion::DoubleTap {
+            note,
+            timeout_ms,
+            channel,
+        } => TriggerConfig::DoubleTap {
             note: *note,
             timeout_ms: Some(*timeout_ms),
+            channel: *channel,
         },
-        TriggerSuggestion::Chord { notes, window_ms } => TriggerConfig::NoteChord {
+        TriggerSuggestion::Chord {
+            notes,
+            window_ms,
+            channel,
+        } => TriggerConfig::NoteChord {
             notes: notes.clone(),
             timeout_ms: Some(*window_ms),
+            channel: *channel,
         },
-        TriggerSuggestion::Encoder { cc, direction } => TriggerConfig::EncoderTurn {
+        TriggerSuggestion::Encoder {
+            cc,
+            direction,
+            channel,
+        } => TriggerConfig::EncoderTurn {
             cc: *cc,
             direction: direction.clone(),
+            channel: *channel,
         },
-        TriggerSuggestion::CC { cc, value_range } => TriggerConfig::CC {
+        TriggerSuggestion::CC {
+            cc,
+            value_range,
+            channel,
+        } => TriggerConfig::CC {
             cc: *cc,
             value_min: value_range.map(|(min, _)| min),
+            channel: *channel,
         },
         TriggerSuggestion::Aftertouch {
             note: _,
             pressure_range,
+            channel,
         } => {
             // Note: current midimon-core Aftertouch doesn't have note field,
             // only pressure_min, so we ignore the note for now
             TriggerConfig::Aftertouch {
                 pressure_min: Some(pressure_range.0),
+                channel: *channel,
             }
         }
-        TriggerSuggestion::PitchBend { bend_range } => {
+        TriggerSuggestion::PitchBend {
+            bend_range,
+            channel,
+        } => {
             // Convert i16 to u16 (0-16383 range)
             let min_u16 = (bend_range.0 + 8192).max(0).min(16383) as u16;
             let max_u16 = (bend_range.1 + 8192).max(0).min(16383) as u16;
@@ -134,6 +177,7 @@ pub fn suggestion_to_config(suggestion: &TriggerSuggestion) -> TriggerConfig {
             TriggerConfig::PitchBend {
                 value_min: Some(min_u16),
                 value_max: Some(max_u16),
+                channel: *channel,
             }
         }
         // Gamepad trigger conversions (v3.0)
@@ -209,6 +253,7 @@ mod tests {
         let suggestion = TriggerSuggestion::Note {
             note: 60,
             velocity_range: Some((1, 127)),
+            channel: Some(9),
         };
 
         let config = suggestion_to_config(&suggestion);
@@ -216,7 +261,8 @@ mod tests {
             config,
             TriggerConfig::Note {
                 note: 60,
-                velocity_min: Some(1)
+                velocity_min: Some(1),
+                channel: Some(9)
             }
         ));
     }
@@ -228,6 +274,7 @@ mod tests {
             velocity_min: 0,
             velocity_max: 40,
             level: "soft".to_string(),
+            channel: None,
         };
 
         let config = suggestion_to_config(&suggestion);
@@ -235,6 +282,7 @@ mod tests {
             note,
             soft_max,
             medium_max,
+            ..
         } = config
         {
             assert_eq!(note, 60);
@@ -250,6 +298,7 @@ mod tests {
         let suggestion = TriggerSuggestion::LongPress {
             note: 60,
             duration_ms: 2500,
+            channel: None,
         };
 
         let config = suggestion_to_config(&suggestion);
@@ -257,7 +306,8 @@ mod tests {
             config,
             TriggerConfig::LongPress {
                 note: 60,
-                duration_ms: Some(2500)
+                duration_ms: Some(2500),
+                channel: None
             }
         ));
     }
@@ -267,10 +317,14 @@ mod tests {
         let suggestion = TriggerSuggestion::Chord {
             notes: vec![60, 64, 67],
             window_ms: 100,
+            channel: None,
         };
 
         let config = suggestion_to_config(&suggestion);
-        if let TriggerConfig::NoteChord { notes, timeout_ms } = config {
+        if let TriggerConfig::NoteChord {
+            notes, timeout_ms, ..
+        } = config
+        {
             assert_eq!(notes, vec![60, 64, 67]);
             assert_eq!(timeout_ms, Some(100));
         } else {
@@ -283,10 +337,11 @@ mod tests {
         let suggestion = TriggerSuggestion::Encoder {
             cc: 1,
             direction: Some("clockwise".to_string()),
+            channel: None,
         };
 
         let config = suggestion_to_config(&suggestion);
-        if let TriggerConfig::EncoderTurn { cc, direction } = config {
+        if let TriggerConfig::EncoderTurn { cc, direction, .. } = config {
             assert_eq!(cc, 1);
             assert_eq!(direction, Some("clockwise".to_string()));
         } else {
@@ -299,12 +354,14 @@ mod tests {
         let config = TriggerConfig::Note {
             note: 60,
             velocity_min: Some(1),
+            channel: Some(9),
         };
 
         let toml = config_to_toml(&config).unwrap();
         assert!(toml.contains(r#"type = "Note""#));
         assert!(toml.contains("note = 60"));
         assert!(toml.contains("velocity_min = 1"));
+        assert!(toml.contains("channel = 9"));
     }
 
     #[test]
@@ -312,6 +369,7 @@ mod tests {
         let config = TriggerConfig::LongPress {
             note: 60,
             duration_ms: Some(2000),
+            channel: None,
         };
 
         let mapping_toml = generate_mapping_toml(&config, "Default");
@@ -36,39 +36,48 @@ pub enum TriggerSuggestion {
     Note {
         note: u8,
         velocity_range: Option<(u8, u8)>,
+        channel: Option<u8>, // Learned MIDI channel (0-15)
     },
     VelocityRange {
         note: u8,
         velocity_min: u8,
         velocity_max: u8,
-        level: String, // "soft", "medium", "hard"
+        level: String,       // "soft", "medium", "hard"
+        channel: Option<u8>, // Learned MIDI channel (0-15)
     },
     LongPress {
         note: u8,
         duration_ms: u64,
+        channel: Option<u8>, // Learned MIDI channel (0-15)
     },
     DoubleTap {
         note: u8,
         timeout_ms: u64,
+        channel: Option<u8>, // Learned MIDI channel (0-15)
     },
     Chord {
         notes: Vec<u8>,
         window_ms: u64,
+        channel: Option<u8>, // Learned MIDI channel (0-15)
     },
     Encoder {
         cc: u8,
         direction: Option<String>, // "clockwise", "counterclockwise"
+        channel: Option<u8>,       // Learned MIDI channel (0-15)
     },
     CC {
         cc: u8,
         value_range: Option<(u8, u8)>,
+        channel: Option<u8>, // Learned MIDI channel (0-15)
     },
     Aftertouch {
         note: Option<u8>,
         pressure_range: (u8, u8),
+        channel: Option<u8>, // Learned MIDI channel (0-15)
     },
     PitchBend {
         bend_range: (i16, i16),
+        channel: Option<u8>, // Learned MIDI channel (0-15)
     },
     // Gamepad trigger suggestions (v3.0)
     GamepadButton {
@@ -89,6 +98,30 @@ pub enum TriggerSuggestion {
     },
 }
 
+impl TriggerSuggestion {
+    /// Set the learned MIDI channel on MIDI suggestions
+    ///
+    /// Gamepad suggestions have no channel and are returned unchanged.
+    fn with_channel(mut self, learned: Option<u8>) -> Self {
+        match &mut self {
+            TriggerSuggestion::Note { channel, .. }
+            | TriggerSuggestion::VelocityRange { channel, .. }
+            | TriggerSuggestion::LongPress { channel, .. }
+            | TriggerSuggestion::DoubleTap { channel, .. }
+            | TriggerSuggestion::Chord { channel, .. }
+            | TriggerSuggestion::Encoder { channel, .. }
+            | TriggerSuggestion::CC { channel, .. }
+            | TriggerSuggestion::Aftertouch { channel, .. }
+            | TriggerSuggestion::PitchBend { channel, .. } => *channel = learned,
+            TriggerSuggestion::GamepadButton { .. }
+            | TriggerSuggestion::GamepadButtonChord { .. }
+            | TriggerSuggestion::GamepadAnalogStick { .. }
+            | TriggerSuggestion::GamepadTrigger { .. } => {}
+        }
+        self
+    }
+}
+
 /// MIDI Learn session state
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum LearnSessionState {
@@ -152,6 +185,8 @@ pub struct MidiLearnSession {
     /// Currently held buttons for chord detection (v3.0)
     #[allow(dead_code)] // Used for chord detection
     held_buttons: Arc<RwLock<Vec<u8>>>,
+    /// MIDI channel of the most recent MIDI event (None for gamepad input)
+    learned_channel: Arc<RwLock<Option<u8>>>,
 }
 
 impl MidiLearnSession {
@@ -171,6 +206,7 @@ impl MidiLearnSession {
             button_press_times: Arc::new(RwLock::new(HashMap::new())), // v3.0
             last_button_times: Arc::new(RwLock::new(HashMap::new())),  // v3.0
             held_buttons: Arc::new(RwLock::new(Vec::new())),           // v3.0
+            learned_channel: Arc::new(RwLock::new(None)),
         }
     }
 
@@ -240,6 +276,8 @@ impl MidiLearnSession {
         });
         drop(history);
 
+        *self.learned_channel.write().await = Some(event.channel());
+
         // Track note presses and releases for pattern detection
         match &event {
             MidiEvent::NoteOn { note, .. } => {
@@ -252,6 +290,7 @@ impl MidiLearnSession {
                         self.complete_learning(TriggerSuggestion::DoubleTap {
                             note: *note,
                             timeout_ms: gap.as_millis() as u64 + 50, // Add buffer
+                            channel: None,
                         })
                         .await;
                         return;
@@ -291,6 +330,7 @@ impl MidiLearnSession {
                         self.complete_learning(TriggerSuggestion::Chord {
                             notes: held.clone(),
                             window_ms: 100,
+                            channel: None,
                         })
                         .await;
                         return;
@@ -307,6 +347,7 @@ impl MidiLearnSession {
                         self.complete_learning(TriggerSuggestion::LongPress {
                             note: *note,
                             duration_ms: duration.as_millis() as u64,
+                            channel: None,
                         })
                         .await;
                         return;
@@ -317,7 +358,9 @@ impl MidiLearnSession {
                 let mut held = self.held_notes.write().await;
                 held.retain(|n| n != note);
             }
-            MidiEvent::ControlChange { controller, value } => {
+            MidiEvent::ControlChange {
+                controller, value, ..
+            } => {
                 // Detect encoder direction by looking at value changes
                 let history = self.event_history.read().await;
                 let prev_cc: Option<u8> = history
@@ -328,6 +371,7 @@ impl MidiLearnSession {
                         MidiEvent::ControlChange {
                             controller: cc,
                             value: v,
+                            ..
                         } if cc == *controller => Some(v),
                         _ => None,
                     });
@@ -347,6 +391,7 @@ impl MidiLearnSession {
                 self.complete_learning(TriggerSuggestion::Encoder {
                     cc: *controller,
                     direction,
+                    channel: None,
                 })
                 .await;
                 return;
@@ -372,6 +417,7 @@ impl MidiLearnSession {
         }
 
         let duration_ms = self.elapsed_ms().await;
+        let trigger = trigger.with_channel(*self.learned_channel.read().await);
 
         let mut result = self.result.write().await;
         *result = Some(MidiLearnResult {
@@ -410,7 +456,7 @@ impl MidiLearnSession {
     #[allow(dead_code)] // Used for simple event analysis in capture_event
     fn analyze_simple_event(&self, event: &MidiEvent) -> TriggerSuggestion {
         match event {
-            MidiEvent::NoteOn { note, velocity } => {
+            MidiEvent::NoteOn { note, velocity, .. } => {
                 // Suggest based on velocity
                 if *velocity < 40 {
                     TriggerSuggestion::VelocityRange {
@@ -418,6 +464,7 @@ impl MidiLearnSession {
                         velocity_min: 0,
                         velocity_max: 40,
                         level: "soft".to_string(),
+                        channel: None,
                     }
                 } else if *velocity < 80 {
                     TriggerSuggestion::VelocityRange {
@@ -425,6 +472,7 @@ impl MidiLearnSession {
                         velocity_min: 41,
                         velocity_max: 80,
                         level: "medium".to_string(),
+                        channel: None,
                     }
                 } else {
                     TriggerSuggestion::VelocityRange {
@@ -432,23 +480,30 @@ impl MidiLearnSession {
                         velocity_min: 81,
                         velocity_max: 127,
                         level: "hard".to_string(),
+                        channel: None,
                     }
                 }
             }
             MidiEvent::NoteOff { note, .. } => TriggerSuggestion::Note {
                 note: *note,
                 velocity_range: None,
+                channel: None,
             },
-            MidiEvent::ControlChange { controller, value } => TriggerSuggestion::CC {
+            MidiEvent::ControlChange {
+                controller, value, ..
+            } => TriggerSuggestion::CC {
                 cc: *controller,
                 value_range: Some((*value, *value)),
+                channel: None,
             },
-            MidiEvent::PitchBend { value } => TriggerSuggestion::PitchBend {
+            MidiEvent::PitchBend { value, .. } => TriggerSuggestion::PitchBend {
                 bend_range: (*value, *value),
+                channel: None,
             },
-            MidiEvent::Aftertouch { note, pressure } => TriggerSuggestion::Aftertouch {
+            MidiEvent::Aftertouch { note, pressure, .. } => TriggerSuggestion::Aftertouch {
                 note: *note,
                 pressure_range: (*pressure, *pressure),
+                channel: None,
             },
         }
     }
@@ -472,6 +527,14 @@ impl MidiLearnSession {
         });
         drop(history);
 
+        // Gamepad IDs (128-255) have no MIDI channel to suggest
+        let is_gamepad = match &event {
+            InputEvent::PadPressed { pad, .. } | InputEvent::PadReleased { pad, .. } => *pad >= 128,
+            InputEvent::EncoderTurned { encoder, .. } => *encoder >= 128,
+            _ => false,
+        };
+        *self.learned_channel.write().await = (!is_gamepad).then(|| event.channel());
+
         // Pattern detection based on event type
         match &event {
             InputEvent::PadPressed { pad, velocity, .. } => {
@@ -505,6 +568,7 @@ impl MidiLearnSession {
                 self.complete_learning(TriggerSuggestion::Aftertouch {
                     note: Some(*pad),
                     pressure_range: (*pressure, *pressure),
+                    channel: None,
                 })
                 .await;
             }
@@ -513,6 +577,7 @@ impl MidiLearnSession {
                 self.complete_learning(TriggerSuggestion::Aftertouch {
                     note: None,
                     pressure_range: (*pressure, *pressure),
+                    channel: None,
                 })
                 .await;
             }
@@ -521,6 +586,7 @@ impl MidiLearnSession {
                 let signed_value = (*value as i16) - 8192;
                 self.complete_learning(TriggerSuggestion::PitchBend {
                     bend_range: (signed_value, signed_value),
+                    channel: None,
                 })
                 .await;
             }
@@ -529,12 +595,26 @@ impl MidiLearnSession {
                 self.complete_learning(TriggerSuggestion::CC {
                     cc: *control,
                     value_range: Some((*value, *value)),
+                    channel: None,
                 })
                 .await;
             }
             InputEvent::ProgramChange { .. } => {
                 // Program change - not currently supported as trigger, ignore
             }
+            InputEvent::HighResControlChange { .. }
+            | InputEvent::Nrpn { .. }
+            | InputEvent::Rpn { .. } => {
+                // 14-bit controls - no learn suggestion yet, ignore
+            }
+            InputEvent::SysEx { .. } => {
+                // SysEx - no learn suggestion yet, ignore
+            }
+            InputEvent::Clock { .. }
+            | InputEvent::Transport { .. }
+            | InputEvent::SongPosition { .. } => {
+                // Clock and transport come from the host, not a control; ignore
+            }
         }
     }
 
@@ -550,6 +630,7 @@ impl MidiLearnSession {
                 self.complete_learning(TriggerSuggestion::DoubleTap {
                     note,
                     timeout_ms: gap.as_millis() as u64 + 50,
+                    channel: None,
                 })
                 .await;
                 return;
@@ -587,6 +668,7 @@ impl MidiLearnSession {
                 self.complete_learning(TriggerSuggestion::Chord {
                     notes: held.clone(),
                     window_ms: 100,
+                    channel: None,
                 })
                 .await;
                 return;
@@ -606,6 +688,7 @@ impl MidiLearnSession {
                 self.complete_learning(TriggerSuggestion::LongPress {
                     note,
                     duration_ms: duration.as_millis() as u64,
+                    channel: None,
                 })
                 .await;
                 return;
@@ -625,6 +708,7 @@ impl MidiLearnSession {
                                 velocity_min: 0,
                                 velocity_max: 40,
                                 level: "soft".to_string(),
+                                channel: None,
                             }
                         } else if velocity < 80 {
                             TriggerSuggestion::VelocityRange {
@@ -632,6 +716,7 @@ impl MidiLearnSession {
                                 velocity_min: 41,
                                 velocity_max: 80,
                                 level: "medium".to_string(),
+                                channel: None,
                             }
                         } else {
                             TriggerSuggestion::VelocityRange {
@@ -639,6 +724,7 @@ impl MidiLearnSession {
                                 velocity_min: 81,
                                 velocity_max: 127,
                                 level: "hard".to_string(),
+                                channel: None,
                             }
                         };
                         self.complete_learning(trigger).await;
@@ -650,6 +736,7 @@ impl MidiLearnSession {
                 self.complete_learning(TriggerSuggestion::Note {
                     note,
                     velocity_range: None,
+                    channel: None,
                 })
                 .await;
             }
@@ -672,6 +759,7 @@ impl MidiLearnSession {
                 self.complete_learning(TriggerSuggestion::DoubleTap {
                     note: button, // Reuse DoubleTap for gamepad buttons
                     timeout_ms: gap.as_millis() as u64 + 50,
+                    channel: None,
                 })
                 .await;
                 return;
@@ -728,6 +816,7 @@ impl MidiLearnSession {
                 self.complete_learning(TriggerSuggestion::LongPress {
                     note: button, // Reuse LongPress for gamepad buttons
                     duration_ms: duration.as_millis() as u64,
+                    channel: None,
                 })
                 .await;
                 return;
@@ -805,6 +894,7 @@ impl MidiLearnSession {
             self.complete_learning(TriggerSuggestion::Encoder {
                 cc: encoder,
                 direction,
+                channel: None,
             })
             .await;
         }
@@ -814,29 +904,62 @@ impl MidiLearnSession {
 /// Simplified MIDI event type for learning
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum MidiEvent {
-    NoteOn { note: u8, velocity: u8 },
-    NoteOff { note: u8, velocity: u8 },
-    ControlChange { controller: u8, value: u8 },
-    PitchBend { value: i16 },
-    Aftertouch { note: Option<u8>, pressure: u8 },
+    NoteOn {
+        channel: u8,
+        note: u8,
+        velocity: u8,
+    },
+    NoteOff {
+        channel: u8,
+        note: u8,
+        velocity: u8,
+    },
+    ControlChange {
+        channel: u8,
+        controller: u8,
+        value: u8,
+    },
+    PitchBend {
+        channel: u8,
+        value: i16,
+    },
+    Aftertouch {
+        channel: u8,
+        note: Option<u8>,
+        pressure: u8,
+    },
 }
 
 impl MidiEvent {
+    /// MIDI channel (0-15) the event arrived on
+    pub fn channel(&self) -> u8 {
+        match self {
+            MidiEvent::NoteOn { channel, .. }
+            | MidiEvent::NoteOff { channel, .. }
+            | MidiEvent::ControlChange { channel, .. }
+            | MidiEvent::PitchBend { channel, .. }
+            | MidiEvent::Aftertouch { channel, .. } => *channel,
+        }
+    }
+
     /// Parse from raw MIDI bytes
     #[allow(dead_code)] // Part of MIDI event parsing API
     pub fn from_bytes(status: u8, data1: u8, data2: u8) -> Option<Self> {
         let message_type = status & 0xF0;
+        let channel = status & 0x0F;
 
         match message_type {
             0x90 => {
                 // Note On
                 if data2 == 0 {
                     Some(MidiEvent::NoteOff {
+                        channel,
                         note: data1,
                         velocity: 0,
                     })
                 } else {
                     Some(MidiEvent::NoteOn {
+                        channel,
                         note: data1,
                         velocity: data2,
                     })
@@ -845,6 +968,7 @@ impl MidiEvent {
             0x80 => {
                 // Note Off
                 Some(MidiEvent::NoteOff {
+                    channel,
                     note: data1,
                     velocity: data2,
                 })
@@ -852,6 +976,7 @@ impl MidiEvent {
             0xB0 => {
                 // Control Change
                 Some(MidiEvent::ControlChange {
+                    channel,
                     controller: data1,
                     value: data2,
                 })
@@ -859,11 +984,12 @@ impl MidiEvent {
             0xE0 => {
                 // Pitch Bend
                 let value = (((data2 as i16) << 7) | (data1 as i16)) - 8192;
-                Some(MidiEvent::PitchBend { value })
+                Some(MidiEvent::PitchBend { channel, value })
             }
             0xA0 => {
                 // Polyphonic Aftertouch
                 Some(MidiEvent::Aftertouch {
+                    channel,
                     note: Some(data1),
                     pressure: data2,
                 })
@@ -871,6 +997,7 @@ impl MidiEvent {
             0xD0 => {
                 // Channel Aftertouch
                 Some(MidiEvent::Aftertouch {
+                    channel,
                     note: None,
                     pressure: data1,
                 })
@@ -904,6 +1031,7 @@ mod tests {
 
         // Simulate Note On
         let event_on = MidiEvent::NoteOn {
+            channel: 0,
             note: 60,
             velocity: 100,
         };
@@ -914,6 +1042,7 @@ mod tests {
 
         // Simulate Note Off to complete the note trigger
         let event_off = MidiEvent::NoteOff {
+            channel: 0,
             note: 60,
             velocity: 0,
         };
@@ -936,6 +1065,7 @@ mod tests {
         assert!(matches!(
             event,
             Some(MidiEvent::NoteOn {
+                channel: 0,
                 note: 60,
                 velocity: 100
             })
@@ -946,9 +1076,39 @@ mod tests {
         assert!(matches!(
             event,
             Some(MidiEvent::ControlChange {
+                channel: 0,
                 controller: 7,
                 value: 127
             })
         ));
+
+        // Note On on channel 10 (zero-based 9)
+        let event = MidiEvent::from_bytes(0x99, 36, 100);
+        assert!(matches!(event, Some(MidiEvent::NoteOn { channel: 9, .. })));
+    }
+
+    #[tokio::test]
+    async fn test_learned_channel_is_suggested() {
+        let session = MidiLearnSession::new(10);
+        session.start().await;
+
+        session
+            .capture_input_event(InputEvent::EncoderTurned {
+                channel: 4,
+                encoder: 7,
+                value: 64,
+                time: Instant::now(),
+            })
+            .await;
+
+        let result = session.get_result().await.unwrap();
+        assert!(matches!(
+            result.trigger,
+            Some(TriggerSuggestion::Encoder {
+                cc: 7,
+                channel: Some(4),
+                ..
+            })
+        ));
     }
 }
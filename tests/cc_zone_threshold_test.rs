@@ -0,0 +1,188 @@
+// Copyright 2025 Amiable
+// SPDX-License-Identifier: MIT
+
+//! Integration tests for CC value ranges, zones and threshold crossings
+//!
+//! Tests that `CC` triggers respect `value_max`, that `CCZone` triggers fire
+//! once on entering a zone (with hysteresis at the boundaries), and that
+//! `CCThreshold` triggers fire once per rising or falling crossing.
+
+mod common;
+
+use common::{Rig, texts};
+use conductor_core::Action;
+use conductor_core::event_processor::MidiEvent;
+use conductor_core::mapping::MappedAction;
+use std::time::Instant;
+
+/// CC 7 on channel 0, dispatched through a processor and engine
+struct Fader(Rig);
+
+impl Fader {
+    fn new(mappings_toml: &str) -> Self {
+        Self(Rig::new(mappings_toml))
+    }
+
+    /// Move the fader through `values`, returning the actions mapped
+    fn move_to(&mut self, values: &[u8]) -> Vec<MappedAction> {
+        values
+            .iter()
+            .flat_map(|value| {
+                self.0.process(MidiEvent::ControlChange {
+                    channel: 0,
+                    cc: 7,
+                    value: *value,
+                    time: Instant::now(),
+                })
+            })
+            .collect()
+    }
+
+    /// Text of the actions fired moving through `values`
+    fn texts(&mut self, values: &[u8]) -> Vec<String> {
+        texts(&self.move_to(values))
+    }
+}
+
+#[test]
+fn test_cc_value_max() {
+    let mut fader = Fader::new(
+        r#"
+        [[modes.mappings]]
+        trigger = { type = "CC", cc = 7, value_min = 32, value_max = 63 }
+        action = { type = "Text", text = "middle" }
+    "#,
+    );
+
+    assert_eq!(fader.texts(&[0, 31, 32, 63, 64, 127]).len(), 2);
+}
+
+#[test]
+fn test_zones_fire_once_on_entry() {
+    let mut fader = Fader::new(
+        r#"
+        [[modes.mappings]]
+        trigger = { type = "CCZone", cc = 7, zones = 3 }
+        action = { type = "Text", text = "zone {offset}" }
+    "#,
+    );
+
+    let zones: Vec<u8> = fader
+        .move_to(&[0, 10, 42, 43, 60, 85, 86, 127, 100, 20])
+        .iter()
+        .map(|mapped| mapped.control.expect("Zone passes its control").offset)
+        .collect();
+    // 0-42, 43-85 and 86-127; the first value enters its zone
+    assert_eq!(zones, vec![0, 1, 2, 0]);
+}
+
+#[test]
+fn test_zone_filter_and_hysteresis() {
+    let mut fader = Fader::new(
+        r#"
+        [[modes.mappings]]
+        trigger = { type = "CCZone", cc = 7, zones = 2, zone = 1, hysteresis = 4 }
+        action = { type = "Text", text = "upper" }
+
+        [[modes.mappings]]
+        trigger = { type = "CCZone", cc = 7, zones = 2, zone = 0 }
+        action = { type = "Text", text = "lower" }
+    "#,
+    );
+
+    assert_eq!(fader.texts(&[10]), vec!["lower"]);
+
+    // Jitter around the boundary at 64 doesn't switch zones
+    assert!(fader.texts(&[63, 64, 66, 67, 63]).is_empty());
+    assert_eq!(fader.texts(&[68]), vec!["upper"]);
+    assert!(fader.texts(&[64, 60]).is_empty());
+    assert_eq!(fader.texts(&[59]), vec!["lower"]);
+}
+
+#[test]
+fn test_rising_and_falling_thresholds() {
+    let mut fader = Fader::new(
+        r#"
+        [[modes.mappings]]
+        trigger = { type = "CCThreshold", cc = 7, threshold = 64, direction = "Rising" }
+        action = { type = "Text", text = "on" }
+
+        [[modes.mappings]]
+        trigger = { type = "CCThreshold", cc = 7, threshold = 64, direction = "Falling" }
+        action = { type = "Text", text = "off" }
+    "#,
+    );
+
+    // The first value only arms the thresholds
+    assert!(fader.texts(&[0, 30]).is_empty());
+    assert_eq!(fader.texts(&[64, 100, 127]), vec!["on"]);
+    assert_eq!(fader.texts(&[63, 0]), vec!["off"]);
+    assert_eq!(fader.texts(&[64, 63]), vec!["on", "off"]);
+}
+
+#[test]
+fn test_threshold_hysteresis() {
+    let mut fader = Fader::new(
+        r#"
+        [[modes.mappings]]
+        trigger = { type = "CCThreshold", cc = 7, threshold = 100, hysteresis = 10 }
+        action = { type = "Text", text = "hit" }
+    "#,
+    );
+
+    assert_eq!(fader.texts(&[50, 100]), vec!["hit"]);
+
+    // Not far enough below to re-arm
+    assert!(fader.texts(&[91, 100, 95, 127]).is_empty());
+    assert_eq!(fader.texts(&[89, 100]), vec!["hit"]);
+}
+
+#[test]
+fn test_cc_and_zone_mappings_on_one_fader() {
+    let mut fader = Fader::new(
+        r#"
+        [[modes.mappings]]
+        trigger = { type = "CC", cc = 7 }
+        action = { type = "Text", text = "moved" }
+
+        [[modes.mappings]]
+        trigger = { type = "CCZone", cc = 7, zones = 2 }
+        action = { type = "Text", text = "zone {offset}" }
+
+        [[modes.mappings]]
+        trigger = { type = "CCThreshold", cc = 7, threshold = 100 }
+        action = { type = "Text", text = "hit" }
+    "#,
+    );
+
+    fader.texts(&[10]);
+
+    // The CC, the zone it entered and the crossing all come from one message
+    assert_eq!(fader.texts(&[20]), vec!["moved"]);
+    assert_eq!(fader.texts(&[80]), vec!["moved", "zone {offset}"]);
+    assert_eq!(fader.texts(&[110]), vec!["moved", "hit"]);
+}
+
+#[test]
+fn test_relative_encoder_zones_follow_position() {
+    let mut fader = Fader::new(
+        r#"
+        [[modes.mappings]]
+        trigger = { type = "EncoderTurn", cc = 7, encoding = "TwosComplement" }
+        action = { type = "Text", text = "turned" }
+
+        [[modes.mappings]]
+        trigger = { type = "CCZone", cc = 7, zones = 2 }
+        action = { type = "Text", text = "zone {offset}" }
+    "#,
+    );
+
+    // Virtual position starts at 64 and steps back, forward twice, then back twice
+    let zones: Vec<u8> = fader
+        .move_to(&[127, 1, 1, 127, 127])
+        .iter()
+        .filter(|mapped| matches!(&mapped.action, Action::Text(text) if text.starts_with("zone")))
+        .map(|mapped| mapped.control.expect("Zone passes its control").offset)
+        .collect();
+    assert_eq!(zones, vec![0, 1, 0]);
+}
@@ -43,18 +43,21 @@ fn test_chord_to_action_mapping() {
     let base_time = Instant::now();
 
     let note1 = MidiEvent::NoteOn {
+        channel: 0,
         note: 1,
         velocity: 100,
         time: base_time,
     };
 
     let note2 = MidiEvent::NoteOn {
+        channel: 0,
         note: 5,
         velocity: 100,
         time: base_time + std::time::Duration::from_millis(10),
     };
 
     let note3 = MidiEvent::NoteOn {
+        channel: 0,
         note: 9,
         velocity: 100,
         time: base_time + std::time::Duration::from_millis(20),
@@ -124,18 +127,21 @@ fn test_chord_with_wrong_notes_does_not_match() {
     let base_time = Instant::now();
 
     event_processor.process(MidiEvent::NoteOn {
+        channel: 0,
         note: 2,
         velocity: 100,
         time: base_time,
     });
 
     event_processor.process(MidiEvent::NoteOn {
+        channel: 0,
         note: 6,
         velocity: 100,
         time: base_time + std::time::Duration::from_millis(10),
     });
 
     let processed = event_processor.process(MidiEvent::NoteOn {
+        channel: 0,
         note: 10,
         velocity: 100,
         time: base_time + std::time::Duration::from_millis(20),
@@ -184,18 +190,21 @@ fn test_chord_exact_match_required() {
     let base_time = Instant::now();
 
     event_processor.process(MidiEvent::NoteOn {
+        channel: 0,
         note: 1,
         velocity: 100,
         time: base_time,
     });
 
     event_processor.process(MidiEvent::NoteOn {
+        channel: 0,
         note: 5,
         velocity: 100,
         time: base_time + std::time::Duration::from_millis(10),
     });
 
     let processed = event_processor.process(MidiEvent::NoteOn {
+        channel: 0,
         note: 9,
         velocity: 100,
         time: base_time + std::time::Duration::from_millis(20),
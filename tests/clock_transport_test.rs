@@ -0,0 +1,218 @@
+// Copyright 2025 Amiable
+// SPDX-License-Identifier: MIT
+
+//! Integration tests for MIDI clock and transport input
+//!
+//! Tests that System Real-Time and Song Position messages are parsed, that
+//! the event processor derives tempo and song position from 24-ppq clock, and
+//! that `Transport`, `SongPosition` and `Tempo` triggers match.
+
+mod common;
+
+use common::engine_with_mappings;
+use conductor_core::event_processor::{MidiEvent, ProcessedEvent};
+use conductor_core::{Action, Config, EventProcessor, TransportCommand, VolumeOperation};
+use std::time::{Duration, Instant};
+
+/// Send `pulses` clock pulses at `bpm` starting at `start`, returning the
+/// time after the last pulse and the tempo changes reported
+fn run_clock(
+    processor: &mut EventProcessor,
+    bpm: f64,
+    pulses: u32,
+    start: Instant,
+) -> (Instant, Vec<f64>) {
+    let interval = Duration::from_secs_f64(60.0 / bpm / 24.0);
+    let mut time = start;
+    let mut tempos = Vec::new();
+    for _ in 0..pulses {
+        time += interval;
+        for event in processor.process(MidiEvent::Clock { time }) {
+            if let ProcessedEvent::TempoChanged { bpm } = event {
+                tempos.push(bpm);
+            }
+        }
+    }
+    (time, tempos)
+}
+
+fn transport(processor: &mut EventProcessor, command: TransportCommand) -> Vec<ProcessedEvent> {
+    processor.process(MidiEvent::Transport {
+        command,
+        time: Instant::now(),
+    })
+}
+
+#[test]
+fn test_parse_real_time_messages() {
+    assert!(matches!(
+        MidiEvent::from_midi_msg(&[0xF8]),
+        Ok(MidiEvent::Clock { .. })
+    ));
+    for (byte, command) in [
+        (0xFA, TransportCommand::Start),
+        (0xFB, TransportCommand::Continue),
+        (0xFC, TransportCommand::Stop),
+    ] {
+        assert!(matches!(
+            MidiEvent::from_midi_msg(&[byte]),
+            Ok(MidiEvent::Transport { command: c, .. }) if c == command
+        ));
+    }
+
+    // Song position 0x0101 = 129 sixteenth notes
+    assert!(matches!(
+        MidiEvent::from_midi_msg(&[0xF2, 0x01, 0x01]),
+        Ok(MidiEvent::SongPosition { position: 129, .. })
+    ));
+
+    // Active sensing is still ignored
+    assert!(MidiEvent::from_midi_msg(&[0xFE]).is_err());
+}
+
+#[test]
+fn test_tempo_from_clock() {
+    let mut processor = EventProcessor::new();
+
+    let (time, tempos) = run_clock(&mut processor, 120.0, 48, Instant::now());
+    // Reported once a beat of clock has arrived, and not again while steady
+    assert_eq!(tempos.len(), 1);
+    assert!((tempos[0] - 120.0).abs() < 0.1);
+    assert!((processor.clock().bpm.unwrap() - 120.0).abs() < 0.1);
+
+    // Speeding up reports the new tempo
+    let (_, tempos) = run_clock(&mut processor, 140.0, 24, time);
+    assert!((tempos.last().unwrap() - 140.0).abs() < 0.1);
+}
+
+#[test]
+fn test_clock_gap_restarts_tempo_estimate() {
+    let mut processor = EventProcessor::new();
+
+    let (time, _) = run_clock(&mut processor, 120.0, 30, Instant::now());
+    let (_, tempos) = run_clock(&mut processor, 90.0, 10, time + Duration::from_secs(2));
+    assert!(tempos.is_empty(), "Too few pulses since the gap");
+}
+
+#[test]
+fn test_transport_and_song_position() {
+    let mut processor = EventProcessor::new();
+    let now = Instant::now();
+
+    // Clock before Start only sets the tempo
+    let (time, _) = run_clock(&mut processor, 120.0, 24, now);
+    assert_eq!(processor.clock().pulses, 0);
+    assert!(!processor.clock().playing);
+
+    let events = transport(&mut processor, TransportCommand::Start);
+    assert!(matches!(
+        events.as_slice(),
+        [ProcessedEvent::TransportChanged {
+            command: TransportCommand::Start
+        }]
+    ));
+
+    // Five beats in: second bar, second beat
+    run_clock(&mut processor, 120.0, 24 * 5, time);
+    let clock = processor.clock();
+    assert!(clock.playing);
+    assert_eq!((clock.beat(), clock.bar(), clock.beat_in_bar()), (5, 1, 1));
+
+    transport(&mut processor, TransportCommand::Stop);
+    assert!(!processor.clock().playing);
+
+    // Song position is in sixteenth notes: 32 = bar 2
+    processor.process(MidiEvent::SongPosition {
+        position: 32,
+        time: now,
+    });
+    assert_eq!(processor.clock().bar(), 2);
+}
+
+#[test]
+fn test_transport_triggers() {
+    let engine = engine_with_mappings(
+        r#"
+        [[modes.mappings]]
+        trigger = { type = "Transport", command = "Start" }
+        action = { type = "Text", text = "rolling" }
+
+        [[modes.mappings]]
+        trigger = { type = "SongPosition", position_min = 64 }
+        action = { type = "Text", text = "late" }
+    "#,
+    );
+
+    let fired = |event: ProcessedEvent| match engine.get_action_for_processed(&event, 0) {
+        Some(Action::Text(text)) => Some(text),
+        _ => None,
+    };
+
+    assert_eq!(
+        fired(ProcessedEvent::TransportChanged {
+            command: TransportCommand::Start
+        }),
+        Some("rolling".to_string())
+    );
+    assert_eq!(
+        fired(ProcessedEvent::TransportChanged {
+            command: TransportCommand::Stop
+        }),
+        None
+    );
+    assert_eq!(
+        fired(ProcessedEvent::SongPositionChanged { position: 63 }),
+        None
+    );
+    assert_eq!(
+        fired(ProcessedEvent::SongPositionChanged { position: 64 }),
+        Some("late".to_string())
+    );
+}
+
+#[test]
+fn test_tempo_drives_continuous_value() {
+    let engine = engine_with_mappings(
+        r#"
+        [[modes.mappings]]
+        trigger = { type = "Tempo", bpm_min = 60.0, bpm_max = 180.0 }
+        action = { type = "VolumeControl", operation = "Set" }
+        continuous = { min = 0, max = 100 }
+    "#,
+    );
+
+    let volume =
+        |bpm| match engine.get_action_for_processed(&ProcessedEvent::TempoChanged { bpm }, 0) {
+            Some(Action::VolumeControl {
+                operation: VolumeOperation::Set,
+                value,
+            }) => value,
+            _ => None,
+        };
+
+    assert_eq!(volume(60.0), Some(0));
+    assert_eq!(volume(120.0), Some(50));
+    assert_eq!(volume(180.0), Some(100));
+    assert_eq!(volume(200.0), None, "Outside the trigger's range");
+}
+
+#[test]
+fn test_tempo_range_validation() {
+    let config: Config = toml::from_str(
+        r#"
+        [device]
+        name = "Test Device"
+        auto_connect = false
+
+        [[modes]]
+        name = "Test Mode"
+
+        [[modes.mappings]]
+        trigger = { type = "Tempo", bpm_min = 200.0, bpm_max = 100.0 }
+        action = { type = "Text", text = "x" }
+    "#,
+    )
+    .expect("Failed to parse config");
+
+    assert!(config.validate().is_err());
+}
@@ -0,0 +1,153 @@
+// Copyright 2025 Amiable
+// SPDX-License-Identifier: MIT
+
+//! Fixtures shared by the integration tests
+//!
+//! Each test binary uses only some of these.
+#![allow(dead_code)]
+
+use conductor_core::event_processor::{MidiEvent, ProcessedEvent};
+use conductor_core::mapping::MappedAction;
+use conductor_core::{Action, Config, EventProcessor, MappingEngine};
+use std::time::Instant;
+
+/// A validated config with one mode, "Test Mode", holding `mappings_toml`
+pub fn config_with_mappings(mappings_toml: &str) -> Config {
+    config_with_settings("", mappings_toml)
+}
+
+/// A validated config with `settings_toml` as its `[advanced_settings]` and
+/// one mode, "Test Mode", holding `mappings_toml`
+pub fn config_with_settings(settings_toml: &str, mappings_toml: &str) -> Config {
+    let config_toml = format!(
+        r#"
+        [device]
+        name = "Test Device"
+        auto_connect = false
+
+        [advanced_settings]
+        {}
+
+        [[modes]]
+        name = "Test Mode"
+        {}
+    "#,
+        settings_toml, mappings_toml
+    );
+
+    let config: Config = toml::from_str(&config_toml).expect("Failed to parse config");
+    config.validate().expect("Config should be valid");
+    config
+}
+
+/// A mapping engine loaded with `mappings_toml`
+pub fn engine_with_mappings(mappings_toml: &str) -> MappingEngine {
+    let mut engine = MappingEngine::new();
+    engine.load_from_config(&config_with_mappings(mappings_toml));
+    engine
+}
+
+/// A mapping of the trigger in `trigger_toml` to the Text action "hit"
+pub fn hit_on(trigger_toml: &str) -> String {
+    format!(
+        r#"
+        [[modes.mappings]]
+        [modes.mappings.trigger]
+        {}
+
+        [modes.mappings.action]
+        type = "Text"
+        text = "hit"
+    "#,
+        trigger_toml
+    )
+}
+
+/// Number of events in a batch that map to the "hit" action, with no pads held
+pub fn hits(engine: &MappingEngine, events: &[ProcessedEvent]) -> usize {
+    engine
+        .get_mapped_actions_for_batch(events, 0, &[])
+        .into_iter()
+        .filter(|(_, mapped)| matches!(&mapped.action, Action::Text(text) if text == "hit"))
+        .count()
+}
+
+/// Whether any event in a batch maps to the "hit" action
+pub fn fires(engine: &MappingEngine, events: &[ProcessedEvent]) -> bool {
+    hits(engine, events) > 0
+}
+
+/// An event processor and mapping engine loaded from the same config
+///
+/// Each batch of processed events is mapped as a whole, the way the daemon
+/// dispatches it: every event with a mapping fires, `while_held` mappings see
+/// the pads held now, and the modifiers they use are marked as used.
+pub struct Rig {
+    pub engine: MappingEngine,
+    pub processor: EventProcessor,
+}
+
+impl Rig {
+    pub fn new(mappings_toml: &str) -> Self {
+        Self::from_config(&config_with_mappings(mappings_toml))
+    }
+
+    pub fn with_settings(settings_toml: &str, mappings_toml: &str) -> Self {
+        Self::from_config(&config_with_settings(settings_toml, mappings_toml))
+    }
+
+    pub fn from_config(config: &Config) -> Self {
+        let mut engine = MappingEngine::new();
+        engine.load_from_config(config);
+        Self {
+            engine,
+            processor: EventProcessor::from_config(config),
+        }
+    }
+
+    /// Map a batch of processed events, returning the actions that fire
+    pub fn dispatch(&mut self, events: &[ProcessedEvent]) -> Vec<MappedAction> {
+        let held: Vec<(u8, u8)> = self
+            .processor
+            .held_pads(Instant::now())
+            .into_iter()
+            .map(|(channel, note, _)| (channel, note))
+            .collect();
+
+        let mapped: Vec<MappedAction> = self
+            .engine
+            .get_mapped_actions_for_batch(events, 0, &held)
+            .into_iter()
+            .map(|(_, mapped)| mapped)
+            .collect();
+        for mapped in &mapped {
+            if !mapped.modifiers.is_empty() {
+                self.processor.mark_modifiers_used(&mapped.modifiers);
+            }
+        }
+        mapped
+    }
+
+    /// Run a MIDI event through the processor and dispatch the batch
+    pub fn process(&mut self, event: MidiEvent) -> Vec<MappedAction> {
+        let events = self.processor.process(event);
+        self.dispatch(&events)
+    }
+
+    /// Advance the processor's timers to `now` and dispatch the batch
+    pub fn tick(&mut self, now: Instant) -> Vec<MappedAction> {
+        let events = self.processor.tick(now);
+        self.dispatch(&events)
+    }
+}
+
+/// Texts of the `Text` actions among `mapped`
+pub fn texts(mapped: &[MappedAction]) -> Vec<String> {
+    mapped
+        .iter()
+        .filter_map(|mapped| match &mapped.action {
+            Action::Text(text) => Some(text.clone()),
+            _ => None,
+        })
+        .collect()
+}
@@ -0,0 +1,189 @@
+// Copyright 2025 Amiable
+// SPDX-License-Identifier: MIT
+
+//! Integration tests for continuous value mappings
+//!
+//! Tests that a mapping with a `continuous` table scales the value of the
+//! knob, fader, pitch bend or stick that triggered it and feeds it into the
+//! action, while other mappings keep their configured values.
+
+mod common;
+
+use common::{config_with_mappings, engine_with_mappings};
+use conductor_core::event_processor::{EncoderDirection, ProcessedEvent};
+use conductor_core::{
+    Action, EventProcessor, MappingEngine, MidiEvent, MidiMessageParams, VolumeOperation,
+};
+use std::time::Instant;
+
+fn encoder_turned(cc: u8, value: u8) -> ProcessedEvent {
+    ProcessedEvent::EncoderTurned {
+        channel: 0,
+        cc,
+        value,
+        direction: EncoderDirection::Clockwise,
+        delta: 1,
+    }
+}
+
+const VOLUME_FADER: &str = r#"
+    [[modes.mappings]]
+    trigger = { type = "CC", cc = 7 }
+    action = { type = "VolumeControl", operation = "Set" }
+    continuous = { min = 0, max = 100 }
+"#;
+
+#[test]
+fn test_fader_sets_volume() {
+    let engine = engine_with_mappings(VOLUME_FADER);
+
+    for (cc_value, volume) in [(0, 0), (127, 100), (64, 50)] {
+        let mapped = engine
+            .get_mapped_action_for_processed(&encoder_turned(7, cc_value), 0)
+            .expect("Fader should be mapped");
+        assert!(
+            matches!(
+                mapped.action,
+                Action::VolumeControl {
+                    operation: VolumeOperation::Set,
+                    value: Some(v),
+                } if v == volume
+            ),
+            "CC value {} should set volume {}, got {:?}",
+            cc_value,
+            volume,
+            mapped.action
+        );
+    }
+}
+
+#[test]
+fn test_fader_values_from_processor() {
+    let config = config_with_mappings(VOLUME_FADER);
+    let mut engine = MappingEngine::new();
+    engine.load_from_config(&config);
+    let mut processor = EventProcessor::from_config(&config);
+    let now = Instant::now();
+
+    let volumes: Vec<f64> = [10, 40, 127]
+        .into_iter()
+        .flat_map(|value| {
+            processor.process(MidiEvent::ControlChange {
+                channel: 0,
+                cc: 7,
+                value,
+                time: now,
+            })
+        })
+        .filter_map(|event| engine.get_mapped_action_for_processed(&event, 0))
+        .filter_map(|mapped| mapped.value)
+        .collect();
+
+    // The first message only establishes the fader's position
+    assert_eq!(volumes.len(), 2);
+    assert!((volumes[0] - 4000.0 / 127.0).abs() < 1e-3);
+    assert_eq!(volumes[1], 100.0);
+}
+
+#[test]
+fn test_pitch_bend_drives_cc_through_curve() {
+    let engine = engine_with_mappings(
+        r#"
+        [[modes.mappings]]
+        trigger = { type = "PitchBend" }
+        action = { type = "SendMidi", port = "Out", message_type = "CC", channel = 0, controller = 74 }
+        continuous = { min = 0, max = 127, curve = "Logarithmic", intensity = 0.2 }
+    "#,
+    );
+
+    let cc_value = |bend: u16| {
+        let event = ProcessedEvent::PitchBendMoved {
+            channel: 0,
+            value: bend,
+        };
+        match engine.get_mapped_action_for_processed(&event, 0) {
+            Some(mapped) => match mapped.action {
+                Action::SendMidi {
+                    params: MidiMessageParams::CC { controller, value },
+                    ..
+                } => {
+                    assert_eq!(controller, 74);
+                    value
+                }
+                other => panic!("Expected SendMidi CC, got {:?}", other),
+            },
+            None => panic!("Pitch bend should be mapped"),
+        }
+    };
+
+    assert_eq!(cc_value(0), 0);
+    assert_eq!(cc_value(16383), 127);
+    assert!(cc_value(8192) < 64, "Curve lowers the middle of the range");
+}
+
+#[test]
+fn test_inverted_range_scrolls_both_ways() {
+    let engine = engine_with_mappings(
+        r#"
+        [[modes.mappings]]
+        trigger = { type = "CC", cc = 1 }
+        action = { type = "MouseScroll" }
+        continuous = { min = 5, max = -5 }
+    "#,
+    );
+
+    let amount = |value: u8| match engine.get_action_for_processed(&encoder_turned(1, value), 0) {
+        Some(Action::MouseScroll { amount, .. }) => amount,
+        other => panic!("Expected MouseScroll, got {:?}", other),
+    };
+
+    assert_eq!(amount(0), 5);
+    assert_eq!(amount(127), -5);
+}
+
+#[test]
+fn test_analog_stick_moves_pointer() {
+    let engine = engine_with_mappings(
+        r#"
+        [[modes.mappings]]
+        trigger = { type = "GamepadAnalogStick", axis = 128 }
+        action = { type = "MouseMove", y = 400 }
+        continuous = { min = 0, max = 1920 }
+    "#,
+    );
+
+    let mapped = engine
+        .get_mapped_action_for_processed(&encoder_turned(128, 127), 0)
+        .expect("Stick should be mapped");
+    assert!(matches!(
+        mapped.action,
+        Action::MouseMove {
+            x: Some(1920),
+            y: Some(400),
+            relative: false,
+        }
+    ));
+}
+
+#[test]
+fn test_mapping_without_continuous_keeps_its_value() {
+    let engine = engine_with_mappings(
+        r#"
+        [[modes.mappings]]
+        trigger = { type = "CC", cc = 7 }
+        action = { type = "VolumeControl", operation = "Set", value = 30 }
+    "#,
+    );
+
+    let mapped = engine
+        .get_mapped_action_for_processed(&encoder_turned(7, 127), 0)
+        .expect("Fader should be mapped");
+    assert_eq!(mapped.value, None);
+    assert!(matches!(
+        mapped.action,
+        Action::VolumeControl {
+            value: Some(30),
+            ..
+        }
+    ));
+}
@@ -0,0 +1,148 @@
+// Copyright 2025 Amiable
+// SPDX-License-Identifier: MIT
+
+//! Integration tests for Cycle and Toggle actions
+//!
+//! Tests that Cycle and Toggle actions compile to cycles identified by their
+//! mapping, and that stepping them the way the daemon's executor does runs
+//! each mapping's actions in turn.
+
+mod common;
+
+use common::engine_with_mappings;
+use conductor_core::event_processor::{ProcessedEvent, VelocityLevel};
+use conductor_core::{Action, Config, CyclePositions, CycleReset, MappingEngine};
+
+fn press(note: u8) -> ProcessedEvent {
+    ProcessedEvent::PadPressed {
+        channel: 0,
+        note,
+        velocity: 100,
+        velocity_level: VelocityLevel::Hard,
+    }
+}
+
+/// Fire the mapping for `note` and step its cycle, returning the text run
+fn fire(engine: &MappingEngine, cycles: &mut CyclePositions, note: u8) -> String {
+    let action = engine
+        .get_action_for_processed(&press(note), 0)
+        .expect("Mapping should match");
+    let Action::Cycle { id, actions, reset } = action else {
+        panic!("Expected a cycle, got {:?}", action);
+    };
+
+    match &actions[cycles.advance(&id, actions.len(), reset)] {
+        Action::Text(text) => text.clone(),
+        other => panic!("Expected text, got {:?}", other),
+    }
+}
+
+const SCENES: &str = r#"
+    [[modes.mappings]]
+    trigger = { type = "Note", note = 36 }
+
+    [modes.mappings.action]
+    type = "Cycle"
+    actions = [
+        { type = "Text", text = "scene 1" },
+        { type = "Text", text = "scene 2" },
+        { type = "Text", text = "scene 3" },
+    ]
+
+    [[modes.mappings]]
+    trigger = { type = "Note", note = 37 }
+
+    [modes.mappings.action]
+    type = "Toggle"
+    on = { type = "Text", text = "arm" }
+    off = { type = "Text", text = "disarm" }
+    reset = "ModeChange"
+"#;
+
+#[test]
+fn test_cycle_wraps_around() {
+    let engine = engine_with_mappings(SCENES);
+    let mut cycles = CyclePositions::default();
+
+    let texts: Vec<String> = (0..4).map(|_| fire(&engine, &mut cycles, 36)).collect();
+    assert_eq!(texts, vec!["scene 1", "scene 2", "scene 3", "scene 1"]);
+}
+
+#[test]
+fn test_toggle_alternates_per_mapping() {
+    let engine = engine_with_mappings(SCENES);
+    let mut cycles = CyclePositions::default();
+
+    assert_eq!(fire(&engine, &mut cycles, 37), "arm");
+    // The scene cycle doesn't move the toggle
+    assert_eq!(fire(&engine, &mut cycles, 36), "scene 1");
+    assert_eq!(fire(&engine, &mut cycles, 37), "disarm");
+    assert_eq!(fire(&engine, &mut cycles, 37), "arm");
+
+    let toggle = cycles.get("Test Mode/1").expect("Toggle has fired");
+    assert_eq!((toggle.position, toggle.length), (1, 2));
+    assert_eq!(toggle.reset, CycleReset::ModeChange);
+}
+
+#[test]
+fn test_mode_change_resets_configured_cycles() {
+    let engine = engine_with_mappings(SCENES);
+    let mut cycles = CyclePositions::default();
+
+    fire(&engine, &mut cycles, 36);
+    fire(&engine, &mut cycles, 37);
+    cycles.reset(CycleReset::ModeChange);
+
+    assert_eq!(fire(&engine, &mut cycles, 37), "arm");
+    assert_eq!(fire(&engine, &mut cycles, 36), "scene 2");
+
+    cycles.reset(CycleReset::Reload);
+    assert_eq!(fire(&engine, &mut cycles, 36), "scene 1");
+}
+
+#[test]
+fn test_nested_cycles_have_their_own_ids() {
+    let engine = engine_with_mappings(
+        r#"
+        [[modes.mappings]]
+        trigger = { type = "Note", note = 36 }
+        action = { type = "Sequence", actions = [
+            { type = "Text", text = "go" },
+            { type = "Toggle", on = { type = "Text", text = "on" }, off = { type = "Text", text = "off" } },
+        ] }
+        on_release = { type = "Toggle", on = { type = "Text", text = "up" }, off = { type = "Text", text = "down" } }
+    "#,
+    );
+
+    let mapped = engine
+        .get_mapped_action_for_processed(&press(36), 0)
+        .unwrap();
+    let Action::Sequence(actions) = mapped.action else {
+        panic!("Expected a sequence");
+    };
+    assert!(matches!(&actions[1], Action::Cycle { id, .. } if id == "Test Mode/0/1"));
+    assert!(matches!(
+        mapped.on_release,
+        Some(Action::Cycle { ref id, .. }) if id == "Test Mode/0/release"
+    ));
+}
+
+#[test]
+fn test_empty_cycle_rejected() {
+    let config: Config = toml::from_str(
+        r#"
+        [device]
+        name = "Test Device"
+        auto_connect = false
+
+        [[modes]]
+        name = "Test Mode"
+
+        [[modes.mappings]]
+        trigger = { type = "Note", note = 36 }
+        action = { type = "Cycle", actions = [] }
+    "#,
+    )
+    .unwrap();
+    assert!(config.validate().is_err());
+}
@@ -22,6 +22,7 @@ use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 
 // Re-use types from the main crate for testing
+use conductor::config::AdvancedSettings;
 use conductor::event_processor::{
     EncoderDirection as MidiDirection, EventProcessor, MidiEvent, ProcessedEvent, VelocityLevel,
 };
@@ -91,25 +92,29 @@ fn convert_sim_to_midi_event(raw: Vec<u8>) -> Option<MidiEvent> {
 
     match message_type {
         0x90 => Some(MidiEvent::NoteOn {
+            channel: 0,
             note: raw[1],
             velocity: raw[2],
             time,
         }),
-        0x80 => Some(MidiEvent::NoteOff { note: raw[1], time }),
+        0x80 => Some(MidiEvent::NoteOff { channel: 0, note: raw[1], time }),
         0xB0 => Some(MidiEvent::ControlChange {
+            channel: 0,
             cc: raw[1],
             value: raw[2],
             time,
         }),
         0xD0 => Some(MidiEvent::Aftertouch {
+            channel: 0,
             pressure: raw[1],
             time,
         }),
         0xE0 => {
             let value = ((raw[2] as u16) << 7) | (raw[1] as u16);
-            Some(MidiEvent::PitchBend { value, time })
+            Some(MidiEvent::PitchBend { channel: 0, value, time })
         }
         0xC0 => Some(MidiEvent::ProgramChange {
+            channel: 0,
             program: raw[1],
             time,
         }),
@@ -424,6 +429,10 @@ fn test_e2e_velocity_hard_action() {
 #[test]
 fn test_e2e_long_press_detected() {
     let mut harness = E2ETestHarness::new();
+    harness.processor = EventProcessor::with_settings(&AdvancedSettings {
+        hold_threshold_ms: 1000,
+        ..AdvancedSettings::default()
+    });
 
     // Simulate long press (hold for 1500ms - threshold is 1000ms)
     let processed = harness.simulate_and_process(Gesture::LongPress {
@@ -434,7 +443,7 @@ fn test_e2e_long_press_detected() {
 
     // Verify long press event detected (threshold is 1000ms, not 2000ms)
     let has_long_press = processed.iter().any(|e| {
-        matches!(e, ProcessedEvent::LongPress { note, duration_ms }
+        matches!(e, ProcessedEvent::LongPress { note, duration_ms, .. }
             if *note == 60 && *duration_ms >= 1000)
     });
     assert!(has_long_press, "Should detect long press after 1000ms");
@@ -501,7 +510,7 @@ fn test_e2e_double_tap_detected() {
     // Verify double-tap event detected
     let has_double_tap = processed
         .iter()
-        .any(|e| matches!(e, ProcessedEvent::DoubleTap { note } if *note == 60));
+        .any(|e| matches!(e, ProcessedEvent::DoubleTap { note, .. } if *note == 60));
     assert!(
         has_double_tap,
         "Should detect double-tap within 300ms window"
@@ -561,7 +570,7 @@ fn test_e2e_chord_detected() {
 
     // Verify chord detected
     let has_chord = processed.iter().any(|e| {
-        if let ProcessedEvent::ChordDetected { notes } = e {
+        if let ProcessedEvent::ChordDetected { notes, .. } = e {
             notes.contains(&60) && notes.contains(&64) && notes.contains(&67)
         } else {
             false
@@ -0,0 +1,187 @@
+// Copyright 2025 Amiable
+// SPDX-License-Identifier: MIT
+
+//! Integration tests for relative encoder encodings and acceleration
+//!
+//! Tests that `EncoderTurn` triggers decode two's-complement, signed-bit,
+//! binary-offset and inc/dec button encoders into the right direction and
+//! step count, and that acceleration repeats the action for fast turns.
+
+mod common;
+
+use common::config_with_mappings;
+use conductor_core::event_processor::EncoderDirection::{Clockwise, CounterClockwise};
+use conductor_core::event_processor::{EncoderDirection, ProcessedEvent};
+use conductor_core::{Action, EventProcessor, MappingEngine, MidiEvent};
+use std::time::{Duration, Instant};
+
+fn encoder(encoding_toml: &str) -> EventProcessor {
+    let config = config_with_mappings(&format!(
+        r#"
+        [[modes.mappings]]
+        trigger = {{ type = "EncoderTurn", cc = 20, {} }}
+        action = {{ type = "Keystroke", keys = "right", modifiers = [] }}
+    "#,
+        encoding_toml
+    ));
+    EventProcessor::from_config(&config)
+}
+
+/// Send a CC and return the (direction, delta, value) of the resulting turn
+fn turn(
+    processor: &mut EventProcessor,
+    cc: u8,
+    value: u8,
+    time: Instant,
+) -> Option<(EncoderDirection, u8, u8)> {
+    processor
+        .process(MidiEvent::ControlChange {
+            channel: 0,
+            cc,
+            value,
+            time,
+        })
+        .into_iter()
+        .find_map(|event| match event {
+            ProcessedEvent::EncoderTurned {
+                cc: 20,
+                direction,
+                delta,
+                value,
+                ..
+            } => Some((direction, delta, value)),
+            _ => None,
+        })
+}
+
+#[test]
+fn test_twos_complement() {
+    let mut processor = encoder(r#"encoding = "TwosComplement""#);
+    let now = Instant::now();
+
+    // Relative encoders report movement from the first message
+    assert_eq!(turn(&mut processor, 20, 1, now), Some((Clockwise, 1, 65)));
+    assert_eq!(turn(&mut processor, 20, 3, now), Some((Clockwise, 3, 68)));
+    assert_eq!(
+        turn(&mut processor, 20, 127, now),
+        Some((CounterClockwise, 1, 67))
+    );
+    assert_eq!(
+        turn(&mut processor, 20, 126, now),
+        Some((CounterClockwise, 2, 65))
+    );
+    assert_eq!(turn(&mut processor, 20, 0, now), None);
+}
+
+#[test]
+fn test_signed_bit() {
+    let mut processor = encoder(r#"encoding = "SignedBit""#);
+    let now = Instant::now();
+
+    assert_eq!(turn(&mut processor, 20, 2, now), Some((Clockwise, 2, 66)));
+    assert_eq!(
+        turn(&mut processor, 20, 65, now),
+        Some((CounterClockwise, 1, 65))
+    );
+    assert_eq!(
+        turn(&mut processor, 20, 68, now),
+        Some((CounterClockwise, 4, 61))
+    );
+}
+
+#[test]
+fn test_binary_offset() {
+    let mut processor = encoder(r#"encoding = "BinaryOffset""#);
+    let now = Instant::now();
+
+    assert_eq!(turn(&mut processor, 20, 65, now), Some((Clockwise, 1, 65)));
+    assert_eq!(
+        turn(&mut processor, 20, 62, now),
+        Some((CounterClockwise, 2, 63))
+    );
+    assert_eq!(turn(&mut processor, 20, 64, now), None);
+}
+
+#[test]
+fn test_inc_dec_buttons() {
+    let mut processor = encoder(r#"encoding = "IncDec", decrement_cc = 21"#);
+    let now = Instant::now();
+
+    assert_eq!(turn(&mut processor, 20, 127, now), Some((Clockwise, 1, 65)));
+    // Button release
+    assert_eq!(turn(&mut processor, 20, 0, now), None);
+    // The decrement button turns the encoder on its main CC
+    assert_eq!(
+        turn(&mut processor, 21, 127, now),
+        Some((CounterClockwise, 1, 64))
+    );
+}
+
+#[test]
+fn test_absolute_is_unchanged() {
+    let mut processor = encoder(r#"direction = "Clockwise""#);
+    let now = Instant::now();
+
+    assert_eq!(turn(&mut processor, 20, 10, now), None);
+    assert_eq!(turn(&mut processor, 20, 13, now), Some((Clockwise, 3, 13)));
+}
+
+#[test]
+fn test_acceleration_scales_fast_turns() {
+    let mut processor = encoder(r#"encoding = "TwosComplement", acceleration = 3.0"#);
+    let now = Instant::now();
+
+    // The first turn and turns after a pause are not accelerated
+    assert_eq!(turn(&mut processor, 20, 1, now).map(|t| t.1), Some(1));
+    let later = now + Duration::from_millis(500);
+    assert_eq!(turn(&mut processor, 20, 2, later).map(|t| t.1), Some(2));
+
+    // Back-to-back turns step up to 1 + acceleration times as far
+    assert_eq!(turn(&mut processor, 20, 2, later).map(|t| t.1), Some(8));
+
+    // Halfway through the acceleration window
+    let halfway = later + Duration::from_millis(50);
+    assert_eq!(turn(&mut processor, 20, 2, halfway).map(|t| t.1), Some(5));
+}
+
+#[test]
+fn test_accelerated_action_repeats_per_step() {
+    let config = config_with_mappings(
+        r#"
+        [[modes.mappings]]
+        trigger = { type = "EncoderTurn", cc = 20, encoding = "BinaryOffset", acceleration = 1.0 }
+        action = { type = "Keystroke", keys = "right", modifiers = [] }
+
+        [[modes.mappings]]
+        trigger = { type = "EncoderTurn", cc = 30, encoding = "BinaryOffset" }
+        action = { type = "Keystroke", keys = "left", modifiers = [] }
+    "#,
+    );
+    let mut engine = MappingEngine::new();
+    engine.load_from_config(&config);
+    let mut processor = EventProcessor::from_config(&config);
+    let now = Instant::now();
+
+    let mapped = |processor: &mut EventProcessor, cc: u8| {
+        processor
+            .process(MidiEvent::ControlChange {
+                channel: 0,
+                cc,
+                value: 67,
+                time: now,
+            })
+            .iter()
+            .find_map(|event| engine.get_action_for_processed(event, 0))
+            .expect("Encoder should be mapped")
+    };
+
+    assert!(matches!(
+        mapped(&mut processor, 20),
+        Action::Repeat { count: 3, .. }
+    ));
+    // Without acceleration the action fires once per message
+    assert!(matches!(
+        mapped(&mut processor, 30),
+        Action::Keystroke { .. }
+    ));
+}
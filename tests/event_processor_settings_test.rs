@@ -0,0 +1,301 @@
+// Copyright 2025 Amiable
+// SPDX-License-Identifier: MIT
+
+//! Integration tests for EventProcessor timing settings
+//!
+//! Tests that `[advanced_settings]` timings are honored by the EventProcessor
+//! and can be changed at runtime (config hot reload), and that per-trigger
+//! timing overrides are applied by the MappingEngine.
+
+mod common;
+
+use common::{Rig, config_with_settings, fires, hit_on};
+use conductor_core::config::AdvancedSettings;
+use conductor_core::event_processor::{ProcessedEvent, VelocityLevel};
+use conductor_core::{Config, EventProcessor, MidiEvent};
+use std::time::{Duration, Instant};
+
+fn is_double_tap(events: &[ProcessedEvent]) -> bool {
+    events
+        .iter()
+        .any(|e| matches!(e, ProcessedEvent::DoubleTap { note: 36, .. }))
+}
+
+/// Tap note 36 twice, `gap_ms` apart, and return the events from the second tap
+fn second_tap(processor: &mut EventProcessor, start: Instant, gap_ms: u64) -> Vec<ProcessedEvent> {
+    processor.process(MidiEvent::NoteOn {
+        channel: 0,
+        note: 36,
+        velocity: 100,
+        time: start,
+    });
+    processor.process(MidiEvent::NoteOff {
+        channel: 0,
+        note: 36,
+        time: start + Duration::from_millis(20),
+    });
+    processor.process(MidiEvent::NoteOn {
+        channel: 0,
+        note: 36,
+        velocity: 100,
+        time: start + Duration::from_millis(gap_ms),
+    })
+}
+
+#[test]
+fn test_apply_settings_changes_double_tap_window() {
+    let mut processor = EventProcessor::new();
+    let now = Instant::now();
+
+    // Default window is 300ms
+    assert!(!is_double_tap(&second_tap(&mut processor, now, 400)));
+
+    processor.apply_settings(&AdvancedSettings {
+        double_tap_timeout_ms: 500,
+        ..AdvancedSettings::default()
+    });
+
+    let later = now + Duration::from_secs(10);
+    assert!(is_double_tap(&second_tap(&mut processor, later, 400)));
+}
+
+#[test]
+fn test_apply_settings_changes_chord_window() {
+    let mut processor = EventProcessor::new();
+    processor.apply_settings(&AdvancedSettings {
+        chord_timeout_ms: 200,
+        ..AdvancedSettings::default()
+    });
+    let now = Instant::now();
+
+    processor.process(MidiEvent::NoteOn {
+        channel: 0,
+        note: 36,
+        velocity: 100,
+        time: now,
+    });
+    let events = processor.process(MidiEvent::NoteOn {
+        channel: 0,
+        note: 40,
+        velocity: 100,
+        time: now + Duration::from_millis(150),
+    });
+
+    assert!(
+        events
+            .iter()
+            .any(|e| matches!(e, ProcessedEvent::ChordDetected { .. })),
+        "Notes 150ms apart should form a chord with a 200ms window"
+    );
+}
+
+#[test]
+fn test_with_settings_constructor() {
+    let mut processor = EventProcessor::with_settings(&AdvancedSettings {
+        double_tap_timeout_ms: 500,
+        ..AdvancedSettings::default()
+    });
+
+    assert!(is_double_tap(&second_tap(
+        &mut processor,
+        Instant::now(),
+        400
+    )));
+}
+
+#[test]
+fn test_per_trigger_double_tap_window_wider_than_global() {
+    let Rig {
+        engine,
+        mut processor,
+    } = Rig::new(&hit_on("type = \"DoubleTap\"\nnote = 36\ntimeout_ms = 600"));
+
+    // 500ms is outside the global 300ms window but inside the trigger's 600ms
+    let events = second_tap(&mut processor, Instant::now(), 500);
+    assert!(fires(&engine, &events));
+}
+
+#[test]
+fn test_per_trigger_double_tap_window_narrower_than_global() {
+    let Rig {
+        engine,
+        mut processor,
+    } = Rig::new(&hit_on("type = \"DoubleTap\"\nnote = 36\ntimeout_ms = 100"));
+
+    let events = second_tap(&mut processor, Instant::now(), 200);
+    assert!(is_double_tap(&events), "Processor still reports the tap");
+    assert!(
+        !fires(&engine, &events),
+        "But the trigger's window is 100ms"
+    );
+}
+
+#[test]
+fn test_long_press_uses_hold_threshold_and_duration_override() {
+    let hold = |config: &Config, held_ms: u64| {
+        let Rig {
+            engine,
+            mut processor,
+        } = Rig::from_config(config);
+        let now = Instant::now();
+        processor.process(MidiEvent::NoteOn {
+            channel: 0,
+            note: 36,
+            velocity: 100,
+            time: now,
+        });
+        let released = processor.process(MidiEvent::NoteOff {
+            channel: 0,
+            note: 36,
+            time: now + Duration::from_millis(held_ms),
+        });
+        fires(&engine, &released)
+    };
+
+    let global = config_with_settings(
+        "hold_threshold_ms = 500",
+        &hit_on("type = \"LongPress\"\nnote = 36"),
+    );
+    assert!(!hold(&global, 400));
+    assert!(hold(&global, 600));
+
+    let overridden = config_with_settings(
+        "hold_threshold_ms = 500",
+        &hit_on("type = \"LongPress\"\nnote = 36\nduration_ms = 1500"),
+    );
+    assert!(!hold(&overridden, 1000));
+    assert!(hold(&overridden, 1600));
+}
+
+#[test]
+fn test_velocity_range_soft_max_override() {
+    let Rig {
+        engine,
+        mut processor,
+    } = Rig::new(&hit_on(
+        "type = \"VelocityRange\"\nnote = 36\nsoft_max = 60\nlevel = \"Soft\"",
+    ));
+
+    // 50 is Medium with the default bands but Soft with soft_max = 60
+    let events = processor.process(MidiEvent::NoteOn {
+        channel: 0,
+        note: 36,
+        velocity: 50,
+        time: Instant::now(),
+    });
+    assert!(fires(&engine, &events));
+}
+
+#[test]
+fn test_per_trigger_chord_window() {
+    let Rig {
+        engine,
+        mut processor,
+    } = Rig::new(&hit_on(
+        "type = \"NoteChord\"\nnotes = [36, 40]\ntimeout_ms = 150",
+    ));
+    let now = Instant::now();
+
+    processor.process(MidiEvent::NoteOn {
+        channel: 0,
+        note: 36,
+        velocity: 100,
+        time: now,
+    });
+    let events = processor.process(MidiEvent::NoteOn {
+        channel: 0,
+        note: 40,
+        velocity: 100,
+        time: now + Duration::from_millis(100),
+    });
+    assert!(fires(&engine, &events));
+}
+
+/// Press and release note 36 after `held_ms`, returning the release events
+fn press_for(processor: &mut EventProcessor, held_ms: u64) -> Vec<ProcessedEvent> {
+    let now = Instant::now();
+    processor.process(MidiEvent::NoteOn {
+        channel: 0,
+        note: 36,
+        velocity: 100,
+        time: now,
+    });
+    processor.process(MidiEvent::NoteOff {
+        channel: 0,
+        note: 36,
+        time: now + Duration::from_millis(held_ms),
+    })
+}
+
+/// Which of short, medium and long press a release reports
+fn press_kind(events: &[ProcessedEvent]) -> Option<&'static str> {
+    events.iter().find_map(|e| match e {
+        ProcessedEvent::ShortPress { .. } => Some("short"),
+        ProcessedEvent::MediumPress { .. } => Some("medium"),
+        ProcessedEvent::LongPress { .. } => Some("long"),
+        _ => None,
+    })
+}
+
+#[test]
+fn test_press_duration_cut_offs() {
+    let mut processor = EventProcessor::with_settings(&AdvancedSettings {
+        short_press_ms: 100,
+        medium_press_ms: 500,
+        hold_threshold_ms: 800,
+        ..AdvancedSettings::default()
+    });
+
+    assert_eq!(press_kind(&press_for(&mut processor, 50)), Some("short"));
+    assert_eq!(press_kind(&press_for(&mut processor, 150)), Some("medium"));
+    // Past the medium cut-off but short of the hold threshold
+    assert_eq!(press_kind(&press_for(&mut processor, 600)), None);
+    assert_eq!(press_kind(&press_for(&mut processor, 900)), Some("long"));
+}
+
+#[test]
+fn test_release_long_press_waits_for_hold_threshold() {
+    let mut processor = EventProcessor::new();
+
+    // 1.2s is past the medium cut-off but short of the default 2s threshold
+    assert_eq!(press_kind(&press_for(&mut processor, 1200)), None);
+    assert_eq!(press_kind(&press_for(&mut processor, 2100)), Some("long"));
+}
+
+#[test]
+fn test_velocity_bands_from_settings() {
+    let Rig {
+        engine,
+        mut processor,
+    } = Rig::with_settings(
+        "velocity_soft_max = 60\nvelocity_medium_max = 100",
+        &hit_on("type = \"VelocityRange\"\nnote = 36\nlevel = \"Medium\""),
+    );
+
+    let mut press = |velocity| {
+        let events = processor.process(MidiEvent::NoteOn {
+            channel: 0,
+            note: 36,
+            velocity,
+            time: Instant::now(),
+        });
+        processor.process(MidiEvent::NoteOff {
+            channel: 0,
+            note: 36,
+            time: Instant::now(),
+        });
+        events
+    };
+
+    // 50 is Medium with the default bands, 90 is Hard
+    let soft = press(50);
+    assert!(soft.iter().any(|e| matches!(
+        e,
+        ProcessedEvent::PadPressed {
+            velocity_level: VelocityLevel::Soft,
+            ..
+        }
+    )));
+    assert!(!fires(&engine, &soft));
+    assert!(fires(&engine, &press(90)));
+}
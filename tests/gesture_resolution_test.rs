@@ -0,0 +1,268 @@
+// Copyright 2025 Amiable
+// SPDX-License-Identifier: MIT
+
+//! Integration tests for deferred single-press resolution
+//!
+//! Tests that a pad with both a single-press mapping and a competing double-tap
+//! or chord mapping resolves to exactly one gesture, and that `immediate`
+//! mappings opt out of the delay.
+
+mod common;
+
+use common::{Rig, texts};
+use conductor_core::event_processor::ProcessedEvent;
+use conductor_core::{EventProcessor, MappingEngine, MidiEvent};
+use std::time::{Duration, Instant};
+
+const TAP_AND_DOUBLE_TAP: &str = r#"
+    [[modes.mappings]]
+    trigger = { type = "Note", note = 36 }
+    action = { type = "Text", text = "tap" }
+
+    [[modes.mappings]]
+    trigger = { type = "DoubleTap", note = 36 }
+    action = { type = "Text", text = "double" }
+"#;
+
+const NOTES_AND_CHORD: &str = r#"
+    [[modes.mappings]]
+    trigger = { type = "Note", note = 36 }
+    action = { type = "Text", text = "tap" }
+
+    [[modes.mappings]]
+    trigger = { type = "Note", note = 40 }
+    action = { type = "Text", text = "other" }
+
+    [[modes.mappings]]
+    trigger = { type = "NoteChord", notes = [36, 40], timeout_ms = 100 }
+    action = { type = "Text", text = "chord" }
+"#;
+
+/// Texts of the actions a batch of processed events maps to, as the daemon
+/// dispatches them
+fn fired(engine: &MappingEngine, events: &[ProcessedEvent]) -> Vec<String> {
+    let mapped: Vec<_> = engine
+        .get_mapped_actions_for_batch(events, 0, &[])
+        .into_iter()
+        .map(|(_, mapped)| mapped)
+        .collect();
+    texts(&mapped)
+}
+
+fn press(processor: &mut EventProcessor, note: u8, time: Instant) -> Vec<ProcessedEvent> {
+    processor.process(MidiEvent::NoteOn {
+        channel: 0,
+        note,
+        velocity: 100,
+        time,
+    })
+}
+
+fn release(processor: &mut EventProcessor, note: u8, time: Instant) -> Vec<ProcessedEvent> {
+    processor.process(MidiEvent::NoteOff {
+        channel: 0,
+        note,
+        time,
+    })
+}
+
+#[test]
+fn test_single_tap_waits_for_double_tap_window() {
+    let Rig {
+        engine,
+        mut processor,
+    } = Rig::new(TAP_AND_DOUBLE_TAP);
+    let now = Instant::now();
+
+    let mut actions = fired(&engine, &press(&mut processor, 36, now));
+    actions.extend(fired(
+        &engine,
+        &release(&mut processor, 36, now + Duration::from_millis(50)),
+    ));
+    assert!(actions.is_empty(), "Single tap is held back");
+
+    let mid = processor.tick(now + Duration::from_millis(200));
+    assert!(fired(&engine, &mid).is_empty());
+
+    let resolved = processor.tick(now + Duration::from_millis(300));
+    assert_eq!(fired(&engine, &resolved), vec!["tap"]);
+}
+
+#[test]
+fn test_double_tap_fires_only_double_tap() {
+    let Rig {
+        engine,
+        mut processor,
+    } = Rig::new(TAP_AND_DOUBLE_TAP);
+    let now = Instant::now();
+
+    let mut actions = fired(&engine, &press(&mut processor, 36, now));
+    actions.extend(fired(
+        &engine,
+        &release(&mut processor, 36, now + Duration::from_millis(50)),
+    ));
+    actions.extend(fired(
+        &engine,
+        &press(&mut processor, 36, now + Duration::from_millis(150)),
+    ));
+    actions.extend(fired(
+        &engine,
+        &release(&mut processor, 36, now + Duration::from_millis(200)),
+    ));
+    actions.extend(fired(
+        &engine,
+        &processor.tick(now + Duration::from_millis(1000)),
+    ));
+
+    assert_eq!(actions, vec!["double"]);
+}
+
+#[test]
+fn test_pending_tap_resolves_on_next_event() {
+    let Rig {
+        engine,
+        mut processor,
+    } = Rig::new(TAP_AND_DOUBLE_TAP);
+    let now = Instant::now();
+
+    press(&mut processor, 36, now);
+    release(&mut processor, 36, now + Duration::from_millis(50));
+
+    // Without a tick, the next event past the window resolves the first tap
+    let events = press(&mut processor, 36, now + Duration::from_millis(1000));
+    assert_eq!(fired(&engine, &events), vec!["tap"]);
+}
+
+#[test]
+fn test_chord_fires_only_chord() {
+    let Rig {
+        engine,
+        mut processor,
+    } = Rig::new(NOTES_AND_CHORD);
+    let now = Instant::now();
+
+    let mut actions = fired(&engine, &press(&mut processor, 36, now));
+    actions.extend(fired(
+        &engine,
+        &press(&mut processor, 40, now + Duration::from_millis(30)),
+    ));
+    actions.extend(fired(
+        &engine,
+        &processor.tick(now + Duration::from_millis(1000)),
+    ));
+
+    assert_eq!(actions, vec!["chord"]);
+}
+
+#[test]
+fn test_unmapped_chord_does_not_swallow_tap() {
+    let Rig {
+        engine,
+        mut processor,
+    } = Rig::new(NOTES_AND_CHORD);
+    let now = Instant::now();
+
+    press(&mut processor, 36, now);
+    press(&mut processor, 41, now + Duration::from_millis(10));
+
+    let resolved = processor.tick(now + Duration::from_millis(100));
+    assert_eq!(fired(&engine, &resolved), vec!["tap"]);
+}
+
+#[test]
+fn test_immediate_mapping_is_not_deferred() {
+    let Rig {
+        engine,
+        mut processor,
+    } = Rig::new(
+        r#"
+        [[modes.mappings]]
+        trigger = { type = "Note", note = 36 }
+        action = { type = "Text", text = "tap" }
+        immediate = true
+
+        [[modes.mappings]]
+        trigger = { type = "DoubleTap", note = 36 }
+        action = { type = "Text", text = "double" }
+    "#,
+    );
+
+    let events = press(&mut processor, 36, Instant::now());
+    assert_eq!(fired(&engine, &events), vec!["tap"]);
+}
+
+#[test]
+fn test_pad_without_competing_gesture_is_not_deferred() {
+    let Rig {
+        engine,
+        mut processor,
+    } = Rig::new(TAP_AND_DOUBLE_TAP);
+
+    let events = press(&mut processor, 37, Instant::now());
+    assert!(
+        events
+            .iter()
+            .any(|e| matches!(e, ProcessedEvent::PadPressed { note: 37, .. }))
+    );
+    assert!(fired(&engine, &events).is_empty());
+}
+
+#[test]
+fn test_gesture_on_other_channel_does_not_defer_tap() {
+    let Rig {
+        engine,
+        mut processor,
+    } = Rig::new(
+        r#"
+        [[modes.mappings]]
+        trigger = { type = "Note", note = 36 }
+        action = { type = "Text", text = "tap" }
+
+        [[modes.mappings]]
+        trigger = { type = "DoubleTap", note = 36, channel = 0 }
+        action = { type = "Text", text = "double" }
+    "#,
+    );
+    let now = Instant::now();
+
+    // Two quick taps on channel 2, where the double-tap can't fire
+    let mut actions = Vec::new();
+    for offset in [0, 150] {
+        let time = now + Duration::from_millis(offset);
+        actions.extend(fired(
+            &engine,
+            &processor.process(MidiEvent::NoteOn {
+                channel: 1,
+                note: 36,
+                velocity: 100,
+                time,
+            }),
+        ));
+        actions.extend(fired(
+            &engine,
+            &processor.process(MidiEvent::NoteOff {
+                channel: 1,
+                note: 36,
+                time: time + Duration::from_millis(50),
+            }),
+        ));
+    }
+    actions.extend(fired(
+        &engine,
+        &processor.tick(now + Duration::from_millis(1000)),
+    ));
+    assert_eq!(actions, vec!["tap", "tap"]);
+
+    // On channel 1 the taps still resolve to the double-tap
+    let later = now + Duration::from_millis(2000);
+    let mut actions = fired(&engine, &press(&mut processor, 36, later));
+    actions.extend(fired(
+        &engine,
+        &press(&mut processor, 36, later + Duration::from_millis(150)),
+    ));
+    actions.extend(fired(
+        &engine,
+        &processor.tick(later + Duration::from_millis(1000)),
+    ));
+    assert_eq!(actions, vec!["double"]);
+}
@@ -0,0 +1,168 @@
+// Copyright 2025 Amiable
+// SPDX-License-Identifier: MIT
+
+//! Integration tests for 14-bit CC, NRPN and RPN input
+//!
+//! Tests that `HighResDecoder` pairs MSB/LSB control changes and assembles
+//! NRPN/RPN data entry from raw MIDI bytes, and that `HighResCC`, `Nrpn` and
+//! `Rpn` triggers match the resulting events within their value ranges.
+
+mod common;
+
+use common::config_with_mappings;
+use conductor_core::event_processor::{HighResDecoder, MidiEvent, ProcessedEvent};
+use conductor_core::{Action, EventProcessor, MappingEngine, VolumeOperation};
+
+/// Decode a stream of raw CC messages on channel 1
+fn decode(decoder: &mut HighResDecoder, messages: &[[u8; 2]]) -> Vec<MidiEvent> {
+    messages
+        .iter()
+        .flat_map(|[cc, value]| {
+            let event = MidiEvent::from_midi_msg(&[0xB0, *cc, *value]).expect("Valid CC");
+            decoder.decode(event)
+        })
+        .collect()
+}
+
+/// The 14-bit events among decoded events, as (kind, number, value)
+fn high_res(events: &[MidiEvent]) -> Vec<(&'static str, u16, u16)> {
+    events
+        .iter()
+        .filter_map(|event| match event {
+            MidiEvent::HighResCC { cc, value, .. } => Some(("cc", *cc as u16, *value)),
+            MidiEvent::Nrpn {
+                parameter, value, ..
+            } => Some(("nrpn", *parameter, *value)),
+            MidiEvent::Rpn {
+                parameter, value, ..
+            } => Some(("rpn", *parameter, *value)),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn test_msb_lsb_pairs() {
+    let mut decoder = HighResDecoder::default();
+
+    let events = decode(&mut decoder, &[[7, 0x40], [39, 0x01]]);
+    assert_eq!(high_res(&events), vec![("cc", 7, 0x2001)]);
+
+    // Plain control changes still pass through
+    assert_eq!(events.len(), 3);
+
+    // Fine movement resends only the LSB
+    let events = decode(&mut decoder, &[[39, 0x02]]);
+    assert_eq!(high_res(&events), vec![("cc", 7, 0x2002)]);
+}
+
+#[test]
+fn test_lsb_without_msb_is_plain_cc() {
+    let mut decoder = HighResDecoder::default();
+
+    let events = decode(&mut decoder, &[[40, 10], [1, 64]]);
+    assert!(high_res(&events).is_empty());
+    assert_eq!(events.len(), 2);
+}
+
+#[test]
+fn test_nrpn_assembly() {
+    let mut decoder = HighResDecoder::default();
+
+    let events = decode(&mut decoder, &[[99, 10], [98, 3], [6, 0x7F], [38, 0x7F]]);
+    assert_eq!(high_res(&events), vec![("nrpn", 10 << 7 | 3, 16383)]);
+
+    // Data entry keeps applying to the selected parameter
+    let events = decode(&mut decoder, &[[6, 0], [38, 5]]);
+    assert_eq!(high_res(&events), vec![("nrpn", 10 << 7 | 3, 5)]);
+}
+
+#[test]
+fn test_rpn_null_ends_data_entry() {
+    let mut decoder = HighResDecoder::default();
+
+    let events = decode(&mut decoder, &[[101, 0], [100, 0], [6, 2], [38, 0]]);
+    assert_eq!(high_res(&events), vec![("rpn", 0, 2 << 7)]);
+
+    // After the null parameter, CC 6/38 are an ordinary 14-bit pair
+    let events = decode(&mut decoder, &[[101, 127], [100, 127], [6, 2], [38, 0]]);
+    assert_eq!(high_res(&events), vec![("cc", 6, 2 << 7)]);
+}
+
+#[test]
+fn test_channels_are_independent() {
+    let mut decoder = HighResDecoder::default();
+
+    decoder.decode(MidiEvent::from_midi_msg(&[0xB0, 7, 0x40]).unwrap());
+    let events = decoder.decode(MidiEvent::from_midi_msg(&[0xB1, 39, 0x01]).unwrap());
+    assert!(high_res(&events).is_empty());
+}
+
+#[test]
+fn test_high_res_cc_trigger_value_range() {
+    let config = config_with_mappings(
+        r#"
+        [[modes.mappings]]
+        trigger = { type = "HighResCC", cc = 7, value_min = 8192 }
+        action = { type = "Text", text = "upper half" }
+    "#,
+    );
+    let mut engine = MappingEngine::new();
+    engine.load_from_config(&config);
+    let mut processor = EventProcessor::from_config(&config);
+    let mut decoder = HighResDecoder::default();
+
+    let mut fired = |msb: u8, lsb: u8| {
+        decode(&mut decoder, &[[7, msb], [39, lsb]])
+            .into_iter()
+            .flat_map(|event| processor.process(event))
+            .filter_map(|event| engine.get_action_for_processed(&event, 0))
+            .count()
+    };
+
+    assert_eq!(fired(0x3F, 0x7F), 0);
+    assert_eq!(fired(0x40, 0x00), 1);
+}
+
+#[test]
+fn test_nrpn_trigger_drives_continuous_value() {
+    let engine = {
+        let mut engine = MappingEngine::new();
+        engine.load_from_config(&config_with_mappings(
+            r#"
+            [[modes.mappings]]
+            trigger = { type = "Nrpn", parameter = 1283 }
+            action = { type = "VolumeControl", operation = "Set" }
+            continuous = { min = 0, max = 100 }
+        "#,
+        ));
+        engine
+    };
+
+    let event = ProcessedEvent::NrpnChanged {
+        channel: 0,
+        parameter: 1283,
+        value: 16383,
+    };
+    assert!(matches!(
+        engine.get_action_for_processed(&event, 0),
+        Some(Action::VolumeControl {
+            operation: VolumeOperation::Set,
+            value: Some(100),
+        })
+    ));
+
+    // Other parameters and RPNs with the same number don't match
+    let other = ProcessedEvent::NrpnChanged {
+        channel: 0,
+        parameter: 1284,
+        value: 16383,
+    };
+    assert!(engine.get_action_for_processed(&other, 0).is_none());
+    let rpn = ProcessedEvent::RpnChanged {
+        channel: 0,
+        parameter: 1283,
+        value: 16383,
+    };
+    assert!(engine.get_action_for_processed(&rpn, 0).is_none());
+}
@@ -0,0 +1,149 @@
+// Copyright 2025 Amiable
+// SPDX-License-Identifier: MIT
+
+//! Integration tests for timer-driven hold detection
+//!
+//! Tests that `EventProcessor::tick` reports holds while pads are still held,
+//! so LongPress triggers fire at their threshold (and repeat) without waiting
+//! for the release.
+
+mod common;
+
+use common::{Rig, hit_on, hits};
+use conductor_core::event_processor::ProcessedEvent;
+use conductor_core::{EventProcessor, MidiEvent};
+use std::time::{Duration, Instant};
+
+fn press(processor: &mut EventProcessor, time: Instant) {
+    processor.process(MidiEvent::NoteOn {
+        channel: 0,
+        note: 36,
+        velocity: 100,
+        time,
+    });
+}
+
+fn release(processor: &mut EventProcessor, time: Instant) -> Vec<ProcessedEvent> {
+    processor.process(MidiEvent::NoteOff {
+        channel: 0,
+        note: 36,
+        time,
+    })
+}
+
+fn long_presses(events: &[ProcessedEvent]) -> usize {
+    events
+        .iter()
+        .filter(|e| matches!(e, ProcessedEvent::LongPress { note: 36, .. }))
+        .count()
+}
+
+#[test]
+fn test_long_press_fires_while_held() {
+    let Rig {
+        engine,
+        mut processor,
+    } = Rig::with_settings(
+        "hold_threshold_ms = 500",
+        &hit_on("type = \"LongPress\"\nnote = 36"),
+    );
+    let now = Instant::now();
+
+    press(&mut processor, now);
+
+    let early = processor.tick(now + Duration::from_millis(400));
+    assert_eq!(hits(&engine, &early), 0, "Below the threshold");
+
+    let at_threshold = processor.tick(now + Duration::from_millis(520));
+    assert_eq!(hits(&engine, &at_threshold), 1);
+
+    let later = processor.tick(now + Duration::from_millis(900));
+    assert_eq!(hits(&engine, &later), 0, "Fires once without repeat_ms");
+
+    let released = release(&mut processor, now + Duration::from_millis(1000));
+    assert_eq!(hits(&engine, &released), 0, "Release does not fire again");
+}
+
+#[test]
+fn test_tick_emits_long_press_once() {
+    let Rig { mut processor, .. } = Rig::with_settings(
+        "hold_threshold_ms = 500",
+        &hit_on("type = \"Note\"\nnote = 36"),
+    );
+    let now = Instant::now();
+
+    press(&mut processor, now);
+    let ticked = processor.tick(now + Duration::from_millis(600));
+    assert_eq!(long_presses(&ticked), 1);
+    assert!(ticked.iter().any(|e| matches!(
+        e,
+        ProcessedEvent::HoldDetected {
+            note: 36,
+            held_ms: 600,
+            previous_ms: 0,
+            ..
+        }
+    )));
+
+    let ticked = processor.tick(now + Duration::from_millis(800));
+    assert_eq!(long_presses(&ticked), 0);
+
+    let released = release(&mut processor, now + Duration::from_millis(1500));
+    assert_eq!(long_presses(&released), 0);
+}
+
+#[test]
+fn test_long_press_repeat() {
+    let Rig {
+        engine,
+        mut processor,
+    } = Rig::new(&hit_on(
+        "type = \"LongPress\"\nnote = 36\nduration_ms = 500\nrepeat_ms = 100",
+    ));
+    let now = Instant::now();
+
+    press(&mut processor, now);
+
+    let mut fired = 0;
+    for ms in (20..=800).step_by(20) {
+        fired += hits(&engine, &processor.tick(now + Duration::from_millis(ms)));
+    }
+
+    // Once at 500ms, then at 600, 700 and 800ms
+    assert_eq!(fired, 4);
+}
+
+#[test]
+fn test_per_trigger_duration_shorter_than_hold_threshold_fires_on_tick() {
+    let Rig {
+        engine,
+        mut processor,
+    } = Rig::new(&hit_on(
+        "type = \"LongPress\"\nnote = 36\nduration_ms = 300",
+    ));
+    let now = Instant::now();
+
+    press(&mut processor, now);
+    let events = processor.tick(now + Duration::from_millis(320));
+    assert_eq!(hits(&engine, &events), 1);
+    assert_eq!(long_presses(&events), 0, "Global threshold not reached yet");
+}
+
+#[test]
+fn test_held_pads_reports_elapsed_time() {
+    let mut processor = EventProcessor::new();
+    let now = Instant::now();
+
+    press(&mut processor, now);
+    assert_eq!(
+        processor.held_pads(now + Duration::from_millis(250)),
+        vec![(0, 36, 250)]
+    );
+
+    release(&mut processor, now + Duration::from_millis(300));
+    assert!(
+        processor
+            .held_pads(now + Duration::from_millis(350))
+            .is_empty()
+    );
+}
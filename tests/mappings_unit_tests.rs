@@ -36,12 +36,19 @@ fn create_test_config() -> Config {
                     trigger: Trigger::Note {
                         note: 60,
                         velocity_min: Some(1),
+                        channel: None,
                     },
                     action: ActionConfig::Keystroke {
                         keys: "a".to_string(),
                         modifiers: vec![],
                     },
                     description: Some("Test mapping".to_string()),
+                    immediate: false,
+                    on_release: None,
+                    momentary: false,
+                    continuous: None,
+                    while_held: vec![],
+                    concurrency: Default::default(),
                 }],
             },
             Mode {
@@ -51,12 +58,19 @@ fn create_test_config() -> Config {
                     trigger: Trigger::Note {
                         note: 61,
                         velocity_min: Some(1),
+                        channel: None,
                     },
                     action: ActionConfig::Keystroke {
                         keys: "b".to_string(),
                         modifiers: vec![],
                     },
                     description: Some("Mode 1 mapping".to_string()),
+                    immediate: false,
+                    on_release: None,
+                    momentary: false,
+                    continuous: None,
+                    while_held: vec![],
+                    concurrency: Default::default(),
                 }],
             },
         ],
@@ -64,13 +78,21 @@ fn create_test_config() -> Config {
             trigger: Trigger::Note {
                 note: 127,
                 velocity_min: Some(1),
+                channel: None,
             },
             action: ActionConfig::Shell {
                 command: "exit".to_string(),
             },
             description: Some("Global exit".to_string()),
+            immediate: false,
+            on_release: None,
+            momentary: false,
+            continuous: None,
+            while_held: vec![],
+            concurrency: Default::default(),
         }],
         advanced_settings: Default::default(),
+        variables: Default::default(),
         logging: None,
     }
 }
@@ -85,6 +107,7 @@ fn test_mapping_engine_new() {
     // Engine should be created successfully
     // We can't inspect internals directly, but we can verify it doesn't panic
     let event = MidiEvent::NoteOn {
+        channel: 0,
         note: 60,
         velocity: 100,
         time: Instant::now(),
@@ -102,6 +125,7 @@ fn test_load_from_config_basic() {
 
     // Should find mapping for note 60 in mode 0
     let event = MidiEvent::NoteOn {
+        channel: 0,
         note: 60,
         velocity: 100,
         time: Instant::now(),
@@ -122,12 +146,14 @@ fn test_load_empty_config() {
         modes: vec![],
         global_mappings: vec![],
         advanced_settings: Default::default(),
+        variables: Default::default(),
         logging: None,
     };
 
     engine.load_from_config(&config);
 
     let event = MidiEvent::NoteOn {
+        channel: 0,
         note: 60,
         velocity: 100,
         time: Instant::now(),
@@ -153,11 +179,18 @@ fn test_load_config_with_multiple_modes() {
                     trigger: Trigger::Note {
                         note: 60,
                         velocity_min: None,
+                        channel: None,
                     },
                     action: ActionConfig::Text {
                         text: "mode0".to_string(),
                     },
                     description: None,
+                    immediate: false,
+                    on_release: None,
+                    momentary: false,
+                    continuous: None,
+                    while_held: vec![],
+                    concurrency: Default::default(),
                 }],
             },
             Mode {
@@ -167,11 +200,18 @@ fn test_load_config_with_multiple_modes() {
                     trigger: Trigger::Note {
                         note: 61,
                         velocity_min: None,
+                        channel: None,
                     },
                     action: ActionConfig::Text {
                         text: "mode1".to_string(),
                     },
                     description: None,
+                    immediate: false,
+                    on_release: None,
+                    momentary: false,
+                    continuous: None,
+                    while_held: vec![],
+                    concurrency: Default::default(),
                 }],
             },
             Mode {
@@ -181,16 +221,24 @@ fn test_load_config_with_multiple_modes() {
                     trigger: Trigger::Note {
                         note: 62,
                         velocity_min: None,
+                        channel: None,
                     },
                     action: ActionConfig::Text {
                         text: "mode2".to_string(),
                     },
                     description: None,
+                    immediate: false,
+                    on_release: None,
+                    momentary: false,
+                    continuous: None,
+                    while_held: vec![],
+                    concurrency: Default::default(),
                 }],
             },
         ],
         global_mappings: vec![],
         advanced_settings: Default::default(),
+        variables: Default::default(),
         logging: None,
     };
 
@@ -198,16 +246,19 @@ fn test_load_config_with_multiple_modes() {
 
     // Each mode should have its own mapping
     let event0 = MidiEvent::NoteOn {
+        channel: 0,
         note: 60,
         velocity: 100,
         time: Instant::now(),
     };
     let event1 = MidiEvent::NoteOn {
+        channel: 0,
         note: 61,
         velocity: 100,
         time: Instant::now(),
     };
     let event2 = MidiEvent::NoteOn {
+        channel: 0,
         note: 62,
         velocity: 100,
         time: Instant::now(),
@@ -239,15 +290,23 @@ fn test_compile_note_trigger_with_velocity_min() {
                 trigger: Trigger::Note {
                     note: 60,
                     velocity_min: Some(50),
+                    channel: None,
                 },
                 action: ActionConfig::Text {
                     text: "test".to_string(),
                 },
                 description: None,
+                immediate: false,
+                on_release: None,
+                momentary: false,
+                continuous: None,
+                while_held: vec![],
+                concurrency: Default::default(),
             }],
         }],
         global_mappings: vec![],
         advanced_settings: Default::default(),
+        variables: Default::default(),
         logging: None,
     };
 
@@ -255,6 +314,7 @@ fn test_compile_note_trigger_with_velocity_min() {
 
     // Should match with velocity >= 50
     let event_high = MidiEvent::NoteOn {
+        channel: 0,
         note: 60,
         velocity: 100,
         time: Instant::now(),
@@ -263,6 +323,7 @@ fn test_compile_note_trigger_with_velocity_min() {
 
     // Should not match with velocity < 50
     let event_low = MidiEvent::NoteOn {
+        channel: 0,
         note: 60,
         velocity: 30,
         time: Instant::now(),
@@ -287,15 +348,23 @@ fn test_compile_note_trigger_without_velocity_min() {
                 trigger: Trigger::Note {
                     note: 60,
                     velocity_min: None,
+                    channel: None,
                 },
                 action: ActionConfig::Text {
                     text: "test".to_string(),
                 },
                 description: None,
+                immediate: false,
+                on_release: None,
+                momentary: false,
+                continuous: None,
+                while_held: vec![],
+                concurrency: Default::default(),
             }],
         }],
         global_mappings: vec![],
         advanced_settings: Default::default(),
+        variables: Default::default(),
         logging: None,
     };
 
@@ -303,6 +372,7 @@ fn test_compile_note_trigger_without_velocity_min() {
 
     // Should match with any velocity >= 1 (default)
     let event_low = MidiEvent::NoteOn {
+        channel: 0,
         note: 60,
         velocity: 1,
         time: Instant::now(),
@@ -310,6 +380,7 @@ fn test_compile_note_trigger_without_velocity_min() {
     assert!(engine.get_action(&event_low, 0).is_some());
 
     let event_high = MidiEvent::NoteOn {
+        channel: 0,
         note: 60,
         velocity: 127,
         time: Instant::now(),
@@ -334,15 +405,24 @@ fn test_compile_cc_trigger_with_value_min() {
                 trigger: Trigger::CC {
                     cc: 1,
                     value_min: Some(64),
+                    value_max: None,
+                    channel: None,
                 },
                 action: ActionConfig::Text {
                     text: "test".to_string(),
                 },
                 description: None,
+                immediate: false,
+                on_release: None,
+                momentary: false,
+                continuous: None,
+                while_held: vec![],
+                concurrency: Default::default(),
             }],
         }],
         global_mappings: vec![],
         advanced_settings: Default::default(),
+        variables: Default::default(),
         logging: None,
     };
 
@@ -350,6 +430,7 @@ fn test_compile_cc_trigger_with_value_min() {
 
     // Should match with value >= 64
     let event_high = MidiEvent::ControlChange {
+        channel: 0,
         cc: 1,
         value: 100,
         time: Instant::now(),
@@ -358,6 +439,7 @@ fn test_compile_cc_trigger_with_value_min() {
 
     // Should not match with value < 64
     let event_low = MidiEvent::ControlChange {
+        channel: 0,
         cc: 1,
         value: 30,
         time: Instant::now(),
@@ -382,15 +464,24 @@ fn test_compile_cc_trigger_without_value_min() {
                 trigger: Trigger::CC {
                     cc: 1,
                     value_min: None,
+                    value_max: None,
+                    channel: None,
                 },
                 action: ActionConfig::Text {
                     text: "test".to_string(),
                 },
                 description: None,
+                immediate: false,
+                on_release: None,
+                momentary: false,
+                continuous: None,
+                while_held: vec![],
+                concurrency: Default::default(),
             }],
         }],
         global_mappings: vec![],
         advanced_settings: Default::default(),
+        variables: Default::default(),
         logging: None,
     };
 
@@ -398,6 +489,7 @@ fn test_compile_cc_trigger_without_value_min() {
 
     // Should match with any value >= 0 (default)
     let event_zero = MidiEvent::ControlChange {
+        channel: 0,
         cc: 1,
         value: 0,
         time: Instant::now(),
@@ -405,6 +497,7 @@ fn test_compile_cc_trigger_without_value_min() {
     assert!(engine.get_action(&event_zero, 0).is_some());
 
     let event_high = MidiEvent::ControlChange {
+        channel: 0,
         cc: 1,
         value: 127,
         time: Instant::now(),
@@ -429,15 +522,23 @@ fn test_compile_note_chord_trigger() {
                 trigger: Trigger::NoteChord {
                     notes: vec![60, 64, 67],
                     timeout_ms: None,
+                    channel: None,
                 },
                 action: ActionConfig::Text {
                     text: "chord".to_string(),
                 },
                 description: None,
+                immediate: false,
+                on_release: None,
+                momentary: false,
+                continuous: None,
+                while_held: vec![],
+                concurrency: Default::default(),
             }],
         }],
         global_mappings: vec![],
         advanced_settings: Default::default(),
+        variables: Default::default(),
         logging: None,
     };
 
@@ -447,6 +548,7 @@ fn test_compile_note_chord_trigger() {
     // This test verifies that the trigger compiles without error
     // Matching will always return false for chord triggers currently
     let event = MidiEvent::NoteOn {
+        channel: 0,
         note: 60,
         velocity: 100,
         time: Instant::now(),
@@ -476,15 +578,23 @@ fn test_note_trigger_matches_exact_note() {
                 trigger: Trigger::Note {
                     note: 60,
                     velocity_min: Some(1),
+                    channel: None,
                 },
                 action: ActionConfig::Text {
                     text: "test".to_string(),
                 },
                 description: None,
+                immediate: false,
+                on_release: None,
+                momentary: false,
+                continuous: None,
+                while_held: vec![],
+                concurrency: Default::default(),
             }],
         }],
         global_mappings: vec![],
         advanced_settings: Default::default(),
+        variables: Default::default(),
         logging: None,
     };
 
@@ -492,6 +602,7 @@ fn test_note_trigger_matches_exact_note() {
 
     // Should match exact note
     let event = MidiEvent::NoteOn {
+        channel: 0,
         note: 60,
         velocity: 100,
         time: Instant::now(),
@@ -500,6 +611,7 @@ fn test_note_trigger_matches_exact_note() {
 
     // Should not match different note
     let event_diff = MidiEvent::NoteOn {
+        channel: 0,
         note: 61,
         velocity: 100,
         time: Instant::now(),
@@ -524,15 +636,23 @@ fn test_note_trigger_velocity_threshold() {
                 trigger: Trigger::Note {
                     note: 60,
                     velocity_min: Some(80),
+                    channel: None,
                 },
                 action: ActionConfig::Text {
                     text: "hard press".to_string(),
                 },
                 description: None,
+                immediate: false,
+                on_release: None,
+                momentary: false,
+                continuous: None,
+                while_held: vec![],
+                concurrency: Default::default(),
             }],
         }],
         global_mappings: vec![],
         advanced_settings: Default::default(),
+        variables: Default::default(),
         logging: None,
     };
 
@@ -540,6 +660,7 @@ fn test_note_trigger_velocity_threshold() {
 
     // Should match at threshold
     let event_threshold = MidiEvent::NoteOn {
+        channel: 0,
         note: 60,
         velocity: 80,
         time: Instant::now(),
@@ -548,6 +669,7 @@ fn test_note_trigger_velocity_threshold() {
 
     // Should match above threshold
     let event_above = MidiEvent::NoteOn {
+        channel: 0,
         note: 60,
         velocity: 127,
         time: Instant::now(),
@@ -556,6 +678,7 @@ fn test_note_trigger_velocity_threshold() {
 
     // Should not match below threshold
     let event_below = MidiEvent::NoteOn {
+        channel: 0,
         note: 60,
         velocity: 79,
         time: Instant::now(),
@@ -580,15 +703,24 @@ fn test_cc_trigger_matches_exact_cc() {
                 trigger: Trigger::CC {
                     cc: 1,
                     value_min: Some(0),
+                    value_max: None,
+                    channel: None,
                 },
                 action: ActionConfig::Text {
                     text: "test".to_string(),
                 },
                 description: None,
+                immediate: false,
+                on_release: None,
+                momentary: false,
+                continuous: None,
+                while_held: vec![],
+                concurrency: Default::default(),
             }],
         }],
         global_mappings: vec![],
         advanced_settings: Default::default(),
+        variables: Default::default(),
         logging: None,
     };
 
@@ -596,6 +728,7 @@ fn test_cc_trigger_matches_exact_cc() {
 
     // Should match exact CC
     let event = MidiEvent::ControlChange {
+        channel: 0,
         cc: 1,
         value: 64,
         time: Instant::now(),
@@ -604,6 +737,7 @@ fn test_cc_trigger_matches_exact_cc() {
 
     // Should not match different CC
     let event_diff = MidiEvent::ControlChange {
+        channel: 0,
         cc: 2,
         value: 64,
         time: Instant::now(),
@@ -628,15 +762,24 @@ fn test_cc_trigger_value_threshold() {
                 trigger: Trigger::CC {
                     cc: 1,
                     value_min: Some(64),
+                    value_max: None,
+                    channel: None,
                 },
                 action: ActionConfig::Text {
                     text: "high value".to_string(),
                 },
                 description: None,
+                immediate: false,
+                on_release: None,
+                momentary: false,
+                continuous: None,
+                while_held: vec![],
+                concurrency: Default::default(),
             }],
         }],
         global_mappings: vec![],
         advanced_settings: Default::default(),
+        variables: Default::default(),
         logging: None,
     };
 
@@ -644,6 +787,7 @@ fn test_cc_trigger_value_threshold() {
 
     // Should match at threshold
     let event_threshold = MidiEvent::ControlChange {
+        channel: 0,
         cc: 1,
         value: 64,
         time: Instant::now(),
@@ -652,6 +796,7 @@ fn test_cc_trigger_value_threshold() {
 
     // Should match above threshold
     let event_above = MidiEvent::ControlChange {
+        channel: 0,
         cc: 1,
         value: 127,
         time: Instant::now(),
@@ -660,6 +805,7 @@ fn test_cc_trigger_value_threshold() {
 
     // Should not match below threshold
     let event_below = MidiEvent::ControlChange {
+        channel: 0,
         cc: 1,
         value: 63,
         time: Instant::now(),
@@ -684,15 +830,23 @@ fn test_trigger_does_not_match_wrong_event_type() {
                 trigger: Trigger::Note {
                     note: 60,
                     velocity_min: Some(1),
+                    channel: None,
                 },
                 action: ActionConfig::Text {
                     text: "test".to_string(),
                 },
                 description: None,
+                immediate: false,
+                on_release: None,
+                momentary: false,
+                continuous: None,
+                while_held: vec![],
+                concurrency: Default::default(),
             }],
         }],
         global_mappings: vec![],
         advanced_settings: Default::default(),
+        variables: Default::default(),
         logging: None,
     };
 
@@ -700,6 +854,7 @@ fn test_trigger_does_not_match_wrong_event_type() {
 
     // Note trigger should not match CC event
     let cc_event = MidiEvent::ControlChange {
+        channel: 0,
         cc: 60,
         value: 100,
         time: Instant::now(),
@@ -708,6 +863,7 @@ fn test_trigger_does_not_match_wrong_event_type() {
 
     // Note trigger should not match NoteOff
     let note_off = MidiEvent::NoteOff {
+        channel: 0,
         note: 60,
         time: Instant::now(),
     };
@@ -731,21 +887,30 @@ fn test_trigger_does_not_match_aftertouch() {
                 trigger: Trigger::Note {
                     note: 60,
                     velocity_min: Some(1),
+                    channel: None,
                 },
                 action: ActionConfig::Text {
                     text: "test".to_string(),
                 },
                 description: None,
+                immediate: false,
+                on_release: None,
+                momentary: false,
+                continuous: None,
+                while_held: vec![],
+                concurrency: Default::default(),
             }],
         }],
         global_mappings: vec![],
         advanced_settings: Default::default(),
+        variables: Default::default(),
         logging: None,
     };
 
     engine.load_from_config(&config);
 
     let aftertouch_event = MidiEvent::Aftertouch {
+        channel: 0,
         pressure: 100,
         time: Instant::now(),
     };
@@ -769,21 +934,30 @@ fn test_trigger_does_not_match_pitch_bend() {
                 trigger: Trigger::Note {
                     note: 60,
                     velocity_min: Some(1),
+                    channel: None,
                 },
                 action: ActionConfig::Text {
                     text: "test".to_string(),
                 },
                 description: None,
+                immediate: false,
+                on_release: None,
+                momentary: false,
+                continuous: None,
+                while_held: vec![],
+                concurrency: Default::default(),
             }],
         }],
         global_mappings: vec![],
         advanced_settings: Default::default(),
+        variables: Default::default(),
         logging: None,
     };
 
     engine.load_from_config(&config);
 
     let pitch_bend_event = MidiEvent::PitchBend {
+        channel: 0,
         value: 8192,
         time: Instant::now(),
     };
@@ -812,11 +986,18 @@ fn test_mode_specific_mappings() {
                     trigger: Trigger::Note {
                         note: 60,
                         velocity_min: Some(1),
+                        channel: None,
                     },
                     action: ActionConfig::Text {
                         text: "mode 0".to_string(),
                     },
                     description: None,
+                    immediate: false,
+                    on_release: None,
+                    momentary: false,
+                    continuous: None,
+                    while_held: vec![],
+                    concurrency: Default::default(),
                 }],
             },
             Mode {
@@ -826,22 +1007,31 @@ fn test_mode_specific_mappings() {
                     trigger: Trigger::Note {
                         note: 60,
                         velocity_min: Some(1),
+                        channel: None,
                     },
                     action: ActionConfig::Text {
                         text: "mode 1".to_string(),
                     },
                     description: None,
+                    immediate: false,
+                    on_release: None,
+                    momentary: false,
+                    continuous: None,
+                    while_held: vec![],
+                    concurrency: Default::default(),
                 }],
             },
         ],
         global_mappings: vec![],
         advanced_settings: Default::default(),
+        variables: Default::default(),
         logging: None,
     };
 
     engine.load_from_config(&config);
 
     let event = MidiEvent::NoteOn {
+        channel: 0,
         note: 60,
         velocity: 100,
         time: Instant::now(),
@@ -870,11 +1060,18 @@ fn test_mode_isolation() {
                     trigger: Trigger::Note {
                         note: 60,
                         velocity_min: Some(1),
+                        channel: None,
                     },
                     action: ActionConfig::Text {
                         text: "mode 0".to_string(),
                     },
                     description: None,
+                    immediate: false,
+                    on_release: None,
+                    momentary: false,
+                    continuous: None,
+                    while_held: vec![],
+                    concurrency: Default::default(),
                 }],
             },
             Mode {
@@ -884,27 +1081,37 @@ fn test_mode_isolation() {
                     trigger: Trigger::Note {
                         note: 61,
                         velocity_min: Some(1),
+                        channel: None,
                     },
                     action: ActionConfig::Text {
                         text: "mode 1".to_string(),
                     },
                     description: None,
+                    immediate: false,
+                    on_release: None,
+                    momentary: false,
+                    continuous: None,
+                    while_held: vec![],
+                    concurrency: Default::default(),
                 }],
             },
         ],
         global_mappings: vec![],
         advanced_settings: Default::default(),
+        variables: Default::default(),
         logging: None,
     };
 
     engine.load_from_config(&config);
 
     let event0 = MidiEvent::NoteOn {
+        channel: 0,
         note: 60,
         velocity: 100,
         time: Instant::now(),
     };
     let event1 = MidiEvent::NoteOn {
+        channel: 0,
         note: 61,
         velocity: 100,
         time: Instant::now(),
@@ -938,19 +1145,28 @@ fn test_nonexistent_mode_falls_back_to_global() {
             trigger: Trigger::Note {
                 note: 127,
                 velocity_min: Some(1),
+                channel: None,
             },
             action: ActionConfig::Text {
                 text: "global".to_string(),
             },
             description: None,
+            immediate: false,
+            on_release: None,
+            momentary: false,
+            continuous: None,
+            while_held: vec![],
+            concurrency: Default::default(),
         }],
         advanced_settings: Default::default(),
+        variables: Default::default(),
         logging: None,
     };
 
     engine.load_from_config(&config);
 
     let event = MidiEvent::NoteOn {
+        channel: 0,
         note: 127,
         velocity: 100,
         time: Instant::now(),
@@ -984,11 +1200,18 @@ fn test_global_mappings_work_in_all_modes() {
                         trigger: Trigger::Note {
                             note: 127,
                             velocity_min: Some(1),
+                            channel: None,
                         },
                         action: ActionConfig::Shell {
                             command: "exit".to_string(),
                         },
                         description: None,
+                        immediate: false,
+                        on_release: None,
+                        momentary: false,
+                        continuous: None,
+                        while_held: vec![],
+                        concurrency: Default::default(),
                     },
                 ],
             },
@@ -999,11 +1222,18 @@ fn test_global_mappings_work_in_all_modes() {
                     trigger: Trigger::Note {
                         note: 127,
                         velocity_min: Some(1),
+                        channel: None,
                     },
                     action: ActionConfig::Shell {
                         command: "exit".to_string(),
                     },
                     description: None,
+                    immediate: false,
+                    on_release: None,
+                    momentary: false,
+                    continuous: None,
+                    while_held: vec![],
+                    concurrency: Default::default(),
                 }],
             },
         ],
@@ -1011,19 +1241,28 @@ fn test_global_mappings_work_in_all_modes() {
             trigger: Trigger::Note {
                 note: 127,
                 velocity_min: Some(1),
+                channel: None,
             },
             action: ActionConfig::Shell {
                 command: "exit".to_string(),
             },
             description: None,
+            immediate: false,
+            on_release: None,
+            momentary: false,
+            continuous: None,
+            while_held: vec![],
+            concurrency: Default::default(),
         }],
         advanced_settings: Default::default(),
+        variables: Default::default(),
         logging: None,
     };
 
     engine.load_from_config(&config);
 
     let event = MidiEvent::NoteOn {
+        channel: 0,
         note: 127,
         velocity: 100,
         time: Instant::now(),
@@ -1053,30 +1292,46 @@ fn test_mode_specific_overrides_global() {
                 trigger: Trigger::Note {
                     note: 60,
                     velocity_min: Some(1),
+                    channel: None,
                 },
                 action: ActionConfig::Text {
                     text: "mode specific".to_string(),
                 },
                 description: None,
+                immediate: false,
+                on_release: None,
+                momentary: false,
+                continuous: None,
+                while_held: vec![],
+                concurrency: Default::default(),
             }],
         }],
         global_mappings: vec![Mapping {
             trigger: Trigger::Note {
                 note: 60,
                 velocity_min: Some(1),
+                channel: None,
             },
             action: ActionConfig::Text {
                 text: "global".to_string(),
             },
             description: None,
+            immediate: false,
+            on_release: None,
+            momentary: false,
+            continuous: None,
+            while_held: vec![],
+            concurrency: Default::default(),
         }],
         advanced_settings: Default::default(),
+        variables: Default::default(),
         logging: None,
     };
 
     engine.load_from_config(&config);
 
     let event = MidiEvent::NoteOn {
+        channel: 0,
         note: 60,
         velocity: 100,
         time: Instant::now(),
@@ -1106,21 +1361,35 @@ fn test_multiple_global_mappings() {
                     trigger: Trigger::Note {
                         note: 126,
                         velocity_min: Some(1),
+                        channel: None,
                     },
                     action: ActionConfig::Text {
                         text: "global1".to_string(),
                     },
                     description: None,
+                    immediate: false,
+                    on_release: None,
+                    momentary: false,
+                    continuous: None,
+                    while_held: vec![],
+                    concurrency: Default::default(),
                 },
                 Mapping {
                     trigger: Trigger::Note {
                         note: 127,
                         velocity_min: Some(1),
+                        channel: None,
                     },
                     action: ActionConfig::Text {
                         text: "global2".to_string(),
                     },
                     description: None,
+                    immediate: false,
+                    on_release: None,
+                    momentary: false,
+                    continuous: None,
+                    while_held: vec![],
+                    concurrency: Default::default(),
                 },
             ],
         }],
@@ -1129,35 +1398,52 @@ fn test_multiple_global_mappings() {
                 trigger: Trigger::Note {
                     note: 126,
                     velocity_min: Some(1),
+                    channel: None,
                 },
                 action: ActionConfig::Text {
                     text: "global1".to_string(),
                 },
                 description: None,
+                immediate: false,
+                on_release: None,
+                momentary: false,
+                continuous: None,
+                while_held: vec![],
+                concurrency: Default::default(),
             },
             Mapping {
                 trigger: Trigger::Note {
                     note: 127,
                     velocity_min: Some(1),
+                    channel: None,
                 },
                 action: ActionConfig::Text {
                     text: "global2".to_string(),
                 },
                 description: None,
+                immediate: false,
+                on_release: None,
+                momentary: false,
+                continuous: None,
+                while_held: vec![],
+                concurrency: Default::default(),
             },
         ],
         advanced_settings: Default::default(),
+        variables: Default::default(),
         logging: None,
     };
 
     engine.load_from_config(&config);
 
     let event1 = MidiEvent::NoteOn {
+        channel: 0,
         note: 126,
         velocity: 100,
         time: Instant::now(),
     };
     let event2 = MidiEvent::NoteOn {
+        channel: 0,
         note: 127,
         velocity: 100,
         time: Instant::now(),
@@ -1189,22 +1475,31 @@ fn test_compile_keystroke_action() {
                 trigger: Trigger::Note {
                     note: 60,
                     velocity_min: Some(1),
+                    channel: None,
                 },
                 action: ActionConfig::Keystroke {
                     keys: "a".to_string(),
                     modifiers: vec!["cmd".to_string()],
                 },
                 description: None,
+                immediate: false,
+                on_release: None,
+                momentary: false,
+                continuous: None,
+                while_held: vec![],
+                concurrency: Default::default(),
             }],
         }],
         global_mappings: vec![],
         advanced_settings: Default::default(),
+        variables: Default::default(),
         logging: None,
     };
 
     engine.load_from_config(&config);
 
     let event = MidiEvent::NoteOn {
+        channel: 0,
         note: 60,
         velocity: 100,
         time: Instant::now(),
@@ -1231,21 +1526,30 @@ fn test_compile_text_action() {
                 trigger: Trigger::Note {
                     note: 60,
                     velocity_min: Some(1),
+                    channel: None,
                 },
                 action: ActionConfig::Text {
                     text: "Hello World".to_string(),
                 },
                 description: None,
+                immediate: false,
+                on_release: None,
+                momentary: false,
+                continuous: None,
+                while_held: vec![],
+                concurrency: Default::default(),
             }],
         }],
         global_mappings: vec![],
         advanced_settings: Default::default(),
+        variables: Default::default(),
         logging: None,
     };
 
     engine.load_from_config(&config);
 
     let event = MidiEvent::NoteOn {
+        channel: 0,
         note: 60,
         velocity: 100,
         time: Instant::now(),
@@ -1272,21 +1576,30 @@ fn test_compile_launch_action() {
                 trigger: Trigger::Note {
                     note: 60,
                     velocity_min: Some(1),
+                    channel: None,
                 },
                 action: ActionConfig::Launch {
                     app: "Terminal".to_string(),
                 },
                 description: None,
+                immediate: false,
+                on_release: None,
+                momentary: false,
+                continuous: None,
+                while_held: vec![],
+                concurrency: Default::default(),
             }],
         }],
         global_mappings: vec![],
         advanced_settings: Default::default(),
+        variables: Default::default(),
         logging: None,
     };
 
     engine.load_from_config(&config);
 
     let event = MidiEvent::NoteOn {
+        channel: 0,
         note: 60,
         velocity: 100,
         time: Instant::now(),
@@ -1313,21 +1626,30 @@ fn test_compile_shell_action() {
                 trigger: Trigger::Note {
                     note: 60,
                     velocity_min: Some(1),
+                    channel: None,
                 },
                 action: ActionConfig::Shell {
                     command: "echo test".to_string(),
                 },
                 description: None,
+                immediate: false,
+                on_release: None,
+                momentary: false,
+                continuous: None,
+                while_held: vec![],
+                concurrency: Default::default(),
             }],
         }],
         global_mappings: vec![],
         advanced_settings: Default::default(),
+        variables: Default::default(),
         logging: None,
     };
 
     engine.load_from_config(&config);
 
     let event = MidiEvent::NoteOn {
+        channel: 0,
         note: 60,
         velocity: 100,
         time: Instant::now(),
@@ -1354,6 +1676,7 @@ fn test_compile_sequence_action() {
                 trigger: Trigger::Note {
                     note: 60,
                     velocity_min: Some(1),
+                    channel: None,
                 },
                 action: ActionConfig::Sequence {
                     actions: vec![
@@ -1367,16 +1690,24 @@ fn test_compile_sequence_action() {
                     ],
                 },
                 description: None,
+                immediate: false,
+                on_release: None,
+                momentary: false,
+                continuous: None,
+                while_held: vec![],
+                concurrency: Default::default(),
             }],
         }],
         global_mappings: vec![],
         advanced_settings: Default::default(),
+        variables: Default::default(),
         logging: None,
     };
 
     engine.load_from_config(&config);
 
     let event = MidiEvent::NoteOn {
+        channel: 0,
         note: 60,
         velocity: 100,
         time: Instant::now(),
@@ -1403,19 +1734,28 @@ fn test_compile_delay_action() {
                 trigger: Trigger::Note {
                     note: 60,
                     velocity_min: Some(1),
+                    channel: None,
                 },
                 action: ActionConfig::Delay { ms: 500 },
                 description: None,
+                immediate: false,
+                on_release: None,
+                momentary: false,
+                continuous: None,
+                while_held: vec![],
+                concurrency: Default::default(),
             }],
         }],
         global_mappings: vec![],
         advanced_settings: Default::default(),
+        variables: Default::default(),
         logging: None,
     };
 
     engine.load_from_config(&config);
 
     let event = MidiEvent::NoteOn {
+        channel: 0,
         note: 60,
         velocity: 100,
         time: Instant::now(),
@@ -1442,6 +1782,7 @@ fn test_compile_mouse_click_action() {
                 trigger: Trigger::Note {
                     note: 60,
                     velocity_min: Some(1),
+                    channel: None,
                 },
                 action: ActionConfig::MouseClick {
                     button: "left".to_string(),
@@ -1449,16 +1790,24 @@ fn test_compile_mouse_click_action() {
                     y: Some(200),
                 },
                 description: None,
+                immediate: false,
+                on_release: None,
+                momentary: false,
+                continuous: None,
+                while_held: vec![],
+                concurrency: Default::default(),
             }],
         }],
         global_mappings: vec![],
         advanced_settings: Default::default(),
+        variables: Default::default(),
         logging: None,
     };
 
     engine.load_from_config(&config);
 
     let event = MidiEvent::NoteOn {
+        channel: 0,
         note: 60,
         velocity: 100,
         time: Instant::now(),
@@ -1490,32 +1839,48 @@ fn test_first_matching_trigger_wins() {
                     trigger: Trigger::Note {
                         note: 60,
                         velocity_min: Some(1),
+                        channel: None,
                     },
                     action: ActionConfig::Text {
                         text: "first".to_string(),
                     },
                     description: Some("First mapping".to_string()),
+                    immediate: false,
+                    on_release: None,
+                    momentary: false,
+                    continuous: None,
+                    while_held: vec![],
+                    concurrency: Default::default(),
                 },
                 Mapping {
                     trigger: Trigger::Note {
                         note: 60,
                         velocity_min: Some(1),
+                        channel: None,
                     },
                     action: ActionConfig::Text {
                         text: "second".to_string(),
                     },
                     description: Some("Second mapping".to_string()),
+                    immediate: false,
+                    on_release: None,
+                    momentary: false,
+                    continuous: None,
+                    while_held: vec![],
+                    concurrency: Default::default(),
                 },
             ],
         }],
         global_mappings: vec![],
         advanced_settings: Default::default(),
+        variables: Default::default(),
         logging: None,
     };
 
     engine.load_from_config(&config);
 
     let event = MidiEvent::NoteOn {
+        channel: 0,
         note: 60,
         velocity: 100,
         time: Instant::now(),
@@ -1543,15 +1908,23 @@ fn test_no_mapping_returns_none() {
                 trigger: Trigger::Note {
                     note: 60,
                     velocity_min: Some(1),
+                    channel: None,
                 },
                 action: ActionConfig::Text {
                     text: "test".to_string(),
                 },
                 description: None,
+                immediate: false,
+                on_release: None,
+                momentary: false,
+                continuous: None,
+                while_held: vec![],
+                concurrency: Default::default(),
             }],
         }],
         global_mappings: vec![],
         advanced_settings: Default::default(),
+        variables: Default::default(),
         logging: None,
     };
 
@@ -1559,6 +1932,7 @@ fn test_no_mapping_returns_none() {
 
     // Event for unmapped note
     let event = MidiEvent::NoteOn {
+        channel: 0,
         note: 99,
         velocity: 100,
         time: Instant::now(),
@@ -1584,12 +1958,14 @@ fn test_mode_without_mappings_returns_none() {
         }],
         global_mappings: vec![],
         advanced_settings: Default::default(),
+        variables: Default::default(),
         logging: None,
     };
 
     engine.load_from_config(&config);
 
     let event = MidiEvent::NoteOn {
+        channel: 0,
         note: 60,
         velocity: 100,
         time: Instant::now(),
@@ -1619,21 +1995,30 @@ fn test_note_zero_is_valid() {
                 trigger: Trigger::Note {
                     note: 0,
                     velocity_min: Some(1),
+                    channel: None,
                 },
                 action: ActionConfig::Text {
                     text: "note zero".to_string(),
                 },
                 description: None,
+                immediate: false,
+                on_release: None,
+                momentary: false,
+                continuous: None,
+                while_held: vec![],
+                concurrency: Default::default(),
             }],
         }],
         global_mappings: vec![],
         advanced_settings: Default::default(),
+        variables: Default::default(),
         logging: None,
     };
 
     engine.load_from_config(&config);
 
     let event = MidiEvent::NoteOn {
+        channel: 0,
         note: 0,
         velocity: 100,
         time: Instant::now(),
@@ -1659,21 +2044,30 @@ fn test_note_127_is_valid() {
                 trigger: Trigger::Note {
                     note: 127,
                     velocity_min: Some(1),
+                    channel: None,
                 },
                 action: ActionConfig::Text {
                     text: "note 127".to_string(),
                 },
                 description: None,
+                immediate: false,
+                on_release: None,
+                momentary: false,
+                continuous: None,
+                while_held: vec![],
+                concurrency: Default::default(),
             }],
         }],
         global_mappings: vec![],
         advanced_settings: Default::default(),
+        variables: Default::default(),
         logging: None,
     };
 
     engine.load_from_config(&config);
 
     let event = MidiEvent::NoteOn {
+        channel: 0,
         note: 127,
         velocity: 100,
         time: Instant::now(),
@@ -1698,16 +2092,24 @@ fn test_velocity_zero_does_not_match_default() {
             mappings: vec![Mapping {
                 trigger: Trigger::Note {
                     note: 60,
-                    velocity_min: None, // defaults to 1
+                    velocity_min: None, // defaults to 1,
+                    channel: None,
                 },
                 action: ActionConfig::Text {
                     text: "test".to_string(),
                 },
                 description: None,
+                immediate: false,
+                on_release: None,
+                momentary: false,
+                continuous: None,
+                while_held: vec![],
+                concurrency: Default::default(),
             }],
         }],
         global_mappings: vec![],
         advanced_settings: Default::default(),
+        variables: Default::default(),
         logging: None,
     };
 
@@ -1715,6 +2117,7 @@ fn test_velocity_zero_does_not_match_default() {
 
     // Velocity 0 should not match (default min is 1)
     let event = MidiEvent::NoteOn {
+        channel: 0,
         note: 60,
         velocity: 0,
         time: Instant::now(),
@@ -1739,16 +2142,24 @@ fn test_velocity_one_matches_default() {
             mappings: vec![Mapping {
                 trigger: Trigger::Note {
                     note: 60,
-                    velocity_min: None, // defaults to 1
+                    velocity_min: None, // defaults to 1,
+                    channel: None,
                 },
                 action: ActionConfig::Text {
                     text: "test".to_string(),
                 },
                 description: None,
+                immediate: false,
+                on_release: None,
+                momentary: false,
+                continuous: None,
+                while_held: vec![],
+                concurrency: Default::default(),
             }],
         }],
         global_mappings: vec![],
         advanced_settings: Default::default(),
+        variables: Default::default(),
         logging: None,
     };
 
@@ -1756,6 +2167,7 @@ fn test_velocity_one_matches_default() {
 
     // Velocity 1 should match (default min is 1)
     let event = MidiEvent::NoteOn {
+        channel: 0,
         note: 60,
         velocity: 1,
         time: Instant::now(),
@@ -1781,21 +2193,31 @@ fn test_cc_zero_is_valid() {
                 trigger: Trigger::CC {
                     cc: 0,
                     value_min: None,
+                    value_max: None,
+                    channel: None,
                 },
                 action: ActionConfig::Text {
                     text: "cc zero".to_string(),
                 },
                 description: None,
+                immediate: false,
+                on_release: None,
+                momentary: false,
+                continuous: None,
+                while_held: vec![],
+                concurrency: Default::default(),
             }],
         }],
         global_mappings: vec![],
         advanced_settings: Default::default(),
+        variables: Default::default(),
         logging: None,
     };
 
     engine.load_from_config(&config);
 
     let event = MidiEvent::ControlChange {
+        channel: 0,
         cc: 0,
         value: 64,
         time: Instant::now(),
@@ -1821,21 +2243,31 @@ fn test_cc_127_is_valid() {
                 trigger: Trigger::CC {
                     cc: 127,
                     value_min: None,
+                    value_max: None,
+                    channel: None,
                 },
                 action: ActionConfig::Text {
                     text: "cc 127".to_string(),
                 },
                 description: None,
+                immediate: false,
+                on_release: None,
+                momentary: false,
+                continuous: None,
+                while_held: vec![],
+                concurrency: Default::default(),
             }],
         }],
         global_mappings: vec![],
         advanced_settings: Default::default(),
+        variables: Default::default(),
         logging: None,
     };
 
     engine.load_from_config(&config);
 
     let event = MidiEvent::ControlChange {
+        channel: 0,
         cc: 127,
         value: 64,
         time: Instant::now(),
@@ -1861,21 +2293,30 @@ fn test_description_is_optional() {
                 trigger: Trigger::Note {
                     note: 60,
                     velocity_min: Some(1),
+                    channel: None,
                 },
                 action: ActionConfig::Text {
                     text: "test".to_string(),
                 },
                 description: None,
+                immediate: false,
+                on_release: None,
+                momentary: false,
+                continuous: None,
+                while_held: vec![],
+                concurrency: Default::default(),
             }],
         }],
         global_mappings: vec![],
         advanced_settings: Default::default(),
+        variables: Default::default(),
         logging: None,
     };
 
     engine.load_from_config(&config);
 
     let event = MidiEvent::NoteOn {
+        channel: 0,
         note: 60,
         velocity: 100,
         time: Instant::now(),
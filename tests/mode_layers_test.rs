@@ -0,0 +1,164 @@
+// Copyright 2025 Amiable
+// SPDX-License-Identifier: MIT
+
+//! Integration tests for momentary mode layers
+//!
+//! Tests that layered modes are checked from the top of the stack down, fall
+//! through to the base mode and global mappings, and can be released in any
+//! order.
+
+use conductor_core::event_processor::{ProcessedEvent, VelocityLevel};
+use conductor_core::{Action, Config, MappingEngine, MidiEvent};
+use std::time::Instant;
+
+const LAYERED_CONFIG: &str = r#"
+    [device]
+    name = "Test Device"
+    auto_connect = false
+
+    [[modes]]
+    name = "Base"
+
+    [[modes.mappings]]
+    trigger = { type = "Note", note = 36 }
+    action = { type = "Text", text = "base-36" }
+
+    [[modes.mappings]]
+    trigger = { type = "Note", note = 37 }
+    action = { type = "Text", text = "base-37" }
+
+    [[modes.mappings]]
+    trigger = { type = "Note", note = 48 }
+    action = { type = "ModeChange", mode = "Shift" }
+    momentary = true
+
+    [[modes]]
+    name = "Shift"
+
+    [[modes.mappings]]
+    trigger = { type = "Note", note = 36 }
+    action = { type = "Text", text = "shift-36" }
+
+    [[modes]]
+    name = "Fn"
+
+    [[modes.mappings]]
+    trigger = { type = "Note", note = 37 }
+    action = { type = "Text", text = "fn-37" }
+
+    [[global_mappings]]
+    trigger = { type = "Note", note = 40 }
+    action = { type = "Text", text = "global-40" }
+"#;
+
+fn setup() -> MappingEngine {
+    let config: Config = toml::from_str(LAYERED_CONFIG).expect("Failed to parse config");
+    config.validate().expect("Config should be valid");
+
+    let mut engine = MappingEngine::new();
+    engine.load_from_config(&config);
+    engine
+}
+
+/// Text of the action mapped to a pad press in base mode 0
+fn text_for(engine: &MappingEngine, note: u8) -> Option<String> {
+    let event = ProcessedEvent::PadPressed {
+        channel: 0,
+        note,
+        velocity: 100,
+        velocity_level: VelocityLevel::Medium,
+    };
+    match engine.get_action_for_processed(&event, 0) {
+        Some(Action::Text(text)) => Some(text),
+        _ => None,
+    }
+}
+
+#[test]
+fn test_layer_overrides_base_mode() {
+    let mut engine = setup();
+    assert_eq!(text_for(&engine, 36).as_deref(), Some("base-36"));
+
+    engine.push_layer(1);
+    assert_eq!(text_for(&engine, 36).as_deref(), Some("shift-36"));
+    assert_eq!(engine.effective_mode(0), 1);
+
+    assert!(engine.pop_layer(1));
+    assert_eq!(text_for(&engine, 36).as_deref(), Some("base-36"));
+    assert_eq!(engine.effective_mode(0), 0);
+}
+
+#[test]
+fn test_layer_falls_through_to_base_and_global() {
+    let mut engine = setup();
+    engine.push_layer(1);
+
+    assert_eq!(text_for(&engine, 37).as_deref(), Some("base-37"));
+    assert_eq!(text_for(&engine, 40).as_deref(), Some("global-40"));
+    assert_eq!(text_for(&engine, 41), None);
+}
+
+#[test]
+fn test_layers_stack_and_release_in_any_order() {
+    let mut engine = setup();
+    engine.push_layer(1);
+    engine.push_layer(2);
+    assert_eq!(engine.layers(), &[1, 2]);
+
+    // Top layer first, then the layer below it
+    assert_eq!(text_for(&engine, 37).as_deref(), Some("fn-37"));
+    assert_eq!(text_for(&engine, 36).as_deref(), Some("shift-36"));
+
+    // Releasing the lower layer keeps the top one active
+    assert!(engine.pop_layer(1));
+    assert_eq!(engine.layers(), &[2]);
+    assert_eq!(text_for(&engine, 36).as_deref(), Some("base-36"));
+
+    assert!(!engine.pop_layer(1), "Layer is no longer active");
+    engine.clear_layers();
+    assert!(engine.layers().is_empty());
+}
+
+#[test]
+fn test_layers_apply_to_raw_midi_lookup() {
+    let mut engine = setup();
+    engine.push_layer(1);
+
+    let event = MidiEvent::NoteOn {
+        channel: 0,
+        note: 36,
+        velocity: 100,
+        time: Instant::now(),
+    };
+    assert!(matches!(
+        engine.get_action(&event, 0),
+        Some(Action::Text(ref text)) if text == "shift-36"
+    ));
+}
+
+#[test]
+fn test_momentary_mode_change_is_mapped() {
+    let engine = setup();
+    let event = ProcessedEvent::PadPressed {
+        channel: 0,
+        note: 48,
+        velocity: 100,
+        velocity_level: VelocityLevel::Medium,
+    };
+
+    let mapped = engine
+        .get_mapped_action_for_processed(&event, 0)
+        .expect("Pad should be mapped");
+    assert!(mapped.momentary);
+    assert!(matches!(mapped.action, Action::ModeChange { ref mode } if mode == "Shift"));
+}
+
+#[test]
+fn test_reload_clears_layers() {
+    let mut engine = setup();
+    engine.push_layer(1);
+
+    let config: Config = toml::from_str(LAYERED_CONFIG).expect("Failed to parse config");
+    engine.load_from_config(&config);
+    assert!(engine.layers().is_empty());
+}
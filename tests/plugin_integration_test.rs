@@ -216,6 +216,7 @@ fn test_plugin_execution() {
             .duration_since(std::time::UNIX_EPOCH)
             .unwrap()
             .as_millis() as u64,
+        variables: Default::default(),
     };
 
     // Execute plugin action
@@ -292,6 +293,7 @@ fn test_plugin_enable_disable() {
             .duration_since(std::time::UNIX_EPOCH)
             .unwrap()
             .as_millis() as u64,
+        variables: Default::default(),
     };
 
     let result = manager.execute_plugin("spotify", action_data, Some(context));
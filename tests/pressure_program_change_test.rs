@@ -0,0 +1,186 @@
+// Copyright 2025 Amiable
+// SPDX-License-Identifier: MIT
+
+//! Integration tests for polyphonic pressure and program change input
+//!
+//! Tests that the event processor reports per-pad pressure with its previous
+//! value and program changes, that `PolyPressure` triggers fire once per
+//! upward threshold crossing, and that `ProgramChange` triggers match.
+
+mod common;
+
+use common::engine_with_mappings;
+use conductor_core::event_processor::{MidiEvent, ProcessedEvent};
+use conductor_core::{Action, Config, EventProcessor, MappingEngine, VolumeOperation};
+use std::time::Instant;
+
+fn pressure(processor: &mut EventProcessor, note: u8, pressure: u8) -> Vec<ProcessedEvent> {
+    processor.process(MidiEvent::PolyPressure {
+        channel: 0,
+        note,
+        pressure,
+        time: Instant::now(),
+    })
+}
+
+/// Feed pressure values for one pad, counting the actions they fire
+fn fired_count(engine: &MappingEngine, processor: &mut EventProcessor, values: &[u8]) -> usize {
+    values
+        .iter()
+        .flat_map(|value| pressure(processor, 36, *value))
+        .filter(|event| engine.get_action_for_processed(event, 0).is_some())
+        .count()
+}
+
+const SECOND_LAYER: &str = r#"
+    [[modes.mappings]]
+    trigger = { type = "PolyPressure", note = 36, pressure_min = 100 }
+    action = { type = "Text", text = "pressed hard" }
+"#;
+
+#[test]
+fn test_poly_pressure_reports_previous_value() {
+    let mut processor = EventProcessor::new();
+
+    let pressures: Vec<(u8, u8)> = [40, 90, 60]
+        .into_iter()
+        .flat_map(|value| pressure(&mut processor, 36, value))
+        .filter_map(|event| match event {
+            ProcessedEvent::PolyPressureChanged {
+                note: 36,
+                pressure,
+                previous,
+                ..
+            } => Some((previous, pressure)),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(pressures, vec![(0, 40), (40, 90), (90, 60)]);
+
+    // Pads are tracked separately
+    assert!(matches!(
+        pressure(&mut processor, 37, 10).as_slice(),
+        [ProcessedEvent::PolyPressureChanged { previous: 0, .. }]
+    ));
+}
+
+#[test]
+fn test_threshold_fires_once_per_crossing() {
+    let engine = engine_with_mappings(SECOND_LAYER);
+    let mut processor = EventProcessor::new();
+
+    // Rising through 100 fires once, staying above it doesn't repeat
+    assert_eq!(
+        fired_count(&engine, &mut processor, &[50, 99, 100, 120, 127]),
+        1
+    );
+
+    // Easing off and pressing hard again fires again
+    assert_eq!(fired_count(&engine, &mut processor, &[80, 110]), 1);
+
+    // Another pad doesn't match
+    let events = pressure(&mut processor, 37, 127);
+    assert!(engine.get_action_for_processed(&events[0], 0).is_none());
+}
+
+#[test]
+fn test_release_rearms_threshold() {
+    let engine = engine_with_mappings(SECOND_LAYER);
+    let mut processor = EventProcessor::new();
+    let now = Instant::now();
+
+    assert_eq!(fired_count(&engine, &mut processor, &[127]), 1);
+
+    processor.process(MidiEvent::NoteOn {
+        channel: 0,
+        note: 36,
+        velocity: 100,
+        time: now,
+    });
+    processor.process(MidiEvent::NoteOff {
+        channel: 0,
+        note: 36,
+        time: now,
+    });
+
+    // Controllers may not send zero pressure before the note off
+    assert_eq!(fired_count(&engine, &mut processor, &[127]), 1);
+}
+
+#[test]
+fn test_pressure_without_threshold_drives_continuous_value() {
+    let engine = engine_with_mappings(
+        r#"
+        [[modes.mappings]]
+        trigger = { type = "PolyPressure", note = 36 }
+        action = { type = "VolumeControl", operation = "Set" }
+        continuous = { min = 0, max = 100 }
+    "#,
+    );
+    let mut processor = EventProcessor::new();
+
+    let volumes: Vec<Option<u8>> = [0, 127, 0]
+        .into_iter()
+        .flat_map(|value| pressure(&mut processor, 36, value))
+        .map(|event| match engine.get_action_for_processed(&event, 0) {
+            Some(Action::VolumeControl {
+                operation: VolumeOperation::Set,
+                value,
+            }) => value,
+            _ => None,
+        })
+        .collect();
+    assert_eq!(volumes, vec![Some(0), Some(100), Some(0)]);
+}
+
+#[test]
+fn test_program_change_trigger() {
+    let engine = engine_with_mappings(
+        r#"
+        [[modes.mappings]]
+        trigger = { type = "ProgramChange", program = 5, channel = 1 }
+        action = { type = "Text", text = "scene 5" }
+    "#,
+    );
+    let mut processor = EventProcessor::new();
+
+    let mut fired = |channel: u8, program: u8| {
+        processor
+            .process(MidiEvent::ProgramChange {
+                channel,
+                program,
+                time: Instant::now(),
+            })
+            .iter()
+            .filter_map(|event| match engine.get_action_for_processed(event, 0) {
+                Some(Action::Text(text)) => Some(text),
+                _ => None,
+            })
+            .next()
+    };
+
+    assert_eq!(fired(1, 5), Some("scene 5".to_string()));
+    assert_eq!(fired(1, 6), None);
+    assert_eq!(fired(0, 5), None, "Other channel");
+}
+
+#[test]
+fn test_program_out_of_range_rejected() {
+    let config: Config = toml::from_str(
+        r#"
+        [device]
+        name = "Test Device"
+        auto_connect = false
+
+        [[modes]]
+        name = "Test Mode"
+
+        [[modes.mappings]]
+        trigger = { type = "ProgramChange", program = 128 }
+        action = { type = "Text", text = "x" }
+    "#,
+    )
+    .expect("Failed to parse config");
+
+    assert!(config.validate().is_err());
+}
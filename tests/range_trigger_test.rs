@@ -0,0 +1,173 @@
+// Copyright 2025 Amiable
+// SPDX-License-Identifier: MIT
+
+//! Integration tests for NoteRange and CCRange triggers
+//!
+//! Tests that one ranged mapping matches every note or CC in its range and
+//! reports the matched control, its offset within the range and its velocity
+//! or value.
+
+mod common;
+
+use common::engine_with_mappings;
+use conductor_core::event_processor::{EncoderDirection, ProcessedEvent, VelocityLevel};
+use conductor_core::{Action, MatchedControl};
+
+fn pad_pressed(note: u8, velocity: u8) -> ProcessedEvent {
+    ProcessedEvent::PadPressed {
+        channel: 0,
+        note,
+        velocity,
+        velocity_level: VelocityLevel::Medium,
+    }
+}
+
+fn encoder_turned(cc: u8, value: u8) -> ProcessedEvent {
+    ProcessedEvent::EncoderTurned {
+        channel: 0,
+        cc,
+        value,
+        direction: EncoderDirection::Clockwise,
+        delta: 1,
+    }
+}
+
+#[test]
+fn test_note_range_matches_every_note_in_range() {
+    let engine = engine_with_mappings(
+        r#"
+        [[modes.mappings]]
+        trigger = { type = "NoteRange", min = 36, max = 51 }
+        action = { type = "Text", text = "pad {offset}" }
+    "#,
+    );
+
+    for note in 36..=51 {
+        assert!(
+            engine
+                .get_action_for_processed(&pad_pressed(note, 100), 0)
+                .is_some()
+        );
+    }
+    assert!(
+        engine
+            .get_action_for_processed(&pad_pressed(35, 100), 0)
+            .is_none()
+    );
+    assert!(
+        engine
+            .get_action_for_processed(&pad_pressed(52, 100), 0)
+            .is_none()
+    );
+}
+
+#[test]
+fn test_note_range_reports_matched_control() {
+    let engine = engine_with_mappings(
+        r#"
+        [[modes.mappings]]
+        trigger = { type = "NoteRange", min = 36, max = 51 }
+        action = { type = "Text", text = "pad {offset}" }
+    "#,
+    );
+
+    let mapped = engine
+        .get_mapped_action_for_processed(&pad_pressed(40, 90), 0)
+        .expect("Note should be in range");
+
+    assert_eq!(
+        mapped.control,
+        Some(MatchedControl {
+            number: 40,
+            offset: 4,
+            value: 90,
+        })
+    );
+    assert!(matches!(mapped.action, Action::Text(ref text) if text == "pad {offset}"));
+}
+
+#[test]
+fn test_note_range_velocity_min() {
+    let engine = engine_with_mappings(
+        r#"
+        [[modes.mappings]]
+        trigger = { type = "NoteRange", min = 36, max = 51, velocity_min = 80 }
+        action = { type = "Text", text = "hard" }
+    "#,
+    );
+
+    assert!(
+        engine
+            .get_action_for_processed(&pad_pressed(40, 60), 0)
+            .is_none()
+    );
+    assert!(
+        engine
+            .get_action_for_processed(&pad_pressed(40, 100), 0)
+            .is_some()
+    );
+}
+
+#[test]
+fn test_cc_range_reports_matched_control() {
+    let engine = engine_with_mappings(
+        r#"
+        [[modes.mappings]]
+        trigger = { type = "CCRange", min = 20, max = 27 }
+        action = { type = "Text", text = "knob {offset}" }
+    "#,
+    );
+
+    let mapped = engine
+        .get_mapped_action_for_processed(&encoder_turned(23, 64), 0)
+        .expect("CC should be in range");
+    assert_eq!(
+        mapped.control,
+        Some(MatchedControl {
+            number: 23,
+            offset: 3,
+            value: 64,
+        })
+    );
+
+    assert!(
+        engine
+            .get_action_for_processed(&encoder_turned(28, 64), 0)
+            .is_none()
+    );
+}
+
+#[test]
+fn test_single_note_trigger_has_zero_offset() {
+    let engine = engine_with_mappings(
+        r#"
+        [[modes.mappings]]
+        trigger = { type = "Note", note = 36 }
+        action = { type = "Text", text = "pad" }
+    "#,
+    );
+
+    let mapped = engine
+        .get_mapped_action_for_processed(&pad_pressed(36, 100), 0)
+        .expect("Note should be mapped");
+    assert_eq!(mapped.control.map(|c| (c.number, c.offset)), Some((36, 0)));
+}
+
+#[test]
+fn test_relative_send_midi_from_config() {
+    let engine = engine_with_mappings(
+        r#"
+        [[modes.mappings]]
+        trigger = { type = "NoteRange", min = 36, max = 51 }
+        action = { type = "SendMidi", port = "Out", message_type = "NoteOn", channel = 0, note = 60, relative = true }
+    "#,
+    );
+
+    let mapped = engine
+        .get_mapped_action_for_processed(&pad_pressed(38, 100), 0)
+        .expect("Note should be in range");
+    assert!(matches!(
+        mapped.action,
+        Action::SendMidi { relative: true, .. }
+    ));
+}
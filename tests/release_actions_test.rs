@@ -0,0 +1,119 @@
+// Copyright 2025 Amiable
+// SPDX-License-Identifier: MIT
+
+//! Integration tests for release-phase actions and momentary mappings
+//!
+//! Tests that `on_release` and `momentary` are carried from the config through
+//! to the mapped action, and that a deferred tap still reports its release
+//! after the press so the release-phase action can pair with it.
+
+mod common;
+
+use common::config_with_mappings;
+use conductor_core::event_processor::ProcessedEvent;
+use conductor_core::{Action, Config, EventProcessor, MappingEngine, MidiEvent};
+use std::time::{Duration, Instant};
+
+fn engine_for(config: &Config) -> MappingEngine {
+    let mut engine = MappingEngine::new();
+    engine.load_from_config(config);
+    engine
+}
+
+fn pad_pressed(note: u8) -> ProcessedEvent {
+    ProcessedEvent::PadPressed {
+        channel: 0,
+        note,
+        velocity: 100,
+        velocity_level: conductor_core::event_processor::VelocityLevel::Medium,
+    }
+}
+
+#[test]
+fn test_on_release_action_is_mapped() {
+    let config = config_with_mappings(
+        r#"
+        [[modes.mappings]]
+        trigger = { type = "Note", note = 36 }
+        action = { type = "Text", text = "down" }
+        on_release = { type = "Text", text = "up" }
+    "#,
+    );
+    let engine = engine_for(&config);
+
+    let mapped = engine
+        .get_mapped_action_for_processed(&pad_pressed(36), 0)
+        .expect("Pad should be mapped");
+
+    assert!(matches!(mapped.action, Action::Text(ref text) if text == "down"));
+    assert!(matches!(mapped.on_release, Some(Action::Text(ref text)) if text == "up"));
+    assert!(!mapped.momentary);
+}
+
+#[test]
+fn test_momentary_keystroke_is_mapped() {
+    let config = config_with_mappings(
+        r#"
+        [[modes.mappings]]
+        trigger = { type = "Note", note = 36 }
+        action = { type = "Keystroke", keys = "shift", modifiers = [] }
+        momentary = true
+    "#,
+    );
+    let engine = engine_for(&config);
+
+    let mapped = engine
+        .get_mapped_action_for_processed(&pad_pressed(36), 0)
+        .expect("Pad should be mapped");
+
+    assert!(mapped.momentary);
+    assert!(matches!(mapped.action, Action::Keystroke { .. }));
+    assert!(mapped.on_release.is_none());
+}
+
+#[test]
+fn test_deferred_tap_reports_release_after_press() {
+    let config = config_with_mappings(
+        r#"
+        [[modes.mappings]]
+        trigger = { type = "Note", note = 36 }
+        action = { type = "Text", text = "tap" }
+        on_release = { type = "Text", text = "untap" }
+
+        [[modes.mappings]]
+        trigger = { type = "DoubleTap", note = 36 }
+        action = { type = "Text", text = "double" }
+    "#,
+    );
+    let mut processor = EventProcessor::from_config(&config);
+    let now = Instant::now();
+
+    processor.process(MidiEvent::NoteOn {
+        channel: 0,
+        note: 36,
+        velocity: 100,
+        time: now,
+    });
+    let released = processor.process(MidiEvent::NoteOff {
+        channel: 0,
+        note: 36,
+        time: now + Duration::from_millis(50),
+    });
+    assert!(
+        !released
+            .iter()
+            .any(|e| matches!(e, ProcessedEvent::PadReleased { .. })),
+        "Release of a pending press is held back with it"
+    );
+
+    let resolved = processor.tick(now + Duration::from_millis(1000));
+    let pressed = resolved
+        .iter()
+        .position(|e| matches!(e, ProcessedEvent::PadPressed { note: 36, .. }));
+    let released = resolved
+        .iter()
+        .position(|e| matches!(e, ProcessedEvent::PadReleased { note: 36, .. }));
+
+    assert!(pressed.is_some() && released.is_some());
+    assert!(pressed < released, "Press is reported before its release");
+}
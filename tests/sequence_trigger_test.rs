@@ -0,0 +1,180 @@
+// Copyright 2025 Amiable
+// SPDX-License-Identifier: MIT
+
+//! Integration tests for ordered sequence triggers
+//!
+//! Tests that `Sequence` triggers match steps pressed in order within
+//! `max_gap_ms`, and that `suppress_steps` holds back the steps' own mappings
+//! until the sequence completes or is broken.
+
+mod common;
+
+use common::{Rig, texts};
+use conductor_core::MidiEvent;
+use conductor_core::event_processor::ProcessedEvent;
+use std::time::{Duration, Instant};
+
+fn combo(suppress_steps: bool) -> String {
+    format!(
+        r#"
+        [[modes.mappings]]
+        trigger = {{ type = "Note", note = 36 }}
+        action = {{ type = "Text", text = "36" }}
+
+        [[modes.mappings]]
+        trigger = {{ type = "Note", note = 38 }}
+        action = {{ type = "Text", text = "38" }}
+
+        [[modes.mappings]]
+        action = {{ type = "Text", text = "combo" }}
+        [modes.mappings.trigger]
+        type = "Sequence"
+        steps = [{{ type = "Note", note = 36 }}, {{ type = "Note", note = 38 }}, {{ type = "CC", cc = 20 }}]
+        max_gap_ms = 300
+        suppress_steps = {}
+    "#,
+        suppress_steps
+    )
+}
+
+/// Tap a note (press and release) at `ms` after `start`, returning the texts
+/// fired
+fn tap(rig: &mut Rig, note: u8, start: Instant, ms: u64) -> Vec<String> {
+    let time = start + Duration::from_millis(ms);
+    let mut fired = texts(&rig.process(MidiEvent::NoteOn {
+        channel: 0,
+        note,
+        velocity: 100,
+        time,
+    }));
+    fired.extend(texts(&rig.process(MidiEvent::NoteOff {
+        channel: 0,
+        note,
+        time: time + Duration::from_millis(20),
+    })));
+    fired
+}
+
+fn cc_event(value: u8, start: Instant, ms: u64) -> MidiEvent {
+    MidiEvent::ControlChange {
+        channel: 0,
+        cc: 20,
+        value,
+        time: start + Duration::from_millis(ms),
+    }
+}
+
+fn cc(rig: &mut Rig, value: u8, start: Instant, ms: u64) -> Vec<String> {
+    texts(&rig.process(cc_event(value, start, ms)))
+}
+
+#[test]
+fn test_sequence_matches_steps_in_order() {
+    let mut rig = Rig::new(&combo(false));
+    let now = Instant::now();
+
+    let mut actions = tap(&mut rig, 36, now, 0);
+    actions.extend(tap(&mut rig, 38, now, 200));
+    actions.extend(cc(&mut rig, 127, now, 400));
+
+    // Steps fire their own mappings, then the sequence fires
+    assert_eq!(actions, vec!["36", "38", "combo"]);
+}
+
+#[test]
+fn test_last_step_and_sequence_both_fire() {
+    let mut rig = Rig::new(&format!(
+        r#"
+        [[modes.mappings]]
+        trigger = {{ type = "CC", cc = 20 }}
+        action = {{ type = "Text", text = "20" }}
+        {}
+    "#,
+        combo(false)
+    ));
+    let now = Instant::now();
+
+    tap(&mut rig, 36, now, 0);
+    tap(&mut rig, 38, now, 200);
+
+    // The CC mapping fires on movement, so the fader has a value already
+    cc(&mut rig, 0, now, 300);
+
+    // The last step and SequenceMatched come out in the same batch
+    assert_eq!(cc(&mut rig, 127, now, 400), vec!["20", "combo"]);
+}
+
+#[test]
+fn test_sequence_reports_span() {
+    let mut rig = Rig::new(&combo(false));
+    let now = Instant::now();
+
+    tap(&mut rig, 36, now, 0);
+    tap(&mut rig, 38, now, 200);
+    let events = rig.processor.process(cc_event(127, now, 400));
+
+    assert!(
+        events
+            .iter()
+            .any(|e| matches!(e, ProcessedEvent::SequenceMatched { span_ms: 400, .. }))
+    );
+}
+
+#[test]
+fn test_sequence_breaks_on_gap_or_wrong_step() {
+    let mut rig = Rig::new(&combo(false));
+    let now = Instant::now();
+
+    // Too slow between the second and third step
+    tap(&mut rig, 36, now, 0);
+    tap(&mut rig, 38, now, 200);
+    assert!(!cc(&mut rig, 127, now, 600).contains(&"combo".to_string()));
+    cc(&mut rig, 0, now, 650);
+
+    // Wrong step in between
+    tap(&mut rig, 36, now, 1000);
+    tap(&mut rig, 40, now, 1100);
+    tap(&mut rig, 38, now, 1200);
+    assert!(!cc(&mut rig, 127, now, 1300).contains(&"combo".to_string()));
+}
+
+#[test]
+fn test_cc_step_needs_rising_value() {
+    let mut rig = Rig::new(&combo(false));
+    let now = Instant::now();
+
+    cc(&mut rig, 100, now, 0);
+    tap(&mut rig, 36, now, 50);
+    tap(&mut rig, 38, now, 100);
+
+    // Still above 64 from before, so not a new press
+    assert!(cc(&mut rig, 110, now, 150).is_empty());
+}
+
+#[test]
+fn test_suppressed_steps_fire_only_sequence() {
+    let mut rig = Rig::new(&combo(true));
+    let now = Instant::now();
+
+    let mut actions = tap(&mut rig, 36, now, 0);
+    actions.extend(tap(&mut rig, 38, now, 200));
+    actions.extend(cc(&mut rig, 127, now, 400));
+    actions.extend(texts(&rig.tick(now + Duration::from_millis(2000))));
+
+    assert_eq!(actions, vec!["combo"]);
+}
+
+#[test]
+fn test_suppressed_steps_replay_when_sequence_breaks() {
+    let mut rig = Rig::new(&combo(true));
+    let now = Instant::now();
+
+    let mut actions = tap(&mut rig, 36, now, 0);
+    actions.extend(tap(&mut rig, 38, now, 200));
+    assert!(actions.is_empty(), "Prefix is held back");
+
+    // The gap expires without the final step, and both steps replay in one
+    // batch
+    actions.extend(texts(&rig.tick(now + Duration::from_millis(600))));
+    assert_eq!(actions, vec!["36", "38"]);
+}
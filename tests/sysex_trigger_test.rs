@@ -0,0 +1,124 @@
+// Copyright 2025 Amiable
+// SPDX-License-Identifier: MIT
+
+//! Integration tests for SysEx input and byte-pattern triggers
+//!
+//! Tests that SysEx messages are parsed within the size cap, that `SysEx`
+//! triggers match exact, wildcard and captured bytes, and that captured bytes
+//! are passed on with the mapped action.
+
+mod common;
+
+use common::engine_with_mappings;
+use conductor_core::event_processor::{MAX_SYSEX_LEN, MidiEvent, ProcessedEvent};
+use conductor_core::{Action, Config, EventProcessor, SysExByte};
+
+fn sysex(data: &[u8]) -> ProcessedEvent {
+    ProcessedEvent::SysExReceived {
+        data: data.to_vec(),
+    }
+}
+
+const MMC: &str = r#"
+    [[modes.mappings]]
+    trigger = { type = "SysEx", pattern = "F0 7F ?? 06 (??) F7" }
+    action = { type = "Text", text = "mmc {capture0}" }
+"#;
+
+#[test]
+fn test_parse_sysex_message() {
+    let event = MidiEvent::from_midi_msg(&[0xF0, 0x7F, 0x7F, 0x06, 0x02, 0xF7])
+        .expect("SysEx should parse");
+    assert!(matches!(
+        event,
+        MidiEvent::SysEx { ref data, .. } if data == &[0xF0, 0x7F, 0x7F, 0x06, 0x02, 0xF7]
+    ));
+
+    let mut processor = EventProcessor::new();
+    assert!(matches!(
+        processor.process(event).as_slice(),
+        [ProcessedEvent::SysExReceived { .. }]
+    ));
+}
+
+#[test]
+fn test_sysex_size_cap_and_framing() {
+    let mut long = vec![0xF0];
+    long.resize(MAX_SYSEX_LEN - 1, 0x01);
+    long.push(0xF7);
+    assert!(MidiEvent::from_midi_msg(&long).is_ok());
+
+    long.insert(1, 0x01);
+    assert!(MidiEvent::from_midi_msg(&long).is_err(), "Over the cap");
+
+    assert!(MidiEvent::from_midi_msg(&[0xF0, 0x7F, 0x06]).is_err());
+}
+
+#[test]
+fn test_pattern_wildcards_and_captures() {
+    let engine = engine_with_mappings(MMC);
+
+    // Any device ID, play (02) and stop (01) are captured
+    for (device, command) in [(0x7F, 0x02), (0x10, 0x01)] {
+        let mapped = engine
+            .get_mapped_action_for_processed(&sysex(&[0xF0, 0x7F, device, 0x06, command, 0xF7]), 0)
+            .expect("MMC command should match");
+        assert_eq!(mapped.captures, vec![command]);
+        assert!(matches!(mapped.action, Action::Text(ref text) if text == "mmc {capture0}"));
+    }
+}
+
+#[test]
+fn test_pattern_mismatch() {
+    let engine = engine_with_mappings(MMC);
+
+    for data in [
+        // Different sub-ID
+        &[0xF0, 0x7F, 0x7F, 0x07, 0x02, 0xF7][..],
+        // Too short and too long
+        &[0xF0, 0x7F, 0x7F, 0x06, 0xF7][..],
+        &[0xF0, 0x7F, 0x7F, 0x06, 0x02, 0x00, 0xF7][..],
+    ] {
+        assert!(engine.get_action_for_processed(&sysex(data), 0).is_none());
+    }
+}
+
+#[test]
+fn test_parse_pattern() {
+    assert_eq!(
+        SysExByte::parse_pattern("F0 00 ?? (??) f7"),
+        Ok(vec![
+            SysExByte::Exact(0xF0),
+            SysExByte::Exact(0x00),
+            SysExByte::Any,
+            SysExByte::Capture,
+            SysExByte::Exact(0xF7),
+        ])
+    );
+
+    assert!(SysExByte::parse_pattern("00 01 F7").is_err(), "Missing F0");
+    assert!(SysExByte::parse_pattern("F0 01").is_err(), "Missing F7");
+    assert!(SysExByte::parse_pattern("F0 80 F7").is_err(), "Status byte");
+    assert!(SysExByte::parse_pattern("F0 0x1 F7").is_err());
+}
+
+#[test]
+fn test_invalid_pattern_rejected_by_validation() {
+    let config: Config = toml::from_str(
+        r#"
+        [device]
+        name = "Test Device"
+        auto_connect = false
+
+        [[modes]]
+        name = "Test Mode"
+
+        [[modes.mappings]]
+        trigger = { type = "SysEx", pattern = "F0 7F GG F7" }
+        action = { type = "Text", text = "x" }
+    "#,
+    )
+    .expect("Failed to parse config");
+
+    assert!(config.validate().is_err());
+}
@@ -0,0 +1,247 @@
+// Copyright 2025 Amiable
+// SPDX-License-Identifier: MIT
+
+//! Integration tests for touch strip gestures
+//!
+//! Tests that pitch bend and CC streams from a touch strip are recognized as
+//! swipes, taps and slide-and-holds by the event processor, and that the
+//! `TouchSwipe`, `TouchTap` and `TouchSlideHold` triggers match them.
+
+mod common;
+
+use common::{Rig, texts};
+use conductor_core::Config;
+use conductor_core::event_processor::{MidiEvent, ProcessedEvent};
+use conductor_core::gestures::{Gesture, TOUCH_RELEASE};
+use std::time::{Duration, Instant};
+
+/// A touch strip sending pitch bend, dispatched through a processor and
+/// engine
+struct Strip {
+    rig: Rig,
+    time: Instant,
+}
+
+impl Strip {
+    fn new(mappings_toml: &str) -> Self {
+        Self {
+            rig: Rig::new(mappings_toml),
+            time: Instant::now(),
+        }
+    }
+
+    /// Send `positions` (0.0-1.0) as pitch bend `step_ms` apart, then lift
+    /// the finger, returning the gesture events and the texts they fired
+    fn touch(&mut self, positions: &[f32], step_ms: u64) -> (Vec<Gesture>, Vec<String>) {
+        let mut batches = Vec::new();
+        for position in positions {
+            self.time += Duration::from_millis(step_ms);
+            batches.push(self.rig.processor.process(MidiEvent::PitchBend {
+                channel: 0,
+                value: (position * 16383.0) as u16,
+                time: self.time,
+            }));
+        }
+        self.time += TOUCH_RELEASE;
+        batches.push(self.rig.processor.tick(self.time));
+
+        let gestures = batches
+            .iter()
+            .flatten()
+            .filter_map(|event| match event {
+                ProcessedEvent::GestureRecognized { gesture, .. } => Some(gesture.clone()),
+                _ => None,
+            })
+            .collect();
+        let texts = batches
+            .iter()
+            .flat_map(|batch| texts(&self.rig.dispatch(batch)))
+            .collect();
+        (gestures, texts)
+    }
+}
+
+const SWIPES: &str = r#"
+    [[modes.mappings]]
+    trigger = { type = "TouchSwipe", direction = "Right", min_speed = 2.0 }
+    action = { type = "Text", text = "next" }
+
+    [[modes.mappings]]
+    trigger = { type = "TouchSwipe", direction = "Left" }
+    action = { type = "Text", text = "previous" }
+"#;
+
+#[test]
+fn test_swipe_direction() {
+    let mut strip = Strip::new(SWIPES);
+
+    let (gestures, texts) = strip.touch(&[0.1, 0.3, 0.5, 0.7], 20);
+    assert!(matches!(gestures.as_slice(), [Gesture::Swipe { .. }]));
+    assert_eq!(texts, vec!["next"]);
+
+    let (_, texts) = strip.touch(&[0.9, 0.6, 0.3], 20);
+    assert_eq!(texts, vec!["previous"]);
+}
+
+#[test]
+fn test_swipe_speed_and_distance_thresholds() {
+    let mut strip = Strip::new(SWIPES);
+
+    // 0.6 of the strip in 600ms is 1 length per second, below min_speed
+    let positions: Vec<f32> = (0..=6).map(|i| 0.2 + i as f32 * 0.1).collect();
+    let (gestures, texts) = strip.touch(&positions, 100);
+    assert_eq!(gestures.len(), 1);
+    assert!(texts.is_empty());
+
+    // Too short for the default min_distance of 0.25
+    let (_, texts) = strip.touch(&[0.5, 0.4, 0.35], 10);
+    assert!(texts.is_empty());
+}
+
+#[test]
+fn test_tap_zones() {
+    let mut strip = Strip::new(
+        r#"
+        [[modes.mappings]]
+        trigger = { type = "TouchTap", zones = 4, zone = 3 }
+        action = { type = "Text", text = "right end" }
+
+        [[modes.mappings]]
+        trigger = { type = "TouchTap", zones = 4 }
+        action = { type = "Text", text = "zone {offset}" }
+    "#,
+    );
+
+    let (gestures, texts) = strip.touch(&[0.9, 0.9], 20);
+    assert!(matches!(gestures.as_slice(), [Gesture::Tap { .. }]));
+    assert_eq!(texts, vec!["right end"]);
+
+    let (_, texts) = strip.touch(&[0.3], 20);
+    assert_eq!(texts, vec!["zone {offset}"]);
+
+    let event = ProcessedEvent::GestureRecognized {
+        channel: 0,
+        source: conductor_core::gestures::TouchSource::PitchBend,
+        gesture: Gesture::Tap { position: 0.3 },
+    };
+    let mapped = strip
+        .rig
+        .engine
+        .get_mapped_action_for_processed(&event, 0)
+        .expect("Tap should match");
+    assert_eq!(mapped.control.map(|control| control.offset), Some(1));
+
+    // A long touch isn't a tap
+    let (gestures, _) = strip.touch(&[0.5; 40], 10);
+    assert!(gestures.is_empty());
+}
+
+#[test]
+fn test_slide_and_hold() {
+    let mut strip = Strip::new(
+        r#"
+        [[modes.mappings]]
+        trigger = { type = "TouchSlideHold", hold_ms = 200 }
+        action = { type = "Text", text = "scrub" }
+
+        [[modes.mappings]]
+        trigger = { type = "TouchSwipe", direction = "Right" }
+        action = { type = "Text", text = "next" }
+    "#,
+    );
+
+    // Slide, then rest for 300ms while the strip keeps reporting
+    let mut positions = vec![0.2, 0.4, 0.6];
+    positions.extend([0.6; 30]);
+    let (_, texts) = strip.touch(&positions, 10);
+    assert_eq!(
+        texts,
+        vec!["scrub"],
+        "Fires once, and the lift isn't a swipe"
+    );
+
+    // Resting without sliding first doesn't fire
+    let (_, texts) = strip.touch(&[0.6; 30], 10);
+    assert!(texts.is_empty());
+}
+
+#[test]
+fn test_pitch_bend_mapping_does_not_block_gestures() {
+    let mut strip = Strip::new(
+        r#"
+        [[modes.mappings]]
+        trigger = { type = "PitchBend" }
+        action = { type = "Text", text = "bend" }
+
+        [[modes.mappings]]
+        trigger = { type = "TouchSlideHold", hold_ms = 200 }
+        action = { type = "Text", text = "scrub" }
+    "#,
+    );
+
+    // The slide-and-hold is recognized in the batch of a pitch bend message
+    let mut positions = vec![0.2, 0.4, 0.6];
+    positions.extend([0.6; 30]);
+    let (_, texts) = strip.touch(&positions, 10);
+    let bends = texts.iter().filter(|text| *text == "bend").count();
+    assert_eq!(bends, positions.len());
+    assert_eq!(bends + 1, texts.len());
+    assert!(texts.contains(&"scrub".to_string()));
+}
+
+#[test]
+fn test_cc_strip_source() {
+    let mut strip = Strip::new(
+        r#"
+        [[modes.mappings]]
+        trigger = { type = "TouchSwipe", direction = "Right", cc = 1 }
+        action = { type = "Text", text = "cc swipe" }
+    "#,
+    );
+
+    // Pitch bend isn't the configured source
+    let (gestures, _) = strip.touch(&[0.1, 0.5, 0.9], 20);
+    assert!(gestures.is_empty());
+
+    let mut time = strip.time;
+    for value in [10, 60, 110] {
+        time += Duration::from_millis(20);
+        strip.rig.process(MidiEvent::ControlChange {
+            channel: 0,
+            cc: 1,
+            value,
+            time,
+        });
+    }
+    assert_eq!(
+        texts(&strip.rig.tick(time + TOUCH_RELEASE)),
+        vec!["cc swipe"]
+    );
+}
+
+#[test]
+fn test_invalid_gesture_triggers_rejected() {
+    for trigger in [
+        r#"{ type = "TouchTap", zones = 2, zone = 2 }"#,
+        r#"{ type = "TouchSwipe", direction = "Left", min_distance = 1.5 }"#,
+        r#"{ type = "TouchSlideHold", cc = 200 }"#,
+    ] {
+        let config: Config = toml::from_str(&format!(
+            r#"
+            [device]
+            name = "Test Device"
+            auto_connect = false
+
+            [[modes]]
+            name = "Test Mode"
+
+            [[modes.mappings]]
+            trigger = {}
+            action = {{ type = "Text", text = "x" }}
+        "#,
+            trigger
+        ))
+        .expect("Failed to parse config");
+        assert!(config.validate().is_err(), "{} should be rejected", trigger);
+    }
+}
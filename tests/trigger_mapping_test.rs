@@ -0,0 +1,371 @@
+// Copyright 2025 Amiable
+// SPDX-License-Identifier: MIT
+
+//! Integration tests for processed-event trigger mapping
+//!
+//! Tests that every declared Trigger variant compiles into a real matcher in the
+//! MappingEngine and fires on the ProcessedEvents emitted by the EventProcessor.
+
+mod common;
+
+use common::{engine_with_mappings, fires, hit_on};
+use conductor_core::{EventProcessor, MappingEngine, MidiEvent};
+use std::time::{Duration, Instant};
+
+/// A mapping engine with one mapping of `trigger_toml` to the Text action "hit"
+fn engine_with_trigger(trigger_toml: &str) -> MappingEngine {
+    engine_with_mappings(&hit_on(trigger_toml))
+}
+
+#[test]
+fn test_note_trigger_matches_pad_pressed() {
+    let engine = engine_with_trigger("type = \"Note\"\nnote = 36\nvelocity_min = 10");
+    let mut processor = EventProcessor::new();
+    let now = Instant::now();
+
+    let soft = processor.process(MidiEvent::NoteOn {
+        channel: 0,
+        note: 36,
+        velocity: 5,
+        time: now,
+    });
+    assert!(
+        !fires(&engine, &soft),
+        "Velocity below velocity_min should not fire"
+    );
+
+    let other = processor.process(MidiEvent::NoteOn {
+        channel: 0,
+        note: 37,
+        velocity: 100,
+        time: now + Duration::from_millis(500),
+    });
+    assert!(!fires(&engine, &other), "Other notes should not fire");
+
+    let hit = processor.process(MidiEvent::NoteOn {
+        channel: 0,
+        note: 36,
+        velocity: 100,
+        time: now + Duration::from_millis(1000),
+    });
+    assert!(fires(&engine, &hit));
+}
+
+#[test]
+fn test_cc_trigger_matches_encoder_turned() {
+    let engine = engine_with_trigger("type = \"CC\"\ncc = 7\nvalue_min = 64");
+    let mut processor = EventProcessor::new();
+    let now = Instant::now();
+
+    processor.process(MidiEvent::ControlChange {
+        channel: 0,
+        cc: 7,
+        value: 10,
+        time: now,
+    });
+    let low = processor.process(MidiEvent::ControlChange {
+        channel: 0,
+        cc: 7,
+        value: 20,
+        time: now,
+    });
+    assert!(!fires(&engine, &low));
+
+    let high = processor.process(MidiEvent::ControlChange {
+        channel: 0,
+        cc: 7,
+        value: 100,
+        time: now,
+    });
+    assert!(fires(&engine, &high));
+}
+
+#[test]
+fn test_velocity_range_trigger_matches_level() {
+    let engine = engine_with_trigger("type = \"VelocityRange\"\nnote = 36\nlevel = \"Hard\"");
+    let mut processor = EventProcessor::new();
+    let now = Instant::now();
+
+    let soft = processor.process(MidiEvent::NoteOn {
+        channel: 0,
+        note: 36,
+        velocity: 20,
+        time: now,
+    });
+    assert!(
+        !fires(&engine, &soft),
+        "Soft press should not match Hard level"
+    );
+
+    let medium = processor.process(MidiEvent::NoteOn {
+        channel: 0,
+        note: 36,
+        velocity: 60,
+        time: now + Duration::from_millis(500),
+    });
+    assert!(
+        !fires(&engine, &medium),
+        "Medium press should not match Hard level"
+    );
+
+    let hard = processor.process(MidiEvent::NoteOn {
+        channel: 0,
+        note: 36,
+        velocity: 120,
+        time: now + Duration::from_millis(1000),
+    });
+    assert!(fires(&engine, &hard));
+}
+
+#[test]
+fn test_velocity_range_trigger_without_level_matches_any_press() {
+    let engine = engine_with_trigger("type = \"VelocityRange\"\nnote = 36");
+    let mut processor = EventProcessor::new();
+
+    let events = processor.process(MidiEvent::NoteOn {
+        channel: 0,
+        note: 36,
+        velocity: 20,
+        time: Instant::now(),
+    });
+    assert!(fires(&engine, &events));
+}
+
+#[test]
+fn test_long_press_trigger() {
+    let engine = engine_with_trigger("type = \"LongPress\"\nnote = 36");
+    let mut processor = EventProcessor::new();
+    let now = Instant::now();
+
+    processor.process(MidiEvent::NoteOn {
+        channel: 0,
+        note: 36,
+        velocity: 100,
+        time: now,
+    });
+    let released = processor.process(MidiEvent::NoteOff {
+        channel: 0,
+        note: 36,
+        time: now + Duration::from_millis(2500),
+    });
+    assert!(fires(&engine, &released));
+
+    // A quick tap on the same pad is not a long press
+    processor.process(MidiEvent::NoteOn {
+        channel: 0,
+        note: 36,
+        velocity: 100,
+        time: now + Duration::from_millis(5000),
+    });
+    let tapped = processor.process(MidiEvent::NoteOff {
+        channel: 0,
+        note: 36,
+        time: now + Duration::from_millis(5050),
+    });
+    assert!(!fires(&engine, &tapped));
+}
+
+#[test]
+fn test_double_tap_trigger() {
+    let engine = engine_with_trigger("type = \"DoubleTap\"\nnote = 36");
+    let mut processor = EventProcessor::new();
+    let now = Instant::now();
+
+    let first = processor.process(MidiEvent::NoteOn {
+        channel: 0,
+        note: 36,
+        velocity: 100,
+        time: now,
+    });
+    assert!(
+        !fires(&engine, &first),
+        "First tap alone is not a double tap"
+    );
+
+    processor.process(MidiEvent::NoteOff {
+        channel: 0,
+        note: 36,
+        time: now + Duration::from_millis(50),
+    });
+    let second = processor.process(MidiEvent::NoteOn {
+        channel: 0,
+        note: 36,
+        velocity: 100,
+        time: now + Duration::from_millis(150),
+    });
+    assert!(fires(&engine, &second));
+}
+
+#[test]
+fn test_encoder_turn_trigger_direction() {
+    let engine = engine_with_trigger("type = \"EncoderTurn\"\ncc = 1\ndirection = \"Clockwise\"");
+    let mut processor = EventProcessor::new();
+    let now = Instant::now();
+
+    processor.process(MidiEvent::ControlChange {
+        channel: 0,
+        cc: 1,
+        value: 64,
+        time: now,
+    });
+    let ccw = processor.process(MidiEvent::ControlChange {
+        channel: 0,
+        cc: 1,
+        value: 60,
+        time: now,
+    });
+    assert!(!fires(&engine, &ccw));
+
+    let cw = processor.process(MidiEvent::ControlChange {
+        channel: 0,
+        cc: 1,
+        value: 70,
+        time: now,
+    });
+    assert!(fires(&engine, &cw));
+
+    let other_cc = processor.process(MidiEvent::ControlChange {
+        channel: 0,
+        cc: 2,
+        value: 70,
+        time: now,
+    });
+    assert!(!fires(&engine, &other_cc));
+}
+
+#[test]
+fn test_aftertouch_trigger_pressure_min() {
+    let engine = engine_with_trigger("type = \"Aftertouch\"\npressure_min = 64");
+    let mut processor = EventProcessor::new();
+    let now = Instant::now();
+
+    let light = processor.process(MidiEvent::Aftertouch {
+        channel: 0,
+        pressure: 30,
+        time: now,
+    });
+    assert!(!fires(&engine, &light));
+
+    let firm = processor.process(MidiEvent::Aftertouch {
+        channel: 0,
+        pressure: 90,
+        time: now,
+    });
+    assert!(fires(&engine, &firm));
+}
+
+#[test]
+fn test_pitch_bend_trigger_value_range() {
+    let engine = engine_with_trigger("type = \"PitchBend\"\nvalue_min = 10000\nvalue_max = 16383");
+    let mut processor = EventProcessor::new();
+    let now = Instant::now();
+
+    let center = processor.process(MidiEvent::PitchBend {
+        channel: 0,
+        value: 8192,
+        time: now,
+    });
+    assert!(!fires(&engine, &center));
+
+    let up = processor.process(MidiEvent::PitchBend {
+        channel: 0,
+        value: 12000,
+        time: now,
+    });
+    assert!(fires(&engine, &up));
+}
+
+#[test]
+fn test_channel_filter() {
+    let engine = engine_with_trigger("type = \"Note\"\nnote = 36\nchannel = 9");
+    let mut processor = EventProcessor::new();
+    let now = Instant::now();
+
+    let wrong_channel = processor.process(MidiEvent::NoteOn {
+        channel: 0,
+        note: 36,
+        velocity: 100,
+        time: now,
+    });
+    assert!(
+        !fires(&engine, &wrong_channel),
+        "Notes on other channels should not fire"
+    );
+
+    let right_channel = processor.process(MidiEvent::NoteOn {
+        channel: 9,
+        note: 36,
+        velocity: 100,
+        time: now + Duration::from_millis(500),
+    });
+    assert!(fires(&engine, &right_channel));
+}
+
+#[test]
+fn test_trigger_without_channel_matches_any_channel() {
+    let engine = engine_with_trigger("type = \"Aftertouch\"");
+    let mut processor = EventProcessor::new();
+
+    for channel in [0, 5, 15] {
+        let events = processor.process(MidiEvent::Aftertouch {
+            channel,
+            pressure: 100,
+            time: Instant::now(),
+        });
+        assert!(fires(&engine, &events), "Channel {} should fire", channel);
+    }
+}
+
+#[test]
+fn test_same_note_on_different_channels_is_not_double_tap() {
+    let engine = engine_with_trigger("type = \"DoubleTap\"\nnote = 36");
+    let mut processor = EventProcessor::new();
+    let now = Instant::now();
+
+    processor.process(MidiEvent::NoteOn {
+        channel: 0,
+        note: 36,
+        velocity: 100,
+        time: now,
+    });
+    processor.process(MidiEvent::NoteOff {
+        channel: 0,
+        note: 36,
+        time: now + Duration::from_millis(50),
+    });
+    let other_channel = processor.process(MidiEvent::NoteOn {
+        channel: 1,
+        note: 36,
+        velocity: 100,
+        time: now + Duration::from_millis(150),
+    });
+    assert!(!fires(&engine, &other_channel));
+}
+
+#[test]
+fn test_advanced_triggers_do_not_fire_on_note_zero() {
+    // Regression: unsupported triggers used to compile into a Note { note: 0 } matcher
+    let triggers = [
+        "type = \"LongPress\"\nnote = 36",
+        "type = \"DoubleTap\"\nnote = 36",
+        "type = \"EncoderTurn\"\ncc = 1",
+        "type = \"Aftertouch\"\npressure_min = 10",
+        "type = \"PitchBend\"",
+    ];
+
+    for trigger in triggers {
+        let engine = engine_with_trigger(trigger);
+        let mut processor = EventProcessor::new();
+        let events = processor.process(MidiEvent::NoteOn {
+            channel: 0,
+            note: 0,
+            velocity: 100,
+            time: Instant::now(),
+        });
+        assert!(
+            !fires(&engine, &events),
+            "Trigger should not fire on note 0: {}",
+            trigger
+        );
+    }
+}
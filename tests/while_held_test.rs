@@ -0,0 +1,263 @@
+// Copyright 2025 Amiable
+// SPDX-License-Identifier: MIT
+
+//! Integration tests for modifier pads
+//!
+//! Tests that mappings guarded by `while_held` only match while their pads
+//! are held, that the most specific mapping wins, and that a pad used as a
+//! modifier doesn't also fire its own press or long press.
+
+mod common;
+
+use common::{Rig, engine_with_mappings, texts};
+use conductor_core::event_processor::{ProcessedEvent, VelocityLevel};
+use conductor_core::events::InputEvent;
+use std::time::{Duration, Instant};
+
+/// A controller dispatched through a processor and engine the way the
+/// daemon does
+struct Controller {
+    rig: Rig,
+    time: Instant,
+}
+
+impl Controller {
+    fn new(mappings_toml: &str) -> Self {
+        Self {
+            rig: Rig::new(mappings_toml),
+            time: Instant::now(),
+        }
+    }
+
+    /// Map events with the pads held now, returning the texts fired
+    fn fire(&mut self, events: Vec<ProcessedEvent>) -> Vec<String> {
+        texts(&self.rig.dispatch(&events))
+    }
+
+    fn input(&mut self, event: InputEvent) -> Vec<String> {
+        self.time += Duration::from_millis(10);
+        let events = self.rig.processor.process_input(event);
+        self.fire(events)
+    }
+
+    fn press(&mut self, pad: u8) -> Vec<String> {
+        let time = self.time + Duration::from_millis(10);
+        self.input(InputEvent::PadPressed {
+            channel: 0,
+            pad,
+            velocity: 100,
+            time,
+        })
+    }
+
+    fn release(&mut self, pad: u8) -> Vec<String> {
+        let time = self.time + Duration::from_millis(10);
+        self.input(InputEvent::PadReleased {
+            channel: 0,
+            pad,
+            time,
+        })
+    }
+
+    /// Turn encoder 3 one step clockwise
+    fn turn(&mut self) -> Vec<String> {
+        let time = self.time + Duration::from_millis(10);
+        self.input(InputEvent::EncoderTurned {
+            channel: 0,
+            encoder: 3,
+            value: 1,
+            time,
+        })
+    }
+
+    fn wait(&mut self, ms: u64) -> Vec<String> {
+        self.time += Duration::from_millis(ms);
+        let events = self.rig.processor.tick(self.time);
+        self.fire(events)
+    }
+}
+
+const SCRUB: &str = r#"
+    [[modes.mappings]]
+    trigger = { type = "EncoderTurn", cc = 3, encoding = "TwosComplement" }
+    action = { type = "Text", text = "volume" }
+
+    [[modes.mappings]]
+    trigger = { type = "EncoderTurn", cc = 3, encoding = "TwosComplement" }
+    action = { type = "Text", text = "scrub" }
+    while_held = [12]
+
+    [[modes.mappings]]
+    trigger = { type = "Note", note = 12 }
+    action = { type = "Text", text = "pad 12" }
+
+    [[modes.mappings]]
+    trigger = { type = "LongPress", note = 12, duration_ms = 500 }
+    action = { type = "Text", text = "held 12" }
+"#;
+
+#[test]
+fn test_combo_is_distinct_from_plain_trigger() {
+    let mut controller = Controller::new(SCRUB);
+
+    assert_eq!(controller.turn(), vec!["volume"]);
+
+    assert!(controller.press(12).is_empty(), "Held back");
+    assert_eq!(controller.turn(), vec!["scrub"]);
+    assert_eq!(controller.turn(), vec!["scrub"]);
+    assert!(controller.release(12).is_empty(), "Used as a modifier");
+
+    assert_eq!(controller.turn(), vec!["volume"]);
+}
+
+#[test]
+fn test_unused_modifier_fires_on_release() {
+    let mut controller = Controller::new(SCRUB);
+
+    assert!(controller.press(12).is_empty());
+    assert_eq!(controller.release(12), vec!["pad 12"]);
+
+    // Held long without being used, it long-presses as usual
+    controller.press(12);
+    assert_eq!(controller.wait(600), vec!["held 12"]);
+    assert_eq!(controller.release(12), vec!["pad 12"]);
+}
+
+#[test]
+fn test_used_modifier_does_not_long_press() {
+    let mut controller = Controller::new(SCRUB);
+
+    controller.press(12);
+    assert_eq!(controller.turn(), vec!["scrub"]);
+    assert!(controller.wait(600).is_empty());
+    assert!(controller.release(12).is_empty());
+}
+
+#[test]
+fn test_most_specific_mapping_wins() {
+    let mut controller = Controller::new(
+        r#"
+        [[modes.mappings]]
+        trigger = { type = "Note", note = 40 }
+        action = { type = "Text", text = "plain" }
+
+        [[modes.mappings]]
+        trigger = { type = "Note", note = 40 }
+        action = { type = "Text", text = "shift" }
+        while_held = [12]
+
+        [[global_mappings]]
+        trigger = { type = "Note", note = 40 }
+        action = { type = "Text", text = "shift + alt" }
+        while_held = [12, 13]
+    "#,
+    );
+
+    assert_eq!(controller.press(40), vec!["plain"]);
+    controller.release(40);
+
+    controller.press(12);
+    assert_eq!(controller.press(40), vec!["shift"]);
+    controller.release(40);
+
+    controller.press(13);
+    assert_eq!(
+        controller.press(40),
+        vec!["shift + alt"],
+        "Beats the mode's mapping"
+    );
+}
+
+#[test]
+fn test_gamepad_button_modifier() {
+    let mut controller = Controller::new(
+        r#"
+        [[modes.mappings]]
+        trigger = { type = "GamepadButton", button = 128 }
+        action = { type = "Text", text = "jump" }
+
+        [[modes.mappings]]
+        trigger = { type = "GamepadButton", button = 128 }
+        action = { type = "Text", text = "roll" }
+        while_held = [136]
+    "#,
+    );
+
+    assert_eq!(controller.press(128), vec!["jump"]);
+    controller.release(128);
+
+    // L1 has no mapping of its own, so isn't held back
+    controller.press(136);
+    assert_eq!(controller.press(128), vec!["roll"]);
+}
+
+#[test]
+fn test_guarded_mapping_needs_held_pads() {
+    let engine = engine_with_mappings(
+        r#"
+        [[modes.mappings]]
+        trigger = { type = "Note", note = 40 }
+        action = { type = "Text", text = "shift" }
+        while_held = [12]
+    "#,
+    );
+
+    let event = ProcessedEvent::PadPressed {
+        channel: 0,
+        note: 40,
+        velocity: 100,
+        velocity_level: VelocityLevel::Hard,
+    };
+    assert!(engine.get_mapped_action_for_processed(&event, 0).is_none());
+    assert!(
+        engine
+            .get_mapped_action_while_held(&event, 0, &[(0, 13)])
+            .is_none()
+    );
+
+    let mapped = engine
+        .get_mapped_action_while_held(&event, 0, &[(0, 12), (0, 13)])
+        .expect("Modifier is held");
+    assert_eq!(mapped.modifiers, vec![(0, 12)]);
+}
+
+#[test]
+fn test_channel_filter_applies_to_held_pads() {
+    let engine = engine_with_mappings(
+        r#"
+        [[modes.mappings]]
+        trigger = { type = "Note", note = 40, channel = 0 }
+        action = { type = "Text", text = "channel 1" }
+        while_held = [12]
+
+        [[modes.mappings]]
+        trigger = { type = "Note", note = 41 }
+        action = { type = "Text", text = "any channel" }
+        while_held = [12]
+    "#,
+    );
+
+    let pressed = |note| ProcessedEvent::PadPressed {
+        channel: 0,
+        note,
+        velocity: 100,
+        velocity_level: VelocityLevel::Hard,
+    };
+
+    // Pad 12 held on channel 10 isn't pad 12 on channel 1
+    assert!(
+        engine
+            .get_mapped_action_while_held(&pressed(40), 0, &[(9, 12)])
+            .is_none()
+    );
+    let mapped = engine
+        .get_mapped_action_while_held(&pressed(40), 0, &[(9, 12), (0, 12)])
+        .expect("Modifier is held on the trigger's channel");
+    assert_eq!(mapped.modifiers, vec![(0, 12)]);
+
+    // Without a channel, the pad counts on any channel
+    let mapped = engine
+        .get_mapped_action_while_held(&pressed(41), 0, &[(9, 12)])
+        .expect("Modifier is held");
+    assert_eq!(mapped.modifiers, vec![(9, 12)]);
+}